@@ -5,6 +5,7 @@ use frame_benchmarking::v2::*;
 
 use frame_support::traits::fungible::Mutate;
 use frame_system::RawOrigin;
+use pallet_communities::BenchmarkHelper;
 use sp_runtime::SaturatedConversion;
 
 type RuntimeEventFor<T> = <T as Config>::RuntimeEvent;
@@ -136,6 +137,155 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn return_memberships(q: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		// setup code
+		setup_collection::<T>()?;
+		let first_member: AccountIdOf<T> = frame_benchmarking::account("founder", 0, 0);
+		setup_account::<T>(&first_member)?;
+
+		let community_id: CommunityIdOf<T> = 1.into();
+		Pallet::<T>::register(
+			RawOrigin::Root.into(),
+			community_id,
+			BoundedVec::truncate_from(b"Test Community".into()),
+			T::Lookup::unlookup(first_member.clone()),
+			None,
+			None,
+		)?;
+
+		let community_account = pallet_communities::Pallet::<T>::community_account(&community_id);
+		for i in 0..q {
+			T::CreateMemberships::mint_into(&community_id, &i.into(), &community_account, &Default::default(), true)?;
+		}
+		pallet_communities::Pallet::<T>::do_start_destroy(&community_id)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			community_id,
+			300_000_000_000u64.into(),
+			TankConfig {
+				capacity: Some(block_weight::<T>()),
+				periodicity: Some((7 * DAYS).into()),
+			},
+			Some(u32::MAX.into()),
+		);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::<T>::MembershipsReturned {
+				id: community_id,
+				amount: q.min(<T as pallet_communities::Config>::RemoveItemsLimit::get()),
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister() -> Result<(), BenchmarkError> {
+		// setup code
+		let first_member: AccountIdOf<T> = frame_benchmarking::account("founder", 0, 0);
+		setup_account::<T>(&first_member)?;
+
+		let community_id: CommunityIdOf<T> = 1.into();
+		Pallet::<T>::register(
+			RawOrigin::Root.into(),
+			community_id,
+			BoundedVec::truncate_from(b"Test Community".into()),
+			T::Lookup::unlookup(first_member.clone()),
+			None,
+			None,
+		)?;
+		let community_account = pallet_communities::Pallet::<T>::community_account(&community_id);
+		setup_account::<T>(&community_account)?;
+		pallet_communities::Pallet::<T>::do_start_destroy(&community_id)?;
+		let origin = <T as pallet_communities::Config>::BlockOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin, community_id, T::Lookup::unlookup(first_member));
+
+		// verification code
+		assert_has_event::<T>(Event::<T>::CommunityDeregistered { id: community_id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_track() -> Result<(), BenchmarkError> {
+		// setup code
+		let first_member: AccountIdOf<T> = frame_benchmarking::account("founder", 0, 0);
+		setup_account::<T>(&first_member)?;
+
+		let community_id = <T as pallet_communities::Config>::BenchmarkHelper::community_id();
+		Pallet::<T>::register(
+			RawOrigin::Root.into(),
+			community_id,
+			BoundedVec::truncate_from(b"Test Community".into()),
+			T::Lookup::unlookup(first_member),
+			None,
+			None,
+		)?;
+		let origin = <T as pallet_communities::Config>::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(
+			origin,
+			Some(Subset::AtLeastRank(1)),
+			Pallet::<T>::default_tack("Treasury"),
+		);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::<T>::TrackAdded {
+				id: community_id,
+				track_id: T::TrackId::for_community(community_id, 1),
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_track() -> Result<(), BenchmarkError> {
+		// setup code
+		let first_member: AccountIdOf<T> = frame_benchmarking::account("founder", 0, 0);
+		setup_account::<T>(&first_member)?;
+
+		let community_id = <T as pallet_communities::Config>::BenchmarkHelper::community_id();
+		Pallet::<T>::register(
+			RawOrigin::Root.into(),
+			community_id,
+			BoundedVec::truncate_from(b"Test Community".into()),
+			T::Lookup::unlookup(first_member),
+			None,
+			None,
+		)?;
+		let origin = <T as pallet_communities::Config>::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Pallet::<T>::add_track(
+			origin.clone(),
+			Some(Subset::AtLeastRank(1)),
+			Pallet::<T>::default_tack("Treasury"),
+		)?;
+		let track_id = T::TrackId::for_community(community_id, 1);
+
+		#[extrinsic_call]
+		_(origin, track_id);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::<T>::TrackRemoved {
+				id: community_id,
+				track_id,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		sp_io::TestExternalities::new(Default::default()),
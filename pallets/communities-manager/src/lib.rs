@@ -18,14 +18,20 @@ use fc_traits_tracks::MutateTracks;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
+		fungible::{Inspect as _, Mutate as _},
 		nonfungibles_v2::Mutate as ItemMutate,
-		nonfungibles_v2::{Create as CollectionCreate, Trading},
+		nonfungibles_v2::{Create as CollectionCreate, Destroy as CollectionDestroy, InspectEnumerable, Trading},
+		tokens::{Fortitude::Polite, Preservation::Expendable},
 		Incrementable, OriginTrait, RankedMembers,
 	},
 };
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use pallet_communities::{
-	types::{AccountIdOf, CommunityIdOf, DecisionMethodFor, NativeBalanceOf, PalletsOriginOf, RuntimeOriginFor},
+	origin::Subset,
+	types::{
+		AccountIdOf, CommunityIdOf, CommunityMetadata, CommunityTrackId, DecisionMethodFor, NativeBalanceOf,
+		PalletsOriginOf, RuntimeOriginFor,
+	},
 	Origin as CommunityOrigin,
 };
 use pallet_nfts::CollectionConfig;
@@ -33,7 +39,7 @@ use pallet_referenda::{TrackInfo, TracksInfo};
 use parity_scale_codec::Decode;
 use sp_runtime::{
 	str_array,
-	traits::{Get, StaticLookup},
+	traits::{Get, StaticLookup, Zero},
 };
 
 type TrackInfoOf<T> = TrackInfo<NativeBalanceOf<T>, BlockNumberFor<T>>;
@@ -60,10 +66,10 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		type CreateCollection: CollectionCreate<
-			AccountIdOf<Self>,
-			CollectionConfig<NativeBalanceOf<Self>, BlockNumberFor<Self>, CommunityIdOf<Self>>,
-			CollectionId = CommunityIdOf<Self>,
-		>;
+				AccountIdOf<Self>,
+				CollectionConfig<NativeBalanceOf<Self>, BlockNumberFor<Self>, CommunityIdOf<Self>>,
+				CollectionId = CommunityIdOf<Self>,
+			> + CollectionDestroy<AccountIdOf<Self>>;
 
 		type MakeTank: fc_traits_gas_tank::MakeTank<
 			Gas = Weight,
@@ -71,11 +77,15 @@ pub mod pallet {
 			BlockNumber = BlockNumberFor<Self>,
 		>;
 
-		type Tracks: TracksInfo<NativeBalanceOf<Self>, BlockNumberFor<Self>>
-			+ MutateTracks<
+		type Tracks: TracksInfo<
 				NativeBalanceOf<Self>,
 				BlockNumberFor<Self>,
-				Id = CommunityIdOf<Self>,
+				Id = <Self as pallet_communities::Config>::TrackId,
+				RuntimeOrigin = PalletsOriginOf<Self>,
+			> + MutateTracks<
+				NativeBalanceOf<Self>,
+				BlockNumberFor<Self>,
+				Id = <Self as pallet_communities::Config>::TrackId,
 				RuntimeOrigin = PalletsOriginOf<Self>,
 			>;
 
@@ -107,7 +117,12 @@ pub mod pallet {
 				NativeBalanceOf<Self>,
 				CollectionId = CommunityIdOf<Self>,
 				ItemId = <Self as Config>::MembershipId,
-			>;
+			> + InspectEnumerable<AccountIdOf<Self>>;
+
+		/// The maximum number of tracks a community can have, including its
+		/// default track.
+		#[pallet::constant]
+		type MaxTracksPerCommunity: Get<u16>;
 	}
 
 	#[pallet::pallet]
@@ -126,6 +141,22 @@ pub mod pallet {
 			starting_at: <T as Config>::MembershipId,
 			amount: u32,
 		},
+		/// Memberships of a community being destroyed have been returned to
+		/// the memberships manager collection.
+		MembershipsReturned { id: T::CommunityId, amount: u32 },
+		/// The community with [`CommunityId`](pallet_communities::CommunityId)
+		/// has been deregistered.
+		CommunityDeregistered { id: T::CommunityId },
+		/// A new governance track has been added to a community.
+		TrackAdded {
+			id: T::CommunityId,
+			track_id: <T as pallet_communities::Config>::TrackId,
+		},
+		/// A governance track has been removed from a community.
+		TrackRemoved {
+			id: T::CommunityId,
+			track_id: <T as pallet_communities::Config>::TrackId,
+		},
 	}
 
 	// Errors inform users that something worked or went wrong.
@@ -137,6 +168,14 @@ pub mod pallet {
 		CannotRegister,
 		/// The amount of memberships to create exceeds the limit of 1024
 		CreatingTooManyMemberships,
+		/// The community still has memberships to be returned
+		MembershipsRemaining,
+		/// The community already has the maximum number of tracks
+		TooManyTracks,
+		/// Another track of the community is dispatched with the same origin
+		TrackOriginInUse,
+		/// The track doesn't belong to the community
+		InvalidTrack,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke
@@ -165,10 +204,19 @@ pub mod pallet {
 			// Register first to check if community exists
 			pallet_communities::Pallet::<T>::register(&admin_origin.clone().into(), &community_id, maybe_deposit)?;
 
+			pallet_communities::Pallet::<T>::do_set_metadata(
+				&community_id,
+				CommunityMetadata {
+					name: BoundedVec::truncate_from(name.to_vec()),
+					..Default::default()
+				},
+			);
+
 			if let Some(decision_method) = maybe_decision_method {
 				pallet_communities::Pallet::<T>::set_decision_method(
 					admin_origin.into(),
 					community_id,
+					None,
 					decision_method,
 				)?;
 			}
@@ -190,7 +238,7 @@ pub mod pallet {
 			// Create governance track for community
 			let community_origin: RuntimeOriginFor<T> = CommunityOrigin::<T>::new(community_id).into();
 			T::Tracks::insert(
-				community_id,
+				T::TrackId::for_community(community_id, 0),
 				maybe_track_info.unwrap_or_else(|| Self::default_tack(community_name)),
 				community_origin.into_caller(),
 			)?;
@@ -226,24 +274,8 @@ pub mod pallet {
 					true,
 				)?;
 
-				Self::do_set_gas_tank(&(*collection_id, id.clone()), &tank_config)?;
+				Self::do_put_up_for_sale(&id, price, &tank_config, maybe_expiration)?;
 
-				if let Some(expiration) = maybe_expiration {
-					T::CreateMemberships::set_typed_attribute(
-						collection_id,
-						&id,
-						&b"membership_expiration",
-						&expiration,
-					)?;
-				}
-
-				T::CreateMemberships::set_price(
-					&T::MembershipsManagerCollectionId::get(),
-					&id,
-					&T::MembershipsManagerOwner::get(),
-					Some(price),
-					None,
-				)?;
 				if let Some(next_id) = id.increment() {
 					id = next_id;
 					minted += 1;
@@ -269,6 +301,183 @@ pub mod pallet {
 			T::CreateMembershipsOrigin::ensure_origin(origin)?;
 			Self::do_set_gas_tank(&(community_id, membership_id), &config)
 		}
+
+		/// Returns up to [`RemoveItemsLimit`][1] memberships of a community
+		/// being destroyed to the memberships manager collection, so they can
+		/// be given out again. Memberships held by members are returned first,
+		/// followed by the ones the community account still holds unassigned.
+		/// Returned memberships are put up for sale the same way
+		/// [`create_memberships`][`Pallet::create_memberships`] does, with the
+		/// given price, gas tank and expiration.
+		///
+		/// [1]: `pallet_communities::Config::RemoveItemsLimit`
+		#[pallet::weight(<T as Config>::WeightInfo::return_memberships(
+			<T as pallet_communities::Config>::RemoveItemsLimit::get()
+		))]
+		#[pallet::call_index(3)]
+		pub fn return_memberships(
+			origin: OriginFor<T>,
+			community_id: CommunityIdOf<T>,
+			#[pallet::compact] price: NativeBalanceOf<T>,
+			tank_config: TankConfig<Weight, BlockNumberFor<T>>,
+			maybe_expiration: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::CreateMembershipsOrigin::ensure_origin(origin)?;
+			pallet_communities::Pallet::<T>::ensure_destroying(&community_id)?;
+
+			let collection_id = T::MembershipsManagerCollectionId::get();
+			let community_account = pallet_communities::Pallet::<T>::community_account(&community_id);
+			let limit = <T as pallet_communities::Config>::RemoveItemsLimit::get();
+			let mut returned = 0u32;
+			while returned < limit {
+				let Some((held_in, membership_id)) = T::CreateMemberships::items(&community_id)
+					.next()
+					.map(|membership_id| (community_id, membership_id))
+					.or_else(|| {
+						T::CreateMemberships::owned_in_collection(&collection_id, &community_account)
+							.next()
+							.map(|membership_id| (collection_id, membership_id))
+					})
+				else {
+					break;
+				};
+				T::CreateMemberships::burn(&held_in, &membership_id, None)?;
+				T::CreateMemberships::mint_into(
+					&collection_id,
+					&membership_id,
+					&T::MembershipsManagerOwner::get(),
+					&Default::default(),
+					true,
+				)?;
+				Self::do_put_up_for_sale(&membership_id, price, &tank_config, maybe_expiration)?;
+				returned += 1;
+			}
+
+			Self::deposit_event(Event::<T>::MembershipsReturned {
+				id: community_id,
+				amount: returned,
+			});
+			Ok(Some(<T as Config>::WeightInfo::return_memberships(returned)).into())
+		}
+
+		/// Completes the destruction of a community once its votes, records and
+		/// memberships have been cleaned up: removes its memberships
+		/// collection, its governance tracks and its rank at Kreivo Governance,
+		/// and moves the remaining balance of the community account to `dest`.
+		/// Only the block origin of the communities pallet can call it.
+		#[pallet::weight(<T as Config>::WeightInfo::deregister().saturating_add(
+			T::DbWeight::get().reads_writes(2, 2).saturating_mul(T::MaxTracksPerCommunity::get().into())
+		))]
+		#[pallet::call_index(4)]
+		pub fn deregister(
+			origin: OriginFor<T>,
+			community_id: CommunityIdOf<T>,
+			dest: pallet_communities::AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			<T as pallet_communities::Config>::BlockOrigin::ensure_origin(origin)?;
+			pallet_communities::Pallet::<T>::ensure_destroying(&community_id)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			let community_account = pallet_communities::Pallet::<T>::community_account(&community_id);
+			ensure!(
+				T::CreateMemberships::items(&community_id).next().is_none()
+					&& T::CreateMemberships::owned_in_collection(
+						&T::MembershipsManagerCollectionId::get(),
+						&community_account
+					)
+					.next()
+					.is_none(),
+				Error::<T>::MembershipsRemaining
+			);
+
+			// Move the remaining funds of the community account out
+			let balance =
+				<T as pallet_communities::Config>::Balances::reducible_balance(&community_account, Expendable, Polite);
+			if !balance.is_zero() {
+				<T as pallet_communities::Config>::Balances::transfer(&community_account, &dest, balance, Expendable)?;
+			}
+
+			pallet_communities::Pallet::<T>::do_finish_destroy(&community_id)?;
+
+			// Remove memberships collection for community
+			if let Some(witness) = T::CreateCollection::get_destroy_witness(&community_id) {
+				T::CreateCollection::destroy(community_id, witness, None)?;
+			}
+
+			// Remove governance tracks for community
+			for track_id in Self::community_tracks(community_id) {
+				T::Tracks::remove(track_id)?;
+			}
+
+			// Remove community from Kreivo Governance
+			while T::RankedCollective::rank_of(&community_account).is_some() {
+				T::RankedCollective::demote(&community_account)?;
+			}
+
+			Self::deposit_event(Event::<T>::CommunityDeregistered { id: community_id });
+			Ok(())
+		}
+
+		/// Adds a governance track to a community, whose referenda are
+		/// dispatched with the voice of the given `subset` of the community, or
+		/// of the whole community if none is given. Each track is dispatched
+		/// with a different origin, and polls on any of them are still tallied
+		/// by the owning community.
+		#[pallet::weight(<T as Config>::WeightInfo::add_track().saturating_add(
+			T::DbWeight::get().reads(T::MaxTracksPerCommunity::get().into())
+		))]
+		#[pallet::call_index(5)]
+		pub fn add_track(
+			origin: OriginFor<T>,
+			maybe_subset: Option<Subset<T>>,
+			track_info: TrackInfoOf<T>,
+		) -> DispatchResult {
+			let community_id = <T as pallet_communities::Config>::AdminOrigin::ensure_origin(origin)?;
+			pallet_communities::Pallet::<T>::ensure_active(&community_id)?;
+
+			let mut track_origin = CommunityOrigin::<T>::new(community_id);
+			if let Some(subset) = maybe_subset {
+				track_origin.with_subset(subset);
+			}
+			let track_origin = RuntimeOriginFor::<T>::from(track_origin).into_caller();
+			ensure!(
+				T::Tracks::track_for(&track_origin).is_err(),
+				Error::<T>::TrackOriginInUse
+			);
+
+			let track_id = (0..T::MaxTracksPerCommunity::get())
+				.map(|index| T::TrackId::for_community(community_id, index))
+				.find(|track_id| T::Tracks::info(*track_id).is_none())
+				.ok_or(Error::<T>::TooManyTracks)?;
+			T::Tracks::insert(track_id, track_info, track_origin)?;
+
+			Self::deposit_event(Event::<T>::TrackAdded {
+				id: community_id,
+				track_id,
+			});
+			Ok(())
+		}
+
+		/// Removes a governance track from a community, which can be its
+		/// default track as well. Referenda ongoing on the track should be
+		/// finished before removing it.
+		#[pallet::call_index(6)]
+		pub fn remove_track(
+			origin: OriginFor<T>,
+			track_id: <T as pallet_communities::Config>::TrackId,
+		) -> DispatchResult {
+			let community_id = <T as pallet_communities::Config>::AdminOrigin::ensure_origin(origin)?;
+			pallet_communities::Pallet::<T>::ensure_active(&community_id)?;
+			ensure!(track_id.community_id() == community_id, Error::<T>::InvalidTrack);
+
+			T::Tracks::remove(track_id)?;
+
+			Self::deposit_event(Event::<T>::TrackRemoved {
+				id: community_id,
+				track_id,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -283,7 +492,45 @@ pub mod pallet {
 			Ok(())
 		}
 
-		fn default_tack(name: &str) -> TrackInfoOf<T> {
+		/// Puts a membership of the memberships manager collection up for
+		/// sale, setting its gas tank and, if given, its expiration.
+		pub(crate) fn do_put_up_for_sale(
+			membership_id: &<T as Config>::MembershipId,
+			price: NativeBalanceOf<T>,
+			tank_config: &TankConfig<Weight, BlockNumberFor<T>>,
+			maybe_expiration: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let collection_id = &T::MembershipsManagerCollectionId::get();
+			Self::do_set_gas_tank(&(*collection_id, membership_id.clone()), tank_config)?;
+
+			if let Some(expiration) = maybe_expiration {
+				T::CreateMemberships::set_typed_attribute(
+					collection_id,
+					membership_id,
+					&b"membership_expiration",
+					&expiration,
+				)?;
+			}
+
+			T::CreateMemberships::set_price(
+				collection_id,
+				membership_id,
+				&T::MembershipsManagerOwner::get(),
+				Some(price),
+				None,
+			)
+		}
+
+		/// The governance tracks a community has, out of the ones it can have.
+		pub(crate) fn community_tracks(
+			community_id: CommunityIdOf<T>,
+		) -> impl Iterator<Item = <T as pallet_communities::Config>::TrackId> {
+			(0..T::MaxTracksPerCommunity::get())
+				.map(move |index| T::TrackId::for_community(community_id, index))
+				.filter(|track_id| T::Tracks::info(*track_id).is_some())
+		}
+
+		pub(crate) fn default_tack(name: &str) -> TrackInfoOf<T> {
 			use sp_runtime::Perbill;
 			TrackInfo {
 				name: str_array(name),
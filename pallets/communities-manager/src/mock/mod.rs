@@ -3,14 +3,19 @@ use fc_traits_gas_tank::NonFungibleGasTank;
 use fc_traits_memberships::NonFungiblesMemberships;
 use frame_support::{
 	assert_ok, derive_impl, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOf, EqualPrivilegeOnly, VariantCountOf},
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOf, EqualPrivilegeOnly, Nothing, VariantCountOf,
+	},
 	PalletId,
 };
 use frame_system::{EnsureNever, EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
-use pallet_communities::{origin::EnsureCommunity, Tally, VoteWeight};
+use pallet_communities::{
+	origin::{EnsureCommunity, EnsureCommunityTrack},
+	Tally, VoteWeight,
+};
 use sp_io::TestExternalities;
 use sp_runtime::{
-	traits::{IdentifyAccount, IdentityLookup, Verify},
+	traits::{IdentifyAccount, IdentityLookup, TryConvert, Verify},
 	MultiSignature,
 };
 pub use virto_common::{CommunityId, MembershipId};
@@ -153,7 +158,7 @@ impl pallet_referenda::Config for Test {
 
 impl pallet_referenda_tracks::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type TrackId = CommunityId;
+	type TrackId = u32;
 	type MaxTracks = MaxTracks;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureRoot<AccountId>;
@@ -162,6 +167,13 @@ impl pallet_referenda_tracks::Config for Test {
 	type BenchmarkHelper = TracksBenchmarkHelper;
 }
 
+pub struct TrackForOrigin;
+impl TryConvert<OriginCaller, u32> for TrackForOrigin {
+	fn try_convert(origin: OriginCaller) -> Result<u32, OriginCaller> {
+		Tracks::track_for(&origin).map_err(|_| origin)
+	}
+}
+
 type Deposit = Option<(Balance, AccountId, AccountId)>;
 parameter_types! {
 	pub const CommunitiesPalletId: PalletId = PalletId(*b"kv/comms");
@@ -203,7 +215,23 @@ impl pallet_nfts::Config for Test {
 
 impl pallet_communities::Config for Test {
 	type PalletId = CommunitiesPalletId;
+	type RemoveItemsLimit = ConstU32<10>;
+	type MaxDelegators = ConstU32<5>;
+	type VoteLockingPeriod = ConstU64<2>;
+	type VoteCurve = ();
+	type ExpiredMemberships = Nothing;
+	type Memberships = Memberships;
+	type ApplicationDeposit = ConstU64<10>;
+	type ApplicationPeriod = ConstU64<100>;
+	type MaxMembersPerBatch = ConstU32<10>;
+	type InvitationSignature = MultiSignature;
+	type InvitationPublic = AccountPublic;
+	type TrackForOrigin = TrackForOrigin;
+	type SpendOrigin = EnsureCommunityTrack<Self>;
+	type PayoutPeriod = ConstU64<100>;
+	type SubsetApprovalPeriod = ConstU64<100>;
 	type CommunityId = CommunityId;
+	type TrackId = u32;
 	type MembershipId = MembershipId;
 	type Assets = Assets;
 	type AssetsFreezer = AssetsFreezer;
@@ -214,10 +242,12 @@ impl pallet_communities::Config for Test {
 	type CreateOrigin = EnsureNever<Deposit>;
 	type AdminOrigin = EnsureCommunity<Self>;
 	type MemberMgmtOrigin = EnsureCommunity<Self>;
+	type BlockOrigin = EnsureRoot<AccountId>;
 	type RuntimeCall = RuntimeCall;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = WeightInfo;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = CommunityBenchmarkHelper;
@@ -238,6 +268,7 @@ impl Config for Test {
 	type MembershipsManagerOwner = RootAccount;
 	type CreateMemberships = Memberships;
 	type MakeTank = NonFungibleGasTank<Test, Memberships, pallet_nfts::ItemConfig>;
+	type MaxTracksPerCommunity = ConstU16<3>;
 
 	type WeightInfo = WeightInfo;
 }
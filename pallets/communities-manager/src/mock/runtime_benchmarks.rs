@@ -34,6 +34,18 @@ impl pallet_communities::BenchmarkHelper<Test> for CommunityBenchmarkHelper {
 		unimplemented!()
 	}
 
+	fn expire_membership(_: CommunityIdOf<Test>, _: MembershipIdOf<Test>) -> Result<(), BenchmarkError> {
+		unimplemented!()
+	}
+
+	fn invitation_signer() -> (AccountPublic, AccountId) {
+		unimplemented!()
+	}
+
+	fn sign_invitation(_: &AccountPublic, _: &[u8]) -> MultiSignature {
+		unimplemented!()
+	}
+
 	fn prepare_track(_: pallet_communities::PalletsOriginOf<Test>) -> Result<(), BenchmarkError> {
 		unimplemented!()
 	}
@@ -2,10 +2,17 @@ use crate::mock::new_test_ext;
 use crate::{
 	mock::*,
 	weights::{SubstrateWeight, WeightInfo},
-	TankConfig,
+	Error, Event, TankConfig,
+};
+use frame_support::traits::{
+	nonfungibles_v2::{Inspect, Trading},
+	RankedMembers,
 };
-use frame_support::assert_ok;
 use frame_support::weights::Weight;
+use frame_support::BoundedVec;
+use frame_support::{assert_noop, assert_ok};
+use pallet_communities::{origin::Subset, types::CommunityTrackId};
+use pallet_referenda::TracksInfo;
 
 #[test]
 fn create_membership_works() {
@@ -27,6 +34,428 @@ fn create_membership_works() {
 	})
 }
 
+#[test]
+fn register_sets_community_name() {
+	new_test_ext().execute_with(|| {
+		const ALICE: AccountId = AccountId::new([1; 32]);
+
+		assert_ok!(CommunitiesManager::register(
+			RuntimeOrigin::root(),
+			1,
+			BoundedVec::truncate_from(b"Test Community".to_vec()),
+			ALICE,
+			None,
+			None,
+		));
+
+		let metadata = Communities::metadata(&1).expect("metadata is set on register");
+		assert_eq!(metadata.name.as_slice(), b"Test Community");
+	})
+}
+
+mod deregister {
+	use super::*;
+
+	const ALICE: AccountId = AccountId::new([1; 32]);
+	const BOB: AccountId = AccountId::new([2; 32]);
+	const COMMUNITY: CommunityId = 1;
+
+	fn register_community() {
+		assert_ok!(CommunitiesManager::register(
+			RuntimeOrigin::root(),
+			COMMUNITY,
+			BoundedVec::truncate_from(b"Test Community".to_vec()),
+			ALICE,
+			None,
+			None,
+		));
+	}
+
+	#[test]
+	fn fails_if_community_is_not_being_destroyed() {
+		new_test_ext().execute_with(|| {
+			register_community();
+			assert_noop!(
+				CommunitiesManager::return_memberships(
+					RuntimeOrigin::root(),
+					COMMUNITY,
+					1,
+					TankConfig::default(),
+					None
+				),
+				pallet_communities::Error::<Test>::NotBeingDestroyed
+			);
+			assert_noop!(
+				CommunitiesManager::deregister(RuntimeOrigin::root(), COMMUNITY, ALICE),
+				pallet_communities::Error::<Test>::NotBeingDestroyed
+			);
+		})
+	}
+
+	#[test]
+	fn fails_if_not_block_origin() {
+		new_test_ext().execute_with(|| {
+			register_community();
+			assert_ok!(Communities::start_destroy(RuntimeOrigin::root(), COMMUNITY));
+			assert_noop!(
+				CommunitiesManager::deregister(RuntimeOrigin::signed(ALICE), COMMUNITY, ALICE),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn fails_if_memberships_remain() {
+		new_test_ext().execute_with(|| {
+			use frame_support::traits::nonfungibles_v2::Mutate;
+
+			register_community();
+			let community_account = Communities::community_account(&COMMUNITY);
+			assert_ok!(Memberships::mint_into(
+				&COMMUNITY,
+				&1,
+				&community_account,
+				&Default::default(),
+				true
+			));
+			assert_ok!(Communities::start_destroy(RuntimeOrigin::root(), COMMUNITY));
+
+			assert_noop!(
+				CommunitiesManager::deregister(RuntimeOrigin::root(), COMMUNITY, ALICE),
+				Error::<Test>::MembershipsRemaining
+			);
+		})
+	}
+
+	#[test]
+	fn fails_if_unassigned_memberships_remain() {
+		new_test_ext().execute_with(|| {
+			use frame_support::traits::nonfungibles_v2::Mutate;
+
+			register_community();
+			let community_account = Communities::community_account(&COMMUNITY);
+			assert_ok!(Memberships::mint_into(
+				&MembershipsManagerCollectionId::get(),
+				&1,
+				&community_account,
+				&Default::default(),
+				true
+			));
+			assert_ok!(Communities::start_destroy(RuntimeOrigin::root(), COMMUNITY));
+
+			assert_noop!(
+				CommunitiesManager::deregister(RuntimeOrigin::root(), COMMUNITY, ALICE),
+				Error::<Test>::MembershipsRemaining
+			);
+		})
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			use frame_support::traits::{fungible::Mutate as _, nonfungibles_v2::Mutate};
+
+			register_community();
+			assert_ok!(CommunitiesManager::add_track(
+				RuntimeOrigin::signed(ALICE),
+				Some(Subset::AtLeastRank(1)),
+				CommunitiesManager::default_tack("Treasury"),
+			));
+			let community_account = Communities::community_account(&COMMUNITY);
+			for membership_id in [1, 2] {
+				assert_ok!(Memberships::mint_into(
+					&COMMUNITY,
+					&membership_id,
+					&community_account,
+					&Default::default(),
+					true
+				));
+			}
+			// A membership the community bought but never assigned to a member
+			assert_ok!(Memberships::mint_into(
+				&MembershipsManagerCollectionId::get(),
+				&3,
+				&community_account,
+				&Default::default(),
+				true
+			));
+			assert_ok!(Balances::mint_into(&community_account, 100));
+			assert_ok!(Communities::start_destroy(RuntimeOrigin::root(), COMMUNITY));
+
+			assert_noop!(
+				CommunitiesManager::return_memberships(
+					RuntimeOrigin::signed(ALICE),
+					COMMUNITY,
+					1,
+					TankConfig::default(),
+					None
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(CommunitiesManager::return_memberships(
+				RuntimeOrigin::root(),
+				COMMUNITY,
+				10,
+				TankConfig::default(),
+				Some(BlockNumber::MAX),
+			));
+			System::assert_has_event(
+				Event::<Test>::MembershipsReturned {
+					id: COMMUNITY,
+					amount: 3,
+				}
+				.into(),
+			);
+			for membership_id in [1, 3] {
+				assert_eq!(
+					Memberships::owner(&MembershipsManagerCollectionId::get(), &membership_id),
+					Some(RootAccount::get())
+				);
+			}
+			assert_eq!(
+				Memberships::item_price(&MembershipsManagerCollectionId::get(), &3),
+				Some(10)
+			);
+			// Returned memberships are put up for sale again
+			assert_eq!(
+				Memberships::item_price(&MembershipsManagerCollectionId::get(), &1),
+				Some(10)
+			);
+			assert_eq!(
+				Memberships::typed_system_attribute(
+					&MembershipsManagerCollectionId::get(),
+					Some(&1),
+					&b"membership_expiration"
+				),
+				Some(BlockNumber::MAX)
+			);
+
+			assert_ok!(CommunitiesManager::deregister(RuntimeOrigin::root(), COMMUNITY, BOB));
+
+			assert!(!Communities::community_exists(&COMMUNITY));
+			assert_eq!(Balances::free_balance(&community_account), 0);
+			assert_eq!(Balances::free_balance(&BOB), 100);
+			assert!(Memberships::collection_owner(&COMMUNITY).is_none());
+			assert!(<Tracks as TracksInfo<_, _>>::info(COMMUNITY.into()).is_none());
+			assert!(<Tracks as TracksInfo<_, _>>::info(u32::for_community(COMMUNITY, 1)).is_none());
+			assert!(Collective::rank_of(&community_account).is_none());
+			System::assert_last_event(Event::<Test>::CommunityDeregistered { id: COMMUNITY }.into());
+		})
+	}
+}
+
+mod add_track {
+	use super::*;
+	use pallet_communities::Origin as CommunityOrigin;
+
+	const ALICE: AccountId = AccountId::new([1; 32]);
+	const COMMUNITY: CommunityId = 1;
+
+	fn register_community() {
+		assert_ok!(CommunitiesManager::register(
+			RuntimeOrigin::root(),
+			COMMUNITY,
+			BoundedVec::truncate_from(b"Test Community".to_vec()),
+			ALICE,
+			None,
+			None,
+		));
+	}
+
+	#[test]
+	fn fails_if_not_community_admin() {
+		new_test_ext().execute_with(|| {
+			register_community();
+			assert_noop!(
+				CommunitiesManager::add_track(
+					RuntimeOrigin::root(),
+					Some(Subset::AtLeastRank(1)),
+					CommunitiesManager::default_tack("Treasury"),
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			register_community();
+			assert_ok!(CommunitiesManager::add_track(
+				RuntimeOrigin::signed(ALICE),
+				Some(Subset::AtLeastRank(1)),
+				CommunitiesManager::default_tack("Treasury"),
+			));
+
+			let track_id = u32::for_community(COMMUNITY, 1);
+			System::assert_last_event(
+				Event::<Test>::TrackAdded {
+					id: COMMUNITY,
+					track_id,
+				}
+				.into(),
+			);
+			assert!(<Tracks as TracksInfo<_, _>>::info(track_id).is_some());
+			assert_eq!(track_id.community_id(), COMMUNITY);
+
+			let mut track_origin = CommunityOrigin::<Test>::new(COMMUNITY);
+			track_origin.with_subset(Subset::AtLeastRank(1));
+			assert_eq!(
+				<Tracks as TracksInfo<_, _>>::track_for(&RuntimeOrigin::from(track_origin).caller),
+				Ok(track_id)
+			);
+			assert_eq!(
+				<Tracks as TracksInfo<_, _>>::track_for(
+					&RuntimeOrigin::from(CommunityOrigin::<Test>::new(COMMUNITY)).caller
+				),
+				Ok(COMMUNITY.into())
+			);
+		})
+	}
+
+	#[test]
+	fn fails_if_community_has_too_many_tracks() {
+		new_test_ext().execute_with(|| {
+			register_community();
+			for rank in [1, 2] {
+				assert_ok!(CommunitiesManager::add_track(
+					RuntimeOrigin::signed(ALICE),
+					Some(Subset::AtLeastRank(rank)),
+					CommunitiesManager::default_tack("Track"),
+				));
+			}
+
+			assert_noop!(
+				CommunitiesManager::add_track(
+					RuntimeOrigin::signed(ALICE),
+					Some(Subset::AtLeastRank(3)),
+					CommunitiesManager::default_tack("Track"),
+				),
+				Error::<Test>::TooManyTracks
+			);
+		})
+	}
+
+	#[test]
+	fn fails_if_another_track_has_the_same_origin() {
+		new_test_ext().execute_with(|| {
+			register_community();
+
+			// The default track is dispatched with the voice of the whole community
+			assert_noop!(
+				CommunitiesManager::add_track(
+					RuntimeOrigin::signed(ALICE),
+					None,
+					CommunitiesManager::default_tack("Track"),
+				),
+				Error::<Test>::TrackOriginInUse
+			);
+		})
+	}
+}
+
+mod remove_track {
+	use super::*;
+	use pallet_communities::Origin as CommunityOrigin;
+
+	const ALICE: AccountId = AccountId::new([1; 32]);
+	const COMMUNITY: CommunityId = 1;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext = super::new_test_ext();
+		ext.execute_with(|| {
+			assert_ok!(CommunitiesManager::register(
+				RuntimeOrigin::root(),
+				COMMUNITY,
+				BoundedVec::truncate_from(b"Test Community".to_vec()),
+				ALICE,
+				None,
+				None,
+			));
+			assert_ok!(CommunitiesManager::add_track(
+				RuntimeOrigin::signed(ALICE),
+				Some(Subset::AtLeastRank(1)),
+				CommunitiesManager::default_tack("Treasury"),
+			));
+		});
+		ext
+	}
+
+	#[test]
+	fn fails_if_not_community_admin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				CommunitiesManager::remove_track(RuntimeOrigin::root(), u32::for_community(COMMUNITY, 1)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn fails_if_track_belongs_to_another_community() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				CommunitiesManager::remove_track(RuntimeOrigin::signed(ALICE), u32::for_community(2, 1)),
+				Error::<Test>::InvalidTrack
+			);
+		})
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			let track_id = u32::for_community(COMMUNITY, 1);
+			assert_ok!(CommunitiesManager::remove_track(RuntimeOrigin::signed(ALICE), track_id));
+
+			System::assert_last_event(
+				Event::<Test>::TrackRemoved {
+					id: COMMUNITY,
+					track_id,
+				}
+				.into(),
+			);
+			assert!(<Tracks as TracksInfo<_, _>>::info(track_id).is_none());
+
+			// The index of the removed track is given to the next one
+			assert_ok!(CommunitiesManager::add_track(
+				RuntimeOrigin::signed(ALICE),
+				Some(Subset::AtLeastRank(2)),
+				CommunitiesManager::default_tack("Treasury"),
+			));
+			System::assert_last_event(
+				Event::<Test>::TrackAdded {
+					id: COMMUNITY,
+					track_id,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn default_track_can_be_replaced() {
+		new_test_ext().execute_with(|| {
+			let default_track = u32::for_community(COMMUNITY, 0);
+			assert_ok!(CommunitiesManager::remove_track(
+				RuntimeOrigin::signed(ALICE),
+				default_track
+			));
+
+			assert_ok!(CommunitiesManager::add_track(
+				RuntimeOrigin::signed(ALICE),
+				None,
+				CommunitiesManager::default_tack("Community"),
+			));
+			assert_eq!(
+				<Tracks as TracksInfo<_, _>>::track_for(
+					&RuntimeOrigin::from(CommunityOrigin::<Test>::new(COMMUNITY)).caller
+				),
+				Ok(default_track)
+			);
+		})
+	}
+}
+
 #[test]
 fn weights() {
 	let max_total_extrinsics = MAX_BLOCK_WEIGHT * NORMAL_DISPATCH_RATIO;
@@ -49,6 +478,13 @@ fn weights() {
 			SubstrateWeight::<Test>::create_memberships(1024),
 		),
 		("set_gas_tank", SubstrateWeight::<Test>::set_gas_tank()),
+		(
+			"return_memberships(100)",
+			SubstrateWeight::<Test>::return_memberships(100),
+		),
+		("deregister", SubstrateWeight::<Test>::deregister()),
+		("add_track", SubstrateWeight::<Test>::add_track()),
+		("remove_track", SubstrateWeight::<Test>::remove_track()),
 	];
 
 	for (function, weight) in calls {
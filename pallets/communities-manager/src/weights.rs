@@ -10,6 +10,10 @@ pub trait WeightInfo {
 	fn register() -> Weight;
 	fn create_memberships(q: u32, ) -> Weight;
 	fn set_gas_tank() -> Weight;
+	fn return_memberships(q: u32, ) -> Weight;
+	fn deregister() -> Weight;
+	fn add_track() -> Weight;
+	fn remove_track() -> Weight;
 }
 
 /// Weights for pallet_communities using the Substrate node and recommended hardware.
@@ -88,6 +92,156 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:101 w:200)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:2 w:2)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `CommunityMemberships::CollectionConfigOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:100 w:200)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:0 w:200)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemMetadataOf` (r:100 w:0)
+	/// Proof: `CommunityMemberships::ItemMetadataOf` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemPriceOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::ItemPriceOf` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemAttributesApprovalsOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:100 w:100)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// The range of component `q` is `[0, 100]`.
+	fn return_memberships(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `433`
+		//  Estimated: `3605 + q * (3334 ±0)`
+		// Minimum execution time: 27_341_000 picoseconds.
+		Weight::from_parts(33_917_000, 0)
+			.saturating_add(Weight::from_parts(0, 3605))
+			// Standard Error: 362_070
+			.saturating_add(Weight::from_parts(112_604_000, 0).saturating_mul(q.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(q.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(q.into())))
+			.saturating_add(Weight::from_parts(0, 3334).saturating_mul(q.into()))
+	}
+	/// Storage: `Communities::Info` (r:1 w:1)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityPolls` (r:1 w:0)
+	/// Proof: `Communities::CommunityPolls` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipApplications` (r:1 w:0)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovals` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovals` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovalsCount` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovalsCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:0)
+	/// Proof: `Communities::MembershipInvitations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:1 w:0)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:0)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::TrackDecisionMethod` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackSpendLimits` (r:1 w:0)
+	/// Proof: `Communities::TrackSpendLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Spends` (r:1 w:0)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityIdFor` (r:2 w:1)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionAccount` (r:0 w:1)
+	/// Proof: `CommunityMemberships::CollectionAccount` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionRoleOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::CollectionRoleOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionConfigOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::CollectionConfigOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::Tracks` (r:1 w:1)
+	/// Proof: `CommunityTracks::Tracks` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::TracksIds` (r:1 w:1)
+	/// Proof: `CommunityTracks::TracksIds` (`max_values`: Some(1), `max_size`: Some(131076), added: 131571, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:0 w:1)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::Members` (r:1 w:1)
+	/// Proof: `KreivoCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::MemberCount` (r:1 w:1)
+	/// Proof: `KreivoCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::IdToIndex` (r:1 w:2)
+	/// Proof: `KreivoCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::IndexToId` (r:1 w:1)
+	/// Proof: `KreivoCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Metadata` (r:0 w:1)
+	/// Proof: `Communities::Metadata` (`max_values`: None, `max_size`: Some(629), added: 3104, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:0 w:1)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityRankBounds` (r:0 w:1)
+	/// Proof: `Communities::CommunityRankBounds` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SpendCount` (r:0 w:1)
+	/// Proof: `Communities::SpendCount` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn deregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `132561`
+		// Minimum execution time: 187_304_000 picoseconds.
+		Weight::from_parts(199_826_000, 0)
+			.saturating_add(Weight::from_parts(0, 132561))
+			.saturating_add(T::DbWeight::get().reads(25))
+			.saturating_add(T::DbWeight::get().writes(20))
+	}
+	/// Storage: `Communities::CommunityIdFor` (r:1 w:0)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:1 w:1)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::Tracks` (r:2 w:1)
+	/// Proof: `CommunityTracks::Tracks` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::TracksIds` (r:1 w:1)
+	/// Proof: `CommunityTracks::TracksIds` (`max_values`: Some(1), `max_size`: Some(262148), added: 262643, mode: `MaxEncodedLen`)
+	fn add_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `263633`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(43_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 263633))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Communities::CommunityIdFor` (r:1 w:0)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::Tracks` (r:1 w:1)
+	/// Proof: `CommunityTracks::Tracks` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::TracksIds` (r:1 w:1)
+	/// Proof: `CommunityTracks::TracksIds` (`max_values`: Some(1), `max_size`: Some(262148), added: 262643, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:0 w:1)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	fn remove_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `540`
+		//  Estimated: `263633`
+		// Minimum execution time: 36_482_000 picoseconds.
+		Weight::from_parts(38_019_000, 0)
+			.saturating_add(Weight::from_parts(0, 263633))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
 
 impl WeightInfo for () {
@@ -164,4 +318,154 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:101 w:200)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:2 w:2)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `CommunityMemberships::CollectionConfigOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:100 w:200)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:0 w:200)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemMetadataOf` (r:100 w:0)
+	/// Proof: `CommunityMemberships::ItemMetadataOf` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemPriceOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::ItemPriceOf` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemAttributesApprovalsOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(1001), added: 3476, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:100 w:100)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// The range of component `q` is `[0, 100]`.
+	fn return_memberships(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `433`
+		//  Estimated: `3605 + q * (3334 ±0)`
+		// Minimum execution time: 27_341_000 picoseconds.
+		Weight::from_parts(33_917_000, 0)
+			.saturating_add(Weight::from_parts(0, 3605))
+			// Standard Error: 362_070
+			.saturating_add(Weight::from_parts(112_604_000, 0).saturating_mul(q.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(q.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(q.into())))
+			.saturating_add(Weight::from_parts(0, 3334).saturating_mul(q.into()))
+	}
+	/// Storage: `Communities::Info` (r:1 w:1)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityPolls` (r:1 w:0)
+	/// Proof: `Communities::CommunityPolls` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipApplications` (r:1 w:0)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovals` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovals` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovalsCount` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovalsCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:0)
+	/// Proof: `Communities::MembershipInvitations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:1 w:0)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:0)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::TrackDecisionMethod` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackSpendLimits` (r:1 w:0)
+	/// Proof: `Communities::TrackSpendLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Spends` (r:1 w:0)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityIdFor` (r:2 w:1)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionAccount` (r:0 w:1)
+	/// Proof: `CommunityMemberships::CollectionAccount` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionRoleOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::CollectionRoleOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionConfigOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::CollectionConfigOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::Tracks` (r:1 w:1)
+	/// Proof: `CommunityTracks::Tracks` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::TracksIds` (r:1 w:1)
+	/// Proof: `CommunityTracks::TracksIds` (`max_values`: Some(1), `max_size`: Some(131076), added: 131571, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:0 w:1)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::Members` (r:1 w:1)
+	/// Proof: `KreivoCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::MemberCount` (r:1 w:1)
+	/// Proof: `KreivoCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::IdToIndex` (r:1 w:2)
+	/// Proof: `KreivoCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `KreivoCollective::IndexToId` (r:1 w:1)
+	/// Proof: `KreivoCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Metadata` (r:0 w:1)
+	/// Proof: `Communities::Metadata` (`max_values`: None, `max_size`: Some(629), added: 3104, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:0 w:1)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityRankBounds` (r:0 w:1)
+	/// Proof: `Communities::CommunityRankBounds` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SpendCount` (r:0 w:1)
+	/// Proof: `Communities::SpendCount` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn deregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `132561`
+		// Minimum execution time: 187_304_000 picoseconds.
+		Weight::from_parts(199_826_000, 0)
+			.saturating_add(Weight::from_parts(0, 132561))
+			.saturating_add(RocksDbWeight::get().reads(25))
+			.saturating_add(RocksDbWeight::get().writes(20))
+	}
+	/// Storage: `Communities::CommunityIdFor` (r:1 w:0)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:1 w:1)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::Tracks` (r:2 w:1)
+	/// Proof: `CommunityTracks::Tracks` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::TracksIds` (r:1 w:1)
+	/// Proof: `CommunityTracks::TracksIds` (`max_values`: Some(1), `max_size`: Some(262148), added: 262643, mode: `MaxEncodedLen`)
+	fn add_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `263633`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(43_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 263633))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `Communities::CommunityIdFor` (r:1 w:0)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::Tracks` (r:1 w:1)
+	/// Proof: `CommunityTracks::Tracks` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::TracksIds` (r:1 w:1)
+	/// Proof: `CommunityTracks::TracksIds` (`max_values`: Some(1), `max_size`: Some(262148), added: 262643, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:0 w:1)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	fn remove_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `540`
+		//  Estimated: `263633`
+		// Minimum execution time: 36_482_000 picoseconds.
+		Weight::from_parts(38_019_000, 0)
+			.saturating_add(Weight::from_parts(0, 263633))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
 }
\ No newline at end of file
@@ -3,23 +3,29 @@ use super::*;
 
 use self::{
 	types::{
-		AccountIdOf, AssetIdOf, CommunityIdOf, DecisionMethodFor, MembershipIdOf, NativeBalanceOf, PalletsOriginOf,
-		PollIndexOf, RuntimeCallFor, Vote,
+		AccountIdOf, AssetIdOf, CommunityAssetMetadata, CommunityIdOf, CommunityMetadata, CommunityMetadataFor,
+		CommunityState, CommunityTrackId, DecisionMethodFor, MembershipIdOf, NativeBalanceOf, PalletsOriginOf,
+		PollIndexOf, RankBounds, RankHistoryOf, RuntimeCallFor, SpendAsset, Vote,
 	},
-	CommunityDecisionMethod, DecisionMethod, Event, FreezeReason, Pallet as Communities,
+	AssetCreators, CommunityAssets, CommunityDecisionMethod, DecisionMethod, Event, FreezeReason,
+	MembershipApplications, Pallet as Communities, Spends,
 };
 use fc_traits_memberships::{Inspect, Rank};
 use frame_benchmarking::v2::*;
-use frame_support::traits::{
-	fungible::{InspectFreeze, Mutate},
-	fungibles::Mutate as FunsMutate,
-	OriginTrait,
+use frame_support::{
+	traits::{
+		fungible::{Inspect as _, InspectFreeze, Mutate},
+		fungibles::{Create, Inspect as FunsInspect, Mutate as FunsMutate},
+		OriginTrait,
+	},
+	BoundedVec,
 };
 use frame_system::{
 	pallet_prelude::{BlockNumberFor, OriginFor},
 	RawOrigin,
 };
-use sp_runtime::traits::{Hash, StaticLookup};
+use parity_scale_codec::Encode;
+use sp_runtime::traits::{AccountIdConversion, Hash, StaticLookup};
 use sp_std::{boxed::Box, vec, vec::Vec};
 
 type RuntimeEventFor<T> = <T as Config>::RuntimeEvent;
@@ -81,11 +87,48 @@ where
 		community_params::<T>(maybe_decision_method);
 
 	Pallet::<T>::create(origin.clone(), admin_origin_caller, community_id)?;
-	Pallet::<T>::set_decision_method(admin_origin.clone(), community_id, decision_method)?;
+	Pallet::<T>::set_decision_method(admin_origin.clone(), community_id, None, decision_method)?;
 
 	Ok((community_id, admin_origin))
 }
 
+fn community_metadata<T: Config>() -> CommunityMetadataFor<T> {
+	CommunityMetadata {
+		name: BoundedVec::truncate_from(vec![b'a'; 64]),
+		description: BoundedVec::truncate_from(vec![b'b'; 256]),
+		main_url: BoundedVec::truncate_from(vec![b'c'; 256]),
+		logo: Some(<T as frame_system::Config>::Hashing::hash(b"logo")),
+	}
+}
+
+fn community_asset_metadata() -> CommunityAssetMetadata {
+	CommunityAssetMetadata {
+		name: BoundedVec::truncate_from(vec![b'a'; 32]),
+		symbol: BoundedVec::truncate_from(vec![b'b'; 8]),
+		decimals: 12,
+	}
+}
+
+/// Creates an asset issued by a community, funding the community account to
+/// cover the deposits of its metadata.
+fn create_community_asset<T: Config>(
+	community_id: CommunityIdOf<T>,
+	origin: OriginFor<T>,
+) -> Result<AssetIdOf<T>, BenchmarkError>
+where
+	AssetIdOf<T>: From<u32>,
+{
+	let asset_id = T::BenchmarkHelper::community_asset_id();
+	let initial_balance: NativeBalanceOf<T> = 1_000_000_000_000_000u128
+		.try_into()
+		.map_err(|_| BenchmarkError::Stop("could not mint balance for the community account"))?;
+	T::Balances::mint_into(&Communities::<T>::community_account(&community_id), initial_balance)?;
+
+	Pallet::<T>::create_asset(origin, asset_id.clone(), 1u32.into(), false, community_asset_metadata())?;
+
+	Ok(asset_id)
+}
+
 type Member<T> = (AccountIdOf<T>, MembershipIdOf<T>);
 
 /// Initializes the memberships of a community built for benchmarking
@@ -114,6 +157,62 @@ where
 	Ok(members_with_memberships.collect())
 }
 
+/// Adds `count` members to the community, each one of them delegating its
+/// voting power to `delegate`.
+fn setup_delegators<T>(
+	origin: OriginFor<T>,
+	community_id: CommunityIdOf<T>,
+	delegate: MembershipIdOf<T>,
+	count: u32,
+) -> Result<(), BenchmarkError>
+where
+	T: Config,
+	T::MembershipId: From<u32>,
+{
+	// memberships issued by `setup_members` come first
+	let first_id = T::BenchmarkHelper::community_desired_size();
+
+	for i in 0..count {
+		let who = setup_account::<T>("delegator", i, 0)?;
+		let membership_id = MembershipIdOf::<T>::from(first_id + i);
+		T::BenchmarkHelper::issue_membership(community_id, membership_id)?;
+
+		Pallet::<T>::add_member(origin.clone(), T::Lookup::unlookup(who.clone()))?;
+		Pallet::<T>::promote(origin.clone(), membership_id)?;
+		Pallet::<T>::delegate(RawOrigin::Signed(who).into(), membership_id, delegate)?;
+	}
+
+	Ok(())
+}
+
+/// Approves a spend of the native token from the treasury of a community,
+/// limiting the spends of its default track to the amount of the spend.
+/// Returns the beneficiary of the spend.
+fn approve_spend<T: Config>(
+	community_id: CommunityIdOf<T>,
+	origin: OriginFor<T>,
+	amount: NativeBalanceOf<T>,
+) -> Result<AccountIdOf<T>, BenchmarkError> {
+	T::BenchmarkHelper::prepare_track(origin.clone().into_caller())?;
+	Pallet::<T>::set_spend_limit(
+		origin.clone(),
+		T::TrackId::for_community(community_id, 0),
+		SpendAsset::Native,
+		Some(amount),
+	)?;
+
+	let beneficiary = setup_account::<T>("beneficiary", 0, 0)?;
+	Pallet::<T>::spend(
+		origin,
+		SpendAsset::Native,
+		amount,
+		T::Lookup::unlookup(beneficiary.clone()),
+		None,
+	)?;
+
+	Ok(beneficiary)
+}
+
 fn prepare_track_and_prepare_poll<T: Config>(
 	track_origin: PalletsOriginOf<T>,
 	submitter: AccountIdOf<T>,
@@ -189,15 +288,201 @@ mod benchmarks {
 		Communities::<T>::create(RawOrigin::Root.into(), admin_origin.clone(), id)?;
 		CommunityDecisionMethod::<T>::set(id, decision_method);
 
+		let track_id = T::TrackId::for_community(id, 1);
+
 		#[extrinsic_call]
 		_(
 			admin_origin,
 			id,
+			Some(track_id),
 			DecisionMethod::CommunityAsset(T::BenchmarkHelper::community_asset_id(), 10u64.into()),
 		);
 
 		// verification code
-		assert_has_event::<T>(Event::DecisionMethodSet { id }.into());
+		assert_has_event::<T>(
+			Event::DecisionMethodSet {
+				id,
+				track_id: Some(track_id),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_metadata() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _, _, admin_origin) = community_params::<T>(None);
+		Communities::<T>::create(RawOrigin::Root.into(), admin_origin.clone(), id)?;
+		let metadata = community_metadata::<T>();
+
+		#[extrinsic_call]
+		_(admin_origin, metadata.clone());
+
+		// verification code
+		assert_eq!(Communities::<T>::metadata(&id), Some(metadata.clone()));
+		assert_has_event::<T>(
+			Event::MetadataSet {
+				id,
+				name: metadata.name,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_metadata() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _, admin_origin, admin_origin_caller) = community_params::<T>(None);
+		Communities::<T>::create(RawOrigin::Root.into(), admin_origin_caller.clone(), id)?;
+		Communities::<T>::set_metadata(admin_origin, community_metadata::<T>())?;
+
+		#[extrinsic_call]
+		_(admin_origin_caller);
+
+		// verification code
+		assert_eq!(Communities::<T>::metadata(&id), None);
+		assert_has_event::<T>(Event::MetadataCleared { id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn block() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _, _, admin_origin) = community_params::<T>(None);
+		Communities::<T>::create(RawOrigin::Root.into(), admin_origin, id)?;
+		let block_origin = T::BlockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason = BoundedVec::truncate_from(vec![b'a'; 256]);
+
+		#[extrinsic_call]
+		_(block_origin, id, reason.clone());
+
+		// verification code
+		assert_has_event::<T>(Event::CommunityBlocked { id, reason }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unblock() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _, _, admin_origin) = community_params::<T>(None);
+		Communities::<T>::create(RawOrigin::Root.into(), admin_origin, id)?;
+		Communities::<T>::force_state(&id, CommunityState::Blocked);
+		let block_origin = T::BlockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(block_origin, id);
+
+		// verification code
+		assert_has_event::<T>(Event::CommunityUnblocked { id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn start_destroy() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _, _, admin_origin) = community_params::<T>(None);
+		Communities::<T>::create(RawOrigin::Root.into(), admin_origin.clone(), id)?;
+
+		#[extrinsic_call]
+		_(admin_origin, id);
+
+		// verification code
+		assert_has_event::<T>(Event::DestructionStarted { id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_votes(c: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), Some(DecisionMethod::NativeToken))?;
+		let members = setup_members::<T>(origin.clone(), id)?;
+
+		let (submitter, _) = members
+			.first()
+			.expect("desired size of community to be equal or greather than 1")
+			.clone();
+		let index = prepare_track_and_prepare_poll::<T>(origin.into_caller(), submitter.clone())?;
+
+		for (who, membership_id) in members.into_iter().take(c as usize) {
+			Communities::<T>::vote(
+				RawOrigin::Signed(who).into(),
+				membership_id,
+				index,
+				Vote::NativeBalance(true, 1u32.into(), Conviction::None),
+			)?;
+		}
+
+		T::BenchmarkHelper::finish_poll(index)?;
+		Communities::<T>::do_start_destroy(&id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(submitter), id);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::VotesDestroyed {
+				id,
+				votes_destroyed: c.min(T::RemoveItemsLimit::get()),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_records(c: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		for i in 0..c {
+			let who = setup_account::<T>("applicant", i, 0)?;
+			Communities::<T>::apply_for_membership(
+				RawOrigin::Signed(who).into(),
+				id,
+				BoundedVec::truncate_from(vec![b'a'; 256]),
+			)?;
+		}
+		Communities::<T>::do_start_destroy(&id)?;
+		let caller: AccountIdOf<T> = frame_benchmarking::whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::RecordsDestroyed {
+				id,
+				records_destroyed: c.min(T::RemoveItemsLimit::get()),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn finish_destroy() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _, _, admin_origin) = community_params::<T>(None);
+		Communities::<T>::create(RawOrigin::Root.into(), admin_origin, id)?;
+		Communities::<T>::set_metadata(Origin::<T>::new(id).into(), community_metadata::<T>())?;
+		Communities::<T>::do_start_destroy(&id)?;
+		let block_origin = T::BlockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(block_origin, id);
+
+		// verification code
+		assert!(!Communities::<T>::community_exists(&id));
+		assert_has_event::<T>(Event::CommunityDestroyed { id }.into());
 
 		Ok(())
 	}
@@ -229,7 +514,7 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn remove_member() -> Result<(), BenchmarkError> {
+	fn remove_member(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
 		// setup code
 		let (id, origin): (CommunityIdOf<T>, OriginFor<T>) = create_community::<T>(RawOrigin::Root.into(), None)?;
 
@@ -239,6 +524,7 @@ mod benchmarks {
 		T::BenchmarkHelper::issue_membership(id, membership_id)?;
 
 		Communities::<T>::add_member(origin.clone(), T::Lookup::unlookup(who.clone()))?;
+		setup_delegators::<T>(origin.clone(), id, membership_id, d)?;
 
 		#[extrinsic_call]
 		_(origin.into_caller(), T::Lookup::unlookup(who.clone()), membership_id);
@@ -277,7 +563,14 @@ mod benchmarks {
 			.ok_or::<frame_support::pallet_prelude::DispatchError>(Error::<T>::NotAMember.into())?;
 		let rank = T::MemberMgmt::rank_of(&id, &m).expect("has rank");
 
-		assert_has_event::<T>(Event::MembershipRankUpdated { membership_id, rank }.into());
+		assert_has_event::<T>(
+			Event::MembershipRankUpdated {
+				membership_id,
+				old_rank: 0.into(),
+				new_rank: rank,
+			}
+			.into(),
+		);
 
 		assert_eq!(Communities::<T>::member_rank(&id, &membership_id), rank);
 
@@ -308,12 +601,9 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn vote() -> Result<(), BenchmarkError> {
+	fn vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
 		// setup code
-		let (id, origin) = create_community::<T>(
-			RawOrigin::Root.into(),
-			Some(DecisionMethodFor::<T>::CommunityAsset(1u32.into(), 1u64.into())),
-		)?;
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), Some(DecisionMethod::Rank))?;
 		let members = setup_members::<T>(origin.clone(), id)?;
 
 		let (who, membership_id) = members
@@ -321,7 +611,8 @@ mod benchmarks {
 			.expect("desired size of community to be equal or greather than 1")
 			.clone();
 
-		T::Assets::mint_into(1u32.into(), &who, 4u64.into())?;
+		// votes are casted on behalf of delegators only when deciding by rank
+		setup_delegators::<T>(origin.clone(), id, membership_id, d)?;
 
 		prepare_track_and_prepare_poll::<T>(origin.into_caller(), who.clone())?;
 
@@ -329,7 +620,7 @@ mod benchmarks {
 			RawOrigin::Signed(who.clone()).into(),
 			membership_id,
 			0u32,
-			Vote::AssetBalance(true, 1u32.into(), 1u64.into()),
+			Vote::Standard(false),
 		)?;
 
 		#[extrinsic_call]
@@ -337,7 +628,7 @@ mod benchmarks {
 			RawOrigin::Signed(who.clone()),
 			membership_id,
 			0u32,
-			Vote::AssetBalance(true, 1u32.into(), 2u64.into()),
+			Vote::Standard(true),
 		);
 
 		// verification code
@@ -345,7 +636,7 @@ mod benchmarks {
 			Event::VoteCasted {
 				who: who.clone(),
 				poll_index: 0u32,
-				vote: Vote::AssetBalance(true, 1u32.into(), 2u32.into()),
+				vote: Vote::Standard(true),
 			}
 			.into(),
 		);
@@ -354,7 +645,7 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn remove_vote() -> Result<(), BenchmarkError> {
+	fn remove_vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
 		// setup code
 		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
 		let members = setup_members::<T>(origin.clone(), id)?;
@@ -364,6 +655,8 @@ mod benchmarks {
 			.expect("desired size of community to be equal or greather than 1")
 			.clone();
 
+		setup_delegators::<T>(origin.clone(), id, membership_id, d)?;
+
 		prepare_track_and_prepare_poll::<T>(origin.into_caller(), who.clone())?;
 
 		Communities::<T>::vote(
@@ -405,7 +698,7 @@ mod benchmarks {
 			RawOrigin::Signed(who.clone()).into(),
 			membership_id,
 			0u32,
-			Vote::NativeBalance(true, 1u32.into()),
+			Vote::NativeBalance(true, 1u32.into(), Conviction::None),
 		)?;
 
 		assert_eq!(
@@ -448,6 +741,716 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn dispatch_as_origin() -> Result<(), BenchmarkError> {
+		// setup code
+		let (_, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let remark = b"Hello, world".to_vec();
+
+		#[extrinsic_call]
+		_(
+			origin.into_caller(),
+			None,
+			Box::new(frame_system::Call::<T>::remark { remark }.into()),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn delegate() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), Some(DecisionMethod::Rank))?;
+		let members = setup_members::<T>(origin, id)?;
+
+		let (who, membership_id) = members[0].clone();
+		let (_, delegate) = members[1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), membership_id, delegate);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::Delegated {
+				membership_id,
+				delegate,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn undelegate() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), Some(DecisionMethod::Rank))?;
+		let members = setup_members::<T>(origin.clone(), id)?;
+
+		let (who, membership_id) = members[0].clone();
+		let (delegate_account, delegate) = members[1].clone();
+
+		Communities::<T>::delegate(RawOrigin::Signed(who.clone()).into(), membership_id, delegate)?;
+
+		prepare_track_and_prepare_poll::<T>(origin.into_caller(), delegate_account.clone())?;
+
+		Communities::<T>::vote(
+			RawOrigin::Signed(delegate_account).into(),
+			delegate,
+			0u32,
+			Vote::Standard(true),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), membership_id);
+
+		// verification code
+		assert_has_event::<T>(Event::Undelegated { membership_id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispatch_as_subset() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let members = setup_members::<T>(origin, id)?;
+
+		let (who, membership_id) = members[0].clone();
+		// the approvals needed are set by counting the members of the rank
+		let subset = crate::origin::Subset::AtLeastRank(1.into());
+		let call: RuntimeCallFor<T> = frame_system::Call::<T>::remark {
+			remark: b"Hello, world".to_vec(),
+		}
+		.into();
+		let call_hash = T::Hashing::hash_of(&(&subset, &call));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), membership_id, subset, Box::new(call));
+
+		// verification code
+		assert_has_event::<T>(
+			Event::SubsetCallApproved {
+				id,
+				membership_id,
+				call_hash,
+				approvals: 1,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn leave(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), Some(DecisionMethod::Rank))?;
+		let members = setup_members::<T>(origin.clone(), id)?;
+
+		let (who, membership_id) = members
+			.first()
+			.expect("desired size of community to be equal or greather than 1")
+			.clone();
+
+		// the votes casted on behalf of the delegators are removed as well
+		setup_delegators::<T>(origin.clone(), id, membership_id, d)?;
+
+		prepare_track_and_prepare_poll::<T>(origin.into_caller(), who.clone())?;
+
+		Communities::<T>::vote(
+			RawOrigin::Signed(who.clone()).into(),
+			membership_id,
+			0u32,
+			Vote::Standard(true),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), membership_id);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::MemberRemoved {
+				who: who.clone(),
+				membership_id,
+			}
+			.into(),
+		);
+		assert!(T::MemberMgmt::check_membership(&who, &membership_id).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_expired_member(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), Some(DecisionMethod::Rank))?;
+		let members = setup_members::<T>(origin.clone(), id)?;
+
+		let (who, membership_id) = members
+			.first()
+			.expect("desired size of community to be equal or greather than 1")
+			.clone();
+
+		// the votes casted on behalf of the delegators are removed as well
+		setup_delegators::<T>(origin.clone(), id, membership_id, d)?;
+
+		prepare_track_and_prepare_poll::<T>(origin.into_caller(), who.clone())?;
+
+		Communities::<T>::vote(
+			RawOrigin::Signed(who.clone()).into(),
+			membership_id,
+			0u32,
+			Vote::Standard(true),
+		)?;
+		T::BenchmarkHelper::expire_membership(id, membership_id)?;
+
+		let pruner = setup_account::<T>("pruner", 0, 0)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(pruner),
+			T::Lookup::unlookup(who.clone()),
+			membership_id,
+		);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::MemberRemoved {
+				who: who.clone(),
+				membership_id,
+			}
+			.into(),
+		);
+		assert!(T::MemberMgmt::check_membership(&who, &membership_id).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_for_membership() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let who = setup_account::<T>("applicant", 0, 0)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(who.clone()),
+			id,
+			BoundedVec::truncate_from(vec![b'a'; 256]),
+		);
+
+		// verification code
+		assert_has_event::<T>(Event::MembershipApplied { id, who: who.clone() }.into());
+		assert!(MembershipApplications::<T>::contains_key(id, &who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_application() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let who = setup_account::<T>("applicant", 0, 0)?;
+		let membership_id = MembershipIdOf::<T>::from(0);
+
+		T::BenchmarkHelper::issue_membership(id, membership_id)?;
+		Communities::<T>::apply_for_membership(
+			RawOrigin::Signed(who.clone()).into(),
+			id,
+			BoundedVec::truncate_from(vec![b'a'; 256]),
+		)?;
+
+		#[extrinsic_call]
+		_(origin.into_caller(), T::Lookup::unlookup(who.clone()));
+
+		// verification code
+		assert_has_event::<T>(Event::MembershipApplicationApproved { id, who: who.clone() }.into());
+		assert!(T::MemberMgmt::check_membership(&who, &membership_id).is_some());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_application() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let who = setup_account::<T>("applicant", 0, 0)?;
+
+		Communities::<T>::apply_for_membership(
+			RawOrigin::Signed(who.clone()).into(),
+			id,
+			BoundedVec::truncate_from(vec![b'a'; 256]),
+		)?;
+
+		#[extrinsic_call]
+		_(origin.into_caller(), T::Lookup::unlookup(who.clone()));
+
+		// verification code
+		assert_has_event::<T>(Event::MembershipApplicationRejected { id, who: who.clone() }.into());
+		assert!(!MembershipApplications::<T>::contains_key(id, &who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired_application() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, _) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let who = setup_account::<T>("applicant", 0, 0)?;
+		let caller = setup_account::<T>("caller", 0, 0)?;
+
+		Communities::<T>::apply_for_membership(
+			RawOrigin::Signed(who.clone()).into(),
+			id,
+			BoundedVec::truncate_from(vec![b'a'; 256]),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::ApplicationPeriod::get() + 1u32.into(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, T::Lookup::unlookup(who.clone()));
+
+		// verification code
+		assert_has_event::<T>(Event::MembershipApplicationExpired { id, who: who.clone() }.into());
+		assert!(!MembershipApplications::<T>::contains_key(id, &who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn invite_member() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let membership_id = MembershipIdOf::<T>::from(0);
+		let (_, invitation_key) = T::BenchmarkHelper::invitation_signer();
+
+		T::BenchmarkHelper::issue_membership(id, membership_id)?;
+
+		#[extrinsic_call]
+		_(origin.into_caller(), invitation_key.clone());
+
+		// verification code
+		assert_has_event::<T>(
+			Event::MemberInvited {
+				id,
+				membership_id,
+				invitation_key,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_invitation() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let who = setup_account::<T>("invitee", 0, 0)?;
+		let membership_id = MembershipIdOf::<T>::from(0);
+		let (signer, invitation_key) = T::BenchmarkHelper::invitation_signer();
+		let signature = T::BenchmarkHelper::sign_invitation(&signer, &(id, &who).encode());
+
+		T::BenchmarkHelper::issue_membership(id, membership_id)?;
+		Communities::<T>::invite_member(origin, invitation_key.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), id, invitation_key, signature);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::InvitationClaimed {
+				id,
+				who: who.clone(),
+				membership_id,
+			}
+			.into(),
+		);
+		assert!(T::MemberMgmt::check_membership(&who, &membership_id).is_some());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_invitation() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let membership_id = MembershipIdOf::<T>::from(0);
+		let (_, invitation_key) = T::BenchmarkHelper::invitation_signer();
+
+		T::BenchmarkHelper::issue_membership(id, membership_id)?;
+		Communities::<T>::invite_member(origin.clone(), invitation_key.clone())?;
+
+		#[extrinsic_call]
+		_(origin.into_caller(), invitation_key.clone());
+
+		// verification code
+		assert_has_event::<T>(Event::InvitationRevoked { id, invitation_key }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_members(n: Linear<1, { T::MaxMembersPerBatch::get() }>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+
+		let mut members = vec![];
+		for i in 0..n {
+			T::BenchmarkHelper::issue_membership(id, MembershipIdOf::<T>::from(i))?;
+			let who: AccountIdOf<T> = frame_benchmarking::account("community_benchmarking", i, 0);
+			members.push(T::Lookup::unlookup(who));
+		}
+
+		#[extrinsic_call]
+		_(origin.into_caller(), BoundedVec::truncate_from(members));
+
+		// verification code
+		for i in 0..n {
+			let who: AccountIdOf<T> = frame_benchmarking::account("community_benchmarking", i, 0);
+			assert!(Communities::<T>::is_member(&id, &who));
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_members(n: Linear<1, { T::MaxMembersPerBatch::get() }>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+
+		let mut members = vec![];
+		for i in 0..n {
+			let membership_id = MembershipIdOf::<T>::from(i);
+			T::BenchmarkHelper::issue_membership(id, membership_id)?;
+			let who: AccountIdOf<T> = frame_benchmarking::account("community_benchmarking", i, 0);
+			// the only membership in the pool is given to the new member
+			Communities::<T>::add_member(origin.clone(), T::Lookup::unlookup(who.clone()))?;
+			members.push((T::Lookup::unlookup(who), membership_id));
+		}
+
+		#[extrinsic_call]
+		_(origin.into_caller(), BoundedVec::truncate_from(members));
+
+		// verification code
+		for i in 0..n {
+			let who: AccountIdOf<T> = frame_benchmarking::account("community_benchmarking", i, 0);
+			assert!(!Communities::<T>::is_member(&id, &who));
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_ranks(n: Linear<1, { T::MaxMembersPerBatch::get() }>) -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+
+		let mut ranks = vec![];
+		for i in 0..n {
+			let membership_id = MembershipIdOf::<T>::from(i);
+			T::BenchmarkHelper::issue_membership(id, membership_id)?;
+			let who: AccountIdOf<T> = frame_benchmarking::account("community_benchmarking", i, 0);
+			Communities::<T>::add_member(origin.clone(), T::Lookup::unlookup(who))?;
+			ranks.push((membership_id, 1.into()));
+		}
+
+		#[extrinsic_call]
+		_(origin.into_caller(), BoundedVec::truncate_from(ranks));
+
+		// verification code
+		for i in 0..n {
+			let membership_id = MembershipIdOf::<T>::from(i);
+			assert_eq!(Communities::<T>::member_rank(&id, &membership_id), 1.into());
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_member_rank() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+
+		let who: AccountIdOf<T> = frame_benchmarking::account("community_benchmarking", 0, 0);
+		let membership_id = MembershipIdOf::<T>::from(0);
+
+		T::BenchmarkHelper::issue_membership(id, membership_id)?;
+		Communities::<T>::add_member(origin.clone(), T::Lookup::unlookup(who))?;
+		Communities::<T>::set_rank_bounds(
+			origin.clone(),
+			RankBounds {
+				min: Some(0.into()),
+				max: Some(5.into()),
+			},
+		)?;
+
+		// The rank history is full, so its oldest entry is dropped
+		for i in 0..RankHistoryOf::<T>::bound() {
+			frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+			if i % 2 == 0 {
+				Communities::<T>::promote(origin.clone(), membership_id)?;
+			} else {
+				Communities::<T>::demote(origin.clone(), membership_id)?;
+			}
+		}
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+
+		#[extrinsic_call]
+		_(origin.into_caller(), membership_id, 5.into());
+
+		// verification code
+		assert_has_event::<T>(
+			Event::MembershipRankUpdated {
+				membership_id,
+				old_rank: 0.into(),
+				new_rank: 5.into(),
+			}
+			.into(),
+		);
+		assert_eq!(Communities::<T>::member_rank(&id, &membership_id), 5.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_rank_bounds() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let bounds = RankBounds {
+			min: Some(1.into()),
+			max: Some(5.into()),
+		};
+
+		#[extrinsic_call]
+		_(origin.into_caller(), bounds.clone());
+
+		// verification code
+		assert_has_event::<T>(Event::RankBoundsSet { id, bounds }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn spend() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		T::BenchmarkHelper::prepare_track(origin.clone().into_caller())?;
+		let amount: NativeBalanceOf<T> = 100u32.into();
+		Communities::<T>::set_spend_limit(
+			origin.clone(),
+			T::TrackId::for_community(id, 0),
+			SpendAsset::Native,
+			Some(amount),
+		)?;
+		let beneficiary = setup_account::<T>("beneficiary", 0, 0)?;
+
+		#[extrinsic_call]
+		_(
+			origin.into_caller(),
+			SpendAsset::Native,
+			amount,
+			T::Lookup::unlookup(beneficiary.clone()),
+			None,
+		);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::SpendApproved {
+				id,
+				index: 0,
+				asset_kind: SpendAsset::Native,
+				amount,
+				beneficiary,
+				valid_from: frame_system::Pallet::<T>::block_number(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn payout() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let amount: NativeBalanceOf<T> = 100u32.into();
+		let beneficiary = approve_spend::<T>(id, origin, amount)?;
+		let caller = setup_account::<T>("caller", 0, 0)?;
+		T::Balances::mint_into(&Communities::<T>::community_account(&id), amount * 10u32.into())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, 0);
+
+		// verification code
+		assert_has_event::<T>(Event::SpendPaid { id, index: 0 }.into());
+		assert!(T::Balances::balance(&beneficiary) >= amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_spend() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		approve_spend::<T>(id, origin.clone(), 100u32.into())?;
+
+		#[extrinsic_call]
+		_(origin.into_caller(), 0);
+
+		// verification code
+		assert_has_event::<T>(Event::SpendVoided { id, index: 0 }.into());
+		assert!(!Spends::<T>::contains_key(id, 0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired_spend() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		approve_spend::<T>(id, origin, 100u32.into())?;
+		let caller = setup_account::<T>("caller", 0, 0)?;
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + T::PayoutPeriod::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, 0);
+
+		// verification code
+		assert_has_event::<T>(Event::SpendExpired { id, index: 0 }.into());
+		assert!(!Spends::<T>::contains_key(id, 0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_spend_limit() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		T::BenchmarkHelper::prepare_track(origin.clone().into_caller())?;
+		let track_id = T::TrackId::for_community(id, 0);
+		let limit: Option<NativeBalanceOf<T>> = Some(100u32.into());
+
+		#[extrinsic_call]
+		_(origin.into_caller(), track_id, SpendAsset::Native, limit);
+
+		// verification code
+		assert_has_event::<T>(
+			Event::SpendLimitSet {
+				track_id,
+				asset_kind: SpendAsset::Native,
+				limit,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_asset() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let asset_id = T::BenchmarkHelper::community_asset_id();
+		T::Balances::mint_into(&Communities::<T>::community_account(&id), 1_000_000u32.into())?;
+
+		#[extrinsic_call]
+		_(
+			origin.into_caller(),
+			asset_id.clone(),
+			1u32.into(),
+			false,
+			community_asset_metadata(),
+		);
+
+		// verification code
+		assert_has_event::<T>(Event::CommunityAssetCreated { id, asset_id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_metadata() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let asset_id = create_community_asset::<T>(id, origin.clone())?;
+		let metadata = CommunityAssetMetadata {
+			decimals: 10,
+			..community_asset_metadata()
+		};
+
+		#[extrinsic_call]
+		_(origin.into_caller(), asset_id.clone(), metadata);
+
+		// verification code
+		assert!(CommunityAssets::<T>::contains_key(id, asset_id));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn mint_asset() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let asset_id = create_community_asset::<T>(id, origin.clone())?;
+		let beneficiary = setup_account::<T>("beneficiary", 0, 0)?;
+
+		#[extrinsic_call]
+		_(
+			origin.into_caller(),
+			asset_id.clone(),
+			T::Lookup::unlookup(beneficiary.clone()),
+			100u32.into(),
+		);
+
+		// verification code
+		assert_eq!(T::Assets::balance(asset_id, &beneficiary), 100u32.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn burn_asset() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let asset_id = create_community_asset::<T>(id, origin.clone())?;
+		let community_account = Communities::<T>::community_account(&id);
+		T::Assets::mint_into(asset_id.clone(), &community_account, 100u32.into())?;
+
+		#[extrinsic_call]
+		_(origin.into_caller(), asset_id.clone(), 40u32.into());
+
+		// verification code
+		assert_eq!(T::Assets::balance(asset_id, &community_account), 60u32.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_asset() -> Result<(), BenchmarkError> {
+		// setup code
+		let (id, origin) = create_community::<T>(RawOrigin::Root.into(), None)?;
+		let asset_id = T::BenchmarkHelper::community_asset_id();
+		T::Assets::create(
+			asset_id.clone(),
+			T::PalletId::get().into_account_truncating(),
+			false,
+			1u32.into(),
+		)?;
+		CommunityDecisionMethod::<T>::set(id, DecisionMethod::CommunityAsset(asset_id.clone(), 1u32.into()));
+		AssetCreators::<T>::insert(asset_id.clone(), id);
+
+		#[extrinsic_call]
+		_(origin.into_caller(), asset_id.clone());
+
+		// verification code
+		assert_has_event::<T>(Event::CommunityAssetClaimed { id, asset_id }.into());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Communities,
 		sp_io::TestExternalities::new(Default::default()),
@@ -5,15 +5,20 @@ use frame_support::{
 	fail,
 	pallet_prelude::*,
 	traits::{
-		fungible::{InspectFreeze, Mutate, MutateFreeze},
-		fungibles::{InspectFreeze as _, MutateFreeze as _},
-		tokens::Fortitude::Polite,
-		Polling,
+		fungible::{InspectFreeze, Mutate, MutateFreeze, MutateHold},
+		fungibles::{
+			self,
+			roles::{Inspect as _, ResetTeam},
+			Create, InspectFreeze as _, Mutate as _, MutateFreeze as _,
+		},
+		tokens::{Fortitude::Polite, Precision::Exact, Preservation::Expendable, Restriction::Free},
+		Contains, IsSubType, OriginTrait, PollStatus, Polling,
 	},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
-	traits::{AccountIdConversion, Dispatchable},
-	DispatchResultWithInfo,
+	traits::{AccountIdConversion, Dispatchable, Hash, Saturating, Verify, Zero},
+	DispatchResultWithInfo, Perbill,
 };
 use sp_std::vec::Vec;
 
@@ -27,6 +32,16 @@ impl<T: Config> Pallet<T> {
 		Info::<T>::contains_key(community_id)
 	}
 
+	pub fn metadata(community_id: &T::CommunityId) -> Option<CommunityMetadataFor<T>> {
+		Metadata::<T>::get(community_id)
+	}
+
+	/// The number of members of a community and the sum of their ranks.
+	/// Expired memberships are counted until they're pruned.
+	pub fn members_count(community_id: &T::CommunityId) -> MembersCount {
+		CommunityMembersCount::<T>::get(community_id)
+	}
+
 	pub fn is_member(community_id: &T::CommunityId, who: &AccountIdOf<T>) -> bool {
 		T::MemberMgmt::is_member_of(community_id, who)
 	}
@@ -35,6 +50,29 @@ impl<T: Config> Pallet<T> {
 		T::MemberMgmt::rank_of(community_id, m).unwrap_or_default()
 	}
 
+	/// Counts a membership holding `rank` as a member of the community.
+	pub(crate) fn count_member_in(community_id: &T::CommunityId, rank: GenericRank) {
+		CommunityMembersCount::<T>::mutate(community_id, |count| {
+			count.members.saturating_inc();
+			count.ranks.saturating_accrue(u32::from(rank));
+		});
+		CommunityRankCount::<T>::mutate(community_id, rank, |count| count.saturating_inc());
+	}
+
+	/// Stops counting a membership holding `rank` as a member of the
+	/// community.
+	pub(crate) fn count_member_out(community_id: &T::CommunityId, rank: GenericRank) {
+		CommunityMembersCount::<T>::mutate(community_id, |count| {
+			count.members.saturating_dec();
+			count.ranks.saturating_reduce(u32::from(rank));
+		});
+		CommunityRankCount::<T>::mutate_exists(community_id, rank, |count| {
+			*count = count
+				.map(|count| count.saturating_sub(1))
+				.filter(|count| !count.is_zero());
+		});
+	}
+
 	pub fn get_memberships(community_id: T::CommunityId, who: &AccountIdOf<T>) -> Vec<MembershipIdOf<T>> {
 		T::MemberMgmt::user_memberships(who, Some(community_id))
 			.map(|(_, m)| m)
@@ -45,6 +83,37 @@ impl<T: Config> Pallet<T> {
 		Info::<T>::mutate(community_id, |c| c.as_mut().map(|c| c.state = state));
 	}
 
+	/// Fails if the community doesn't exist, it's been blocked or it's being
+	/// destroyed
+	pub fn ensure_active(community_id: &CommunityIdOf<T>) -> DispatchResult {
+		match Info::<T>::get(community_id)
+			.ok_or(Error::<T>::CommunityDoesNotExist)?
+			.state
+		{
+			CommunityState::Active => Ok(()),
+			CommunityState::Blocked => Err(Error::<T>::CommunityBlocked.into()),
+			CommunityState::Destroying => Err(Error::<T>::CommunityDestroying.into()),
+		}
+	}
+
+	/// Fails if the community doesn't exist or it's not being destroyed
+	pub fn ensure_destroying(community_id: &CommunityIdOf<T>) -> DispatchResult {
+		let info = Info::<T>::get(community_id).ok_or(Error::<T>::CommunityDoesNotExist)?;
+		ensure!(info.state == CommunityState::Destroying, Error::<T>::NotBeingDestroyed);
+		Ok(())
+	}
+
+	/// Stores the metadata of the community, replacing any previous one
+	pub fn do_set_metadata(community_id: &CommunityIdOf<T>, metadata: CommunityMetadataFor<T>) {
+		let name = metadata.name.clone();
+		Metadata::<T>::insert(community_id, metadata);
+
+		Self::deposit_event(Event::MetadataSet {
+			id: *community_id,
+			name,
+		});
+	}
+
 	/// Stores an initial info about the community
 	/// Sets the caller as the community admin, the initial community state
 	/// to its default value(awaiting)
@@ -69,15 +138,305 @@ impl<T: Config> Pallet<T> {
 		}
 
 		CommunityIdFor::<T>::insert(admin, community_id);
+		CommunityAdminOrigin::<T>::insert(community_id, admin);
 		Info::<T>::insert(community_id, CommunityInfo::default());
 		frame_system::Pallet::<T>::inc_providers(&Self::community_account(community_id));
 
 		Ok(())
 	}
 
+	/// Marks a community as being destroyed, so it can't operate anymore
+	pub fn do_start_destroy(community_id: &CommunityIdOf<T>) -> DispatchResult {
+		Info::<T>::try_mutate(community_id, |maybe_info| {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::CommunityDoesNotExist)?;
+			ensure!(
+				info.state != CommunityState::Destroying,
+				Error::<T>::CommunityDestroying
+			);
+			info.state = CommunityState::Destroying;
+			Ok::<_, DispatchError>(())
+		})?;
+
+		Self::deposit_event(Event::DestructionStarted { id: *community_id });
+		Ok(())
+	}
+
+	/// Removes up to `max_items` votes casted by members of a community being
+	/// destroyed, releasing the locks they put in place, as well as the
+	/// delegations between its members. Returns the number of removed items.
+	pub fn do_destroy_votes(community_id: &CommunityIdOf<T>, max_items: u32) -> Result<u32, DispatchError> {
+		Self::ensure_destroying(community_id)?;
+
+		let mut removed = 0u32;
+		while removed < max_items {
+			let Some(poll_index) = CommunityPolls::<T>::iter_key_prefix(community_id).next() else {
+				break;
+			};
+			ensure!(T::Polls::as_ongoing(poll_index).is_none(), Error::<T>::PollOngoing);
+
+			let votes = CommunityVotes::<T>::iter_prefix(poll_index)
+				.take(max_items.saturating_sub(removed) as usize)
+				.collect::<Vec<_>>();
+			for (membership_id, (vote, who, _)) in votes {
+				CommunityVotes::<T>::remove(poll_index, membership_id);
+				CommunityDelegatedVotes::<T>::remove(membership_id, poll_index);
+				MembershipVotes::<T>::remove(membership_id, poll_index);
+				if CommunityVoteLocks::<T>::contains_key(&who, poll_index) {
+					Self::update_locks(&who, poll_index, &vote, LockUpdateType::Remove)?;
+				}
+				removed += 1;
+			}
+
+			if CommunityVotes::<T>::iter_prefix(poll_index).next().is_none() {
+				CommunityPolls::<T>::remove(community_id, poll_index);
+			}
+		}
+
+		// Votes casted on behalf of delegators were removed along with the votes
+		// on the polls of the community, so only the delegations are left
+		while removed < max_items {
+			let Some((_, delegate)) = CommunityDelegations::<T>::drain_prefix(community_id).next() else {
+				break;
+			};
+			CommunityDelegators::<T>::remove(community_id, delegate);
+			removed += 1;
+		}
+
+		Self::deposit_event(Event::VotesDestroyed {
+			id: *community_id,
+			votes_destroyed: removed,
+		});
+		Ok(removed)
+	}
+
+	/// Removes up to `max_items` records of a community being destroyed,
+	/// releasing the deposits of its pending applications. Returns the number
+	/// of removed items.
+	pub fn do_destroy_records(community_id: &CommunityIdOf<T>, max_items: u32) -> Result<u32, DispatchError> {
+		Self::ensure_destroying(community_id)?;
+
+		let mut removed = 0u32;
+		let remaining = |removed: u32| max_items.saturating_sub(removed) as usize;
+
+		let applications = MembershipApplications::<T>::iter_prefix(community_id)
+			.take(remaining(removed))
+			.collect::<Vec<_>>();
+		for (who, application) in applications {
+			MembershipApplications::<T>::remove(community_id, &who);
+			Self::release_application_deposit(&who, &application)?;
+			removed += 1;
+		}
+
+		removed += SubsetApprovals::<T>::drain_prefix((community_id,))
+			.take(remaining(removed))
+			.count() as u32;
+		removed += SubsetApprovalsCount::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += MembershipInvitations::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += InvitedMemberships::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += MembershipRankHistory::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += MembershipRankHistoryStart::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += CommunityRankedPolls::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += CommunityRankCount::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += TrackDecisionMethod::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += TrackSpendLimits::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+		removed += Spends::<T>::drain_prefix(community_id).take(remaining(removed)).count() as u32;
+		removed += CommunityAssets::<T>::drain_prefix(community_id)
+			.take(remaining(removed))
+			.count() as u32;
+
+		Self::deposit_event(Event::RecordsDestroyed {
+			id: *community_id,
+			records_destroyed: removed,
+		});
+		Ok(removed)
+	}
+
+	/// Whether a community being destroyed still has records to be removed
+	/// via [`do_destroy_records`][`Pallet::do_destroy_records`].
+	fn has_records(community_id: &CommunityIdOf<T>) -> bool {
+		MembershipApplications::<T>::contains_prefix(community_id)
+			|| SubsetApprovals::<T>::contains_prefix((community_id,))
+			|| SubsetApprovalsCount::<T>::contains_prefix(community_id)
+			|| MembershipInvitations::<T>::contains_prefix(community_id)
+			|| InvitedMemberships::<T>::contains_prefix(community_id)
+			|| MembershipRankHistory::<T>::contains_prefix(community_id)
+			|| MembershipRankHistoryStart::<T>::contains_prefix(community_id)
+			|| CommunityRankedPolls::<T>::contains_prefix(community_id)
+			|| CommunityRankCount::<T>::contains_prefix(community_id)
+			|| TrackDecisionMethod::<T>::contains_prefix(community_id)
+			|| TrackSpendLimits::<T>::contains_prefix(community_id)
+			|| Spends::<T>::contains_prefix(community_id)
+			|| CommunityAssets::<T>::contains_prefix(community_id)
+	}
+
+	/// Removes the remaining information of a community being destroyed,
+	/// once all its votes and records have been destroyed.
+	pub fn do_finish_destroy(community_id: &CommunityIdOf<T>) -> DispatchResult {
+		Self::ensure_destroying(community_id)?;
+		ensure!(
+			CommunityPolls::<T>::iter_key_prefix(community_id).next().is_none()
+				&& CommunityDelegations::<T>::iter_key_prefix(community_id)
+					.next()
+					.is_none(),
+			Error::<T>::VotesRemaining
+		);
+		ensure!(!Self::has_records(community_id), Error::<T>::RecordsRemaining);
+
+		if let Some(admin_origin) = CommunityAdminOrigin::<T>::take(community_id) {
+			CommunityIdFor::<T>::remove(admin_origin);
+		}
+
+		Info::<T>::remove(community_id);
+		Metadata::<T>::remove(community_id);
+		CommunityDecisionMethod::<T>::remove(community_id);
+		CommunityRankBounds::<T>::remove(community_id);
+		CommunityMembersCount::<T>::remove(community_id);
+		SpendCount::<T>::remove(community_id);
+		let _ = frame_system::Pallet::<T>::dec_providers(&Self::community_account(community_id));
+
+		Self::deposit_event(Event::CommunityDestroyed { id: *community_id });
+		Ok(())
+	}
+
+	/// The decision method used by the polls of a track: the one set for the
+	/// track, if any, or the default decision method of the community.
+	pub fn decision_method(track_id: &T::TrackId) -> DecisionMethodFor<T> {
+		let community_id = track_id.community_id();
+		TrackDecisionMethod::<T>::get(community_id, track_id)
+			.unwrap_or_else(|| CommunityDecisionMethod::<T>::get(community_id))
+	}
+
+	/// Creates an asset owned by the community account, linking it to the
+	/// community.
+	pub(crate) fn do_create_asset(
+		community_id: &CommunityIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		is_sufficient: bool,
+		min_balance: NativeBalanceOf<T>,
+	) -> DispatchResult {
+		T::Assets::create(
+			asset_id.clone(),
+			Self::community_account(community_id),
+			is_sufficient,
+			min_balance,
+		)?;
+		CommunityAssets::<T>::insert(community_id, asset_id.clone(), ());
+		AssetCreators::<T>::insert(asset_id.clone(), community_id);
+
+		Self::deposit_event(Event::CommunityAssetCreated {
+			id: *community_id,
+			asset_id,
+		});
+		Ok(())
+	}
+
+	fn ensure_community_asset(community_id: &CommunityIdOf<T>, asset_id: &AssetIdOf<T>) -> DispatchResult {
+		ensure!(
+			CommunityAssets::<T>::contains_key(community_id, asset_id),
+			Error::<T>::NotACommunityAsset
+		);
+		Ok(())
+	}
+
+	pub(crate) fn do_set_asset_metadata(
+		community_id: &CommunityIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		metadata: CommunityAssetMetadata,
+	) -> DispatchResult {
+		Self::ensure_community_asset(community_id, &asset_id)?;
+		<T::Assets as fungibles::metadata::Mutate<_>>::set(
+			asset_id,
+			&Self::community_account(community_id),
+			metadata.name.into_inner(),
+			metadata.symbol.into_inner(),
+			metadata.decimals,
+		)
+	}
+
+	pub(crate) fn do_mint_asset(
+		community_id: &CommunityIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		beneficiary: AccountIdOf<T>,
+		amount: NativeBalanceOf<T>,
+	) -> DispatchResult {
+		Self::ensure_community_asset(community_id, &asset_id)?;
+		T::Assets::mint_into(asset_id, &beneficiary, amount)?;
+		Ok(())
+	}
+
+	pub(crate) fn do_burn_asset(
+		community_id: &CommunityIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		amount: NativeBalanceOf<T>,
+	) -> DispatchResult {
+		Self::ensure_community_asset(community_id, &asset_id)?;
+		T::Assets::burn_from(
+			asset_id,
+			&Self::community_account(community_id),
+			amount,
+			Expendable,
+			Exact,
+			Polite,
+		)?;
+		Ok(())
+	}
+
+	/// Makes the community account the owner of an asset the pallet holds
+	/// on behalf of a community that votes with it, linking the asset to the
+	/// community. Only the community the asset was created for can claim it.
+	pub(crate) fn do_claim_asset(community_id: &CommunityIdOf<T>, asset_id: AssetIdOf<T>) -> DispatchResult {
+		let pallet_account: AccountIdOf<T> = T::PalletId::get().into_account_truncating();
+		ensure!(
+			T::Assets::owner(asset_id.clone()) == Some(pallet_account)
+				&& AssetCreators::<T>::get(&asset_id) == Some(*community_id)
+				&& Self::decides_with_asset(community_id, &asset_id),
+			Error::<T>::AssetNotClaimable
+		);
+
+		let community_account = Self::community_account(community_id);
+		T::Assets::reset_team(
+			asset_id.clone(),
+			community_account.clone(),
+			community_account.clone(),
+			community_account.clone(),
+			community_account,
+		)?;
+		CommunityAssets::<T>::insert(community_id, asset_id.clone(), ());
+
+		Self::deposit_event(Event::CommunityAssetClaimed {
+			id: *community_id,
+			asset_id,
+		});
+		Ok(())
+	}
+
+	/// Whether the community, or any of its tracks, decides by voting with
+	/// the given asset.
+	fn decides_with_asset(community_id: &CommunityIdOf<T>, asset_id: &AssetIdOf<T>) -> bool {
+		CommunityDecisionMethod::<T>::get(community_id).asset() == Some(asset_id)
+			|| TrackDecisionMethod::<T>::iter_prefix_values(community_id).any(|method| method.asset() == Some(asset_id))
+	}
+
 	pub(crate) fn try_vote(
 		community_id: &CommunityIdOf<T>,
-		decision_method: &DecisionMethodFor<T>,
 		who: &AccountIdOf<T>,
 		membership_id: &MembershipIdOf<T>,
 		poll_index: PollIndexOf<T>,
@@ -85,104 +444,575 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		T::Polls::try_access_poll(poll_index, |poll_status| {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T>::NotOngoing)?;
-			ensure!(community_id == &class, Error::<T>::InvalidTrack);
+			ensure!(community_id == &class.community_id(), Error::<T>::InvalidTrack);
 
-			let vote_multiplier = match CommunityDecisionMethod::<T>::get(community_id) {
-				DecisionMethod::Rank => T::MemberMgmt::rank_of(community_id, membership_id)
-					.unwrap_or_default()
-					.into(),
-				_ => 1,
-			};
+			let decision_method = &Self::decision_method(&class);
+			let snapshot_at = tally.snapshot_block();
+			let vote_multiplier = Self::vote_multiplier(community_id, decision_method, membership_id, snapshot_at)
+				.ok_or(Error::<T>::UnknownRank)?;
 
-			let say = *match (vote, decision_method) {
-				(Vote::AssetBalance(say, asset, amount), DecisionMethod::CommunityAsset(a, min)) if asset == a => {
-					ensure!(amount >= min, Error::<T>::VoteBelowMinimum);
-					say
+			match (vote.power(), decision_method) {
+				(
+					VotePower::Asset(asset, amount),
+					DecisionMethod::CommunityAsset(a, min)
+					| DecisionMethod::Quadratic(a, min)
+					| DecisionMethod::CustomCurve(a, min),
+				) if &asset == a => {
+					ensure!(&amount >= min, Error::<T>::VoteBelowMinimum);
 				}
-				(Vote::NativeBalance(say, ..), DecisionMethod::NativeToken)
-				| (Vote::Standard(say), DecisionMethod::Membership | DecisionMethod::Rank) => say,
+				(VotePower::Native(_), DecisionMethod::NativeToken)
+				| (VotePower::Membership, DecisionMethod::Membership | DecisionMethod::Rank) => (),
 				_ => fail!(Error::<T>::InvalidVoteType),
 			};
+			if let Vote::Split { aye, nay, .. } = vote {
+				ensure!(
+					*nay <= Perbill::one().saturating_sub(*aye),
+					Error::<T>::InvalidVoteSplit
+				);
+			}
+
+			tally.add_vote(decision_method, vote, vote_multiplier);
+
+			CommunityVotes::<T>::insert(poll_index, membership_id, (vote, who, vote_multiplier));
+			CommunityPolls::<T>::insert(community_id, poll_index, ());
+			MembershipVotes::<T>::insert(membership_id, poll_index, ());
+			if matches!(decision_method, DecisionMethod::Rank) {
+				CommunityRankedPolls::<T>::insert(community_id, poll_index, snapshot_at);
+			}
 
-			let vote_weight = VoteWeight::from(vote);
-			tally.add_vote(say, vote_multiplier * vote_weight, vote_weight);
+			// Delegated voting power only applies to decision methods where the
+			// weight of a vote comes from the membership itself
+			if matches!(decision_method, DecisionMethod::Membership | DecisionMethod::Rank) {
+				for delegator in CommunityDelegators::<T>::get(community_id, membership_id) {
+					if CommunityVotes::<T>::contains_key(poll_index, delegator)
+						|| T::ExpiredMemberships::contains(&(*community_id, delegator))
+					{
+						continue;
+					}
+					// Delegators whose rank at the snapshot is no longer known are left out
+					let Some(vote_multiplier) =
+						Self::vote_multiplier(community_id, decision_method, &delegator, snapshot_at)
+					else {
+						continue;
+					};
+					tally.add_vote(decision_method, vote, vote_multiplier);
+					CommunityVotes::<T>::insert(poll_index, delegator, (vote, who, vote_multiplier));
+					CommunityDelegatedVotes::<T>::insert(delegator, poll_index, ());
+				}
+			}
 
-			CommunityVotes::<T>::insert(poll_index, membership_id, (vote, who));
 			Self::update_locks(who, poll_index, vote, LockUpdateType::Add)
 		})
 	}
 
 	pub(crate) fn try_remove_vote(
 		community_id: &CommunityIdOf<T>,
-		decision_method: &DecisionMethodFor<T>,
 		membership_id: &MembershipIdOf<T>,
 		poll_index: PollIndexOf<T>,
 	) -> DispatchResult {
 		T::Polls::try_access_poll(poll_index, |poll_status| {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T>::NotOngoing)?;
-			ensure!(community_id == &class, Error::<T>::InvalidTrack);
-
-			let (vote, voter) = CommunityVotes::<T>::get(poll_index, membership_id).ok_or(Error::<T>::NoVoteCasted)?;
-			let vote_multiplier = match decision_method {
-				DecisionMethod::Rank => T::MemberMgmt::rank_of(community_id, membership_id)
-					.unwrap_or_default()
-					.into(),
-				_ => 1,
-			};
+			ensure!(community_id == &class.community_id(), Error::<T>::InvalidTrack);
 
-			let vote_weight = VoteWeight::from(&vote);
-			tally.remove_vote(vote.say(), vote_multiplier * vote_weight, vote_weight);
+			let (vote, voter, vote_multiplier) =
+				CommunityVotes::<T>::get(poll_index, membership_id).ok_or(Error::<T>::NoVoteCasted)?;
+			let decision_method = &Self::decision_method(&class);
+
+			tally.remove_vote(decision_method, &vote, vote_multiplier);
 
 			CommunityVotes::<T>::remove(poll_index, membership_id);
+			CommunityDelegatedVotes::<T>::remove(membership_id, poll_index);
+			MembershipVotes::<T>::remove(membership_id, poll_index);
+
+			for delegator in CommunityDelegators::<T>::get(community_id, membership_id) {
+				if CommunityDelegatedVotes::<T>::take(delegator, poll_index).is_none() {
+					continue;
+				}
+				if let Some((vote, _, vote_multiplier)) = CommunityVotes::<T>::take(poll_index, delegator) {
+					tally.remove_vote(decision_method, &vote, vote_multiplier);
+				}
+			}
+
 			Self::update_locks(&voter, poll_index, &vote, LockUpdateType::Remove)
 		})
 	}
 
+	/// The weight a vote adds to the approval of a poll: its weight shaped by
+	/// the curve of the decision method, multiplied by its conviction.
+	fn approval_weight(decision_method: &DecisionMethodFor<T>, vote: &VoteOf<T>) -> VoteWeight {
+		let weight = match decision_method {
+			DecisionMethod::Quadratic(..) => QuadraticCurve::weigh(vote.weight()),
+			DecisionMethod::CustomCurve(..) => T::VoteCurve::weigh(vote.weight()),
+			_ => vote.weight(),
+		};
+		vote.conviction().map_or(weight, |c| c.votes(weight))
+	}
+
+	/// The multiplier a vote of the membership is tallied with, or `None` if
+	/// the poll is decided by rank and the rank the membership had when the
+	/// poll was snapshotted is no longer known.
+	pub(crate) fn vote_multiplier(
+		community_id: &CommunityIdOf<T>,
+		decision_method: &DecisionMethodFor<T>,
+		membership_id: &MembershipIdOf<T>,
+		snapshot_at: BlockNumberFor<T>,
+	) -> Option<VoteWeight> {
+		match decision_method {
+			DecisionMethod::Rank => {
+				Self::rank_at(community_id, membership_id, snapshot_at).map(|rank| u32::from(rank).into())
+			}
+			_ => Some(1),
+		}
+	}
+
+	/// Enrolls an account as a member of the community, giving it a
+	/// membership from the available pool of memberships of the community.
+	pub(crate) fn do_add_member(community_id: &CommunityIdOf<T>, who: AccountIdOf<T>) -> DispatchResult {
+		Self::do_add_members(community_id, Vec::from([who]))
+	}
+
+	/// Enrolls several accounts as members of the community, reading the pool
+	/// of memberships of the community only once.
+	pub(crate) fn do_add_members(community_id: &CommunityIdOf<T>, members: Vec<AccountIdOf<T>>) -> DispatchResult {
+		// assume the community has memberships to give out to the new members
+		let memberships = Self::available_memberships(community_id, members.len());
+		ensure!(memberships.len() == members.len(), Error::<T>::CommunityAtCapacity);
+
+		for (who, membership_id) in members.into_iter().zip(memberships) {
+			T::MemberMgmt::assign(community_id, &membership_id, &who)?;
+			Self::count_member_in(community_id, Self::member_rank(community_id, &membership_id));
+			Self::deposit_event(Event::MemberAdded { who, membership_id });
+		}
+		Ok(())
+	}
+
+	/// Up to `count` memberships from the pool of the community that aren't
+	/// set aside for an invitation.
+	fn available_memberships(community_id: &CommunityIdOf<T>, count: usize) -> Vec<MembershipIdOf<T>> {
+		let account = Self::community_account(community_id);
+		T::MemberMgmt::user_memberships(&account, None)
+			.map(|(_, membership_id)| membership_id)
+			.filter(|membership_id| !InvitedMemberships::<T>::contains_key(community_id, membership_id))
+			.take(count)
+			.collect()
+	}
+
+	pub(crate) fn do_invite_member(community_id: &CommunityIdOf<T>, invitation_key: AccountIdOf<T>) -> DispatchResult {
+		ensure!(
+			!MembershipInvitations::<T>::contains_key(community_id, &invitation_key),
+			Error::<T>::InvitationAlreadyExists
+		);
+		let membership_id = Self::available_memberships(community_id, 1)
+			.pop()
+			.ok_or(Error::<T>::CommunityAtCapacity)?;
+
+		MembershipInvitations::<T>::insert(community_id, &invitation_key, membership_id);
+		InvitedMemberships::<T>::insert(community_id, membership_id, ());
+
+		Self::deposit_event(Event::MemberInvited {
+			id: *community_id,
+			membership_id,
+			invitation_key,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_claim_invitation(
+		community_id: &CommunityIdOf<T>,
+		who: AccountIdOf<T>,
+		invitation_key: AccountIdOf<T>,
+		signature: T::InvitationSignature,
+	) -> DispatchResult {
+		ensure!(!Self::is_member(community_id, &who), Error::<T>::AlreadyAMember);
+		let membership_id =
+			MembershipInvitations::<T>::get(community_id, &invitation_key).ok_or(Error::<T>::InvitationNotFound)?;
+		ensure!(
+			signature.verify(&*(community_id, &who).encode(), &invitation_key),
+			Error::<T>::InvalidInvitationSignature
+		);
+		MembershipInvitations::<T>::remove(community_id, &invitation_key);
+		InvitedMemberships::<T>::remove(community_id, membership_id);
+
+		T::MemberMgmt::assign(community_id, &membership_id, &who)?;
+		Self::count_member_in(community_id, Self::member_rank(community_id, &membership_id));
+
+		Self::deposit_event(Event::InvitationClaimed {
+			id: *community_id,
+			who: who.clone(),
+			membership_id,
+		});
+		Self::deposit_event(Event::MemberAdded { who, membership_id });
+		Ok(())
+	}
+
+	pub(crate) fn do_revoke_invitation(
+		community_id: &CommunityIdOf<T>,
+		invitation_key: AccountIdOf<T>,
+	) -> DispatchResult {
+		let membership_id =
+			MembershipInvitations::<T>::take(community_id, &invitation_key).ok_or(Error::<T>::InvitationNotFound)?;
+		InvitedMemberships::<T>::remove(community_id, membership_id);
+
+		Self::deposit_event(Event::InvitationRevoked {
+			id: *community_id,
+			invitation_key,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_apply_for_membership(
+		community_id: &CommunityIdOf<T>,
+		who: AccountIdOf<T>,
+		message: ConstSizedField<256>,
+	) -> DispatchResult {
+		ensure!(!Self::is_member(community_id, &who), Error::<T>::AlreadyAMember);
+		ensure!(
+			!MembershipApplications::<T>::contains_key(community_id, &who),
+			Error::<T>::AlreadyApplied
+		);
+
+		let deposit = T::ApplicationDeposit::get();
+		if !deposit.is_zero() {
+			T::Balances::hold(&HoldReason::MembershipApplication.into(), &who, deposit)?;
+		}
+
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(T::ApplicationPeriod::get());
+		MembershipApplications::<T>::insert(
+			community_id,
+			&who,
+			MembershipApplication {
+				deposit,
+				message,
+				expires_at,
+			},
+		);
+
+		Self::deposit_event(Event::MembershipApplied { id: *community_id, who });
+		Ok(())
+	}
+
+	/// Takes the pending application of an account, failing if it has
+	/// expired already.
+	fn take_pending_application(
+		community_id: &CommunityIdOf<T>,
+		who: &AccountIdOf<T>,
+	) -> Result<MembershipApplicationOf<T>, DispatchError> {
+		let application =
+			MembershipApplications::<T>::take(community_id, who).ok_or(Error::<T>::ApplicationNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= application.expires_at,
+			Error::<T>::ApplicationExpired
+		);
+		Ok(application)
+	}
+
+	fn release_application_deposit(who: &AccountIdOf<T>, application: &MembershipApplicationOf<T>) -> DispatchResult {
+		if !application.deposit.is_zero() {
+			T::Balances::release(
+				&HoldReason::MembershipApplication.into(),
+				who,
+				application.deposit,
+				Exact,
+			)?;
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_approve_application(community_id: &CommunityIdOf<T>, who: AccountIdOf<T>) -> DispatchResult {
+		let application = Self::take_pending_application(community_id, &who)?;
+		Self::release_application_deposit(&who, &application)?;
+
+		Self::do_add_member(community_id, who.clone())?;
+
+		Self::deposit_event(Event::MembershipApplicationApproved { id: *community_id, who });
+		Ok(())
+	}
+
+	pub(crate) fn do_reject_application(community_id: &CommunityIdOf<T>, who: AccountIdOf<T>) -> DispatchResult {
+		let application = Self::take_pending_application(community_id, &who)?;
+		if !application.deposit.is_zero() {
+			T::Balances::transfer_on_hold(
+				&HoldReason::MembershipApplication.into(),
+				&who,
+				&Self::community_account(community_id),
+				application.deposit,
+				Exact,
+				Free,
+				Polite,
+			)?;
+		}
+
+		Self::deposit_event(Event::MembershipApplicationRejected { id: *community_id, who });
+		Ok(())
+	}
+
+	pub(crate) fn do_remove_expired_application(
+		community_id: &CommunityIdOf<T>,
+		who: AccountIdOf<T>,
+	) -> DispatchResult {
+		let application =
+			MembershipApplications::<T>::get(community_id, &who).ok_or(Error::<T>::ApplicationNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() > application.expires_at,
+			Error::<T>::ApplicationNotExpired
+		);
+
+		MembershipApplications::<T>::remove(community_id, &who);
+		Self::release_application_deposit(&who, &application)?;
+
+		Self::deposit_event(Event::MembershipApplicationExpired { id: *community_id, who });
+		Ok(())
+	}
+
+	/// Removes a member from the community, releasing its membership. Votes
+	/// casted by the membership on ongoing polls are removed, unfreezing the
+	/// funds they locked, and so are its delegations. Funds locked on finished
+	/// polls are unfrozen as well, unless their conviction still binds them.
+	pub(crate) fn do_remove_member(
+		community_id: &CommunityIdOf<T>,
+		who: AccountIdOf<T>,
+		membership_id: MembershipIdOf<T>,
+	) -> DispatchResult {
+		for poll_index in MembershipVotes::<T>::iter_key_prefix(membership_id).collect::<Vec<_>>() {
+			if T::Polls::as_ongoing(poll_index).is_some() {
+				Self::try_remove_vote(community_id, &membership_id, poll_index)?;
+			} else {
+				MembershipVotes::<T>::remove(membership_id, poll_index);
+				// Locks still bound by their conviction can be released later via `unlock`
+				let Some((_, voter, _)) = CommunityVotes::<T>::get(poll_index, membership_id) else {
+					continue;
+				};
+				if let Some(vote) = CommunityVoteLocks::<T>::get(&voter, poll_index) {
+					if Self::ensure_lock_expired(poll_index, &vote).is_ok() {
+						Self::update_locks(&voter, poll_index, &vote, LockUpdateType::Remove)?;
+					}
+				}
+			}
+		}
+
+		if CommunityDelegations::<T>::contains_key(community_id, membership_id) {
+			Self::do_undelegate(community_id, &membership_id)?;
+		}
+		for delegator in CommunityDelegators::<T>::get(community_id, membership_id) {
+			Self::do_undelegate(community_id, &delegator)?;
+		}
+
+		let rank = Self::member_rank(community_id, &membership_id);
+		T::MemberMgmt::release(community_id, &membership_id)?;
+		Self::count_member_out(community_id, rank);
+		MembershipRankHistory::<T>::remove(community_id, membership_id);
+		MembershipRankHistoryStart::<T>::remove(community_id, membership_id);
+
+		Self::deposit_event(Event::MemberRemoved { who, membership_id });
+		Ok(())
+	}
+
+	/// Sets the rank of a membership, keeping track of the rank it had before,
+	/// so ongoing polls keep weighing its votes by the rank it had when their
+	/// voting power was snapshotted.
+	pub(crate) fn do_set_rank(
+		community_id: &CommunityIdOf<T>,
+		membership_id: &MembershipIdOf<T>,
+		rank: GenericRank,
+	) -> DispatchResult {
+		let previous_rank = T::MemberMgmt::rank_of(community_id, membership_id).ok_or(Error::<T>::NotAMember)?;
+		ensure!(
+			CommunityRankBounds::<T>::get(community_id).contains(&rank),
+			Error::<T>::RankOutOfBounds
+		);
+		let now = frame_system::Pallet::<T>::block_number();
+
+		MembershipRankHistory::<T>::try_mutate(community_id, membership_id, |history| {
+			// Only the rank at the end of the previous block is relevant
+			if history.last().is_some_and(|(changed_at, _)| *changed_at == now) {
+				return Ok(());
+			}
+			// Ranks from long before are dropped in favour of recent ones, as
+			// long as no ongoing poll was snapshotted while they were held
+			if history.is_full() {
+				let (oldest, _) = history.remove(0);
+				Self::ensure_no_ranked_poll_before(community_id, oldest)?;
+				MembershipRankHistoryStart::<T>::insert(community_id, membership_id, oldest);
+			}
+			let _ = history.try_push((now, previous_rank));
+			Ok::<_, DispatchError>(())
+		})?;
+
+		T::MemberMgmt::set_rank(community_id, membership_id, rank)?;
+		Self::count_member_out(community_id, previous_rank);
+		Self::count_member_in(community_id, rank);
+
+		Self::deposit_event(Event::MembershipRankUpdated {
+			membership_id: *membership_id,
+			old_rank: previous_rank,
+			new_rank: rank,
+		});
+		Ok(())
+	}
+
+	/// Fails if a poll decided by rank that members of the community voted
+	/// on is still ongoing and was snapshotted before the given block.
+	/// Polls found to be over are forgotten along the way.
+	fn ensure_no_ranked_poll_before(community_id: &CommunityIdOf<T>, block: BlockNumberFor<T>) -> DispatchResult {
+		for (poll_index, snapshot_at) in CommunityRankedPolls::<T>::iter_prefix(community_id).collect::<Vec<_>>() {
+			if T::Polls::as_ongoing(poll_index).is_none() {
+				CommunityRankedPolls::<T>::remove(community_id, poll_index);
+				continue;
+			}
+			ensure!(snapshot_at >= block, Error::<T>::RankHistoryInUse);
+		}
+		Ok(())
+	}
+
+	/// The rank a membership had by the end of the given block, unless the
+	/// block is older than its rank history.
+	pub(crate) fn rank_at(
+		community_id: &CommunityIdOf<T>,
+		membership_id: &MembershipIdOf<T>,
+		at: BlockNumberFor<T>,
+	) -> Option<GenericRank> {
+		let history = MembershipRankHistory::<T>::get(community_id, membership_id);
+		// Only blocks before the oldest rank change kept can be unknown
+		if history.first().is_some_and(|(changed_at, _)| *changed_at > at)
+			&& MembershipRankHistoryStart::<T>::get(community_id, membership_id).is_some_and(|start| start > at)
+		{
+			return None;
+		}
+		Some(
+			history
+				.into_iter()
+				.find_map(|(changed_at, rank)| (changed_at > at).then_some(rank))
+				.unwrap_or_else(|| T::MemberMgmt::rank_of(community_id, membership_id).unwrap_or_default()),
+		)
+	}
+
+	/// Delegates the voting power of `membership_id` to `delegate`. Chained
+	/// delegations are not allowed, so neither the delegate can be delegating
+	/// its voting power, nor the delegator can have delegators of its own.
+	/// Expired memberships can neither delegate nor be delegated to.
+	pub(crate) fn do_delegate(
+		community_id: &CommunityIdOf<T>,
+		membership_id: &MembershipIdOf<T>,
+		delegate: &MembershipIdOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			!T::ExpiredMemberships::contains(&(*community_id, *membership_id)),
+			Error::<T>::MembershipExpired
+		);
+		ensure!(
+			!CommunityDelegations::<T>::contains_key(community_id, membership_id),
+			Error::<T>::AlreadyDelegating
+		);
+		ensure!(
+			membership_id != delegate
+				&& T::MemberMgmt::rank_of(community_id, delegate).is_some()
+				&& !T::ExpiredMemberships::contains(&(*community_id, *delegate))
+				&& !CommunityDelegations::<T>::contains_key(community_id, delegate),
+			Error::<T>::InvalidDelegate
+		);
+		ensure!(
+			CommunityDelegators::<T>::get(community_id, membership_id).is_empty(),
+			Error::<T>::HasDelegators
+		);
+
+		CommunityDelegators::<T>::try_append(community_id, delegate, *membership_id)
+			.map_err(|_| Error::<T>::TooManyDelegators)?;
+		CommunityDelegations::<T>::insert(community_id, membership_id, delegate);
+		Ok(())
+	}
+
+	/// Takes back the voting power delegated by `membership_id`, removing
+	/// the votes casted on its behalf from the tally of ongoing polls.
+	pub(crate) fn do_undelegate(community_id: &CommunityIdOf<T>, membership_id: &MembershipIdOf<T>) -> DispatchResult {
+		let delegate = CommunityDelegations::<T>::take(community_id, membership_id).ok_or(Error::<T>::NotDelegating)?;
+		CommunityDelegators::<T>::mutate(community_id, delegate, |delegators| {
+			delegators.retain(|m| m != membership_id)
+		});
+
+		for (poll_index, _) in CommunityDelegatedVotes::<T>::drain_prefix(membership_id) {
+			let Some((vote, _, vote_multiplier)) = CommunityVotes::<T>::take(poll_index, membership_id) else {
+				continue;
+			};
+			T::Polls::access_poll(poll_index, |poll_status| {
+				if let Some((tally, class)) = poll_status.ensure_ongoing() {
+					tally.remove_vote(&Self::decision_method(&class), &vote, vote_multiplier);
+				}
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Ensures the lock put in place by a vote on a finished poll can be
+	/// released. Funds voted with a conviction remain frozen for the
+	/// corresponding lock periods after the poll ends, as long as the vote
+	/// was casted on the winning side.
+	pub(crate) fn ensure_lock_expired(poll_index: PollIndexOf<T>, vote: &VoteOf<T>) -> DispatchResult {
+		let lock_periods = vote.conviction().unwrap_or_default().lock_periods();
+		if lock_periods == 0 {
+			return Ok(());
+		}
+
+		let unlock_at = T::Polls::access_poll(poll_index, |poll_status| match poll_status {
+			PollStatus::Completed(end, approved) if Some(approved) == vote.say() => {
+				Some(end.saturating_add(T::VoteLockingPeriod::get().saturating_mul(lock_periods.into())))
+			}
+			_ => None,
+		});
+
+		if let Some(unlock_at) = unlock_at {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= unlock_at,
+				Error::<T>::LockPeriodNotElapsed
+			);
+		}
+		Ok(())
+	}
+
 	pub(crate) fn update_locks(
 		who: &AccountIdOf<T>,
 		poll_index: PollIndexOf<T>,
 		vote: &VoteOf<T>,
 		update_type: LockUpdateType,
 	) -> DispatchResult {
-		use sp_runtime::traits::Zero;
-
 		let reason = FreezeReason::VoteCasted.into();
 
-		match vote.clone() {
-			Vote::AssetBalance(..) | Vote::NativeBalance(..) => match update_type {
+		let power = vote.power();
+		match power {
+			VotePower::Asset(..) | VotePower::Native(..) => match update_type {
 				LockUpdateType::Add => CommunityVoteLocks::<T>::insert(who, poll_index, vote.clone()),
 				LockUpdateType::Remove => CommunityVoteLocks::<T>::remove(who, poll_index),
 			},
-			_ => (),
+			VotePower::Membership => (),
 		}
 
-		match (update_type, vote) {
-			(LockUpdateType::Add, Vote::AssetBalance(_, asset_id, amount)) => {
-				let amount = T::AssetsFreezer::balance_frozen(asset_id.clone(), &reason, who).max(*amount);
-				T::AssetsFreezer::set_frozen(asset_id.clone(), &reason, who, amount, Polite)?;
+		match (update_type, power) {
+			(LockUpdateType::Add, VotePower::Asset(asset_id, amount)) => {
+				let amount = T::AssetsFreezer::balance_frozen(asset_id.clone(), &reason, who).max(amount);
+				T::AssetsFreezer::set_frozen(asset_id, &reason, who, amount, Polite)?;
 			}
-			(LockUpdateType::Add, Vote::NativeBalance(_, amount)) => {
-				let amount = T::Balances::balance_frozen(&reason, who).max(*amount);
+			(LockUpdateType::Add, VotePower::Native(amount)) => {
+				let amount = T::Balances::balance_frozen(&reason, who).max(amount);
 				T::Balances::set_frozen(&reason, who, amount, Polite)?;
 			}
-			(LockUpdateType::Remove, Vote::AssetBalance(_, asset_id, _)) => {
+			(LockUpdateType::Remove, VotePower::Asset(asset_id, _)) => {
 				let mut amount_to_freeze: AssetBalanceOf<T> = Zero::zero();
 
 				for locked_vote in CommunityVoteLocks::<T>::iter_prefix_values(who) {
-					if let Vote::AssetBalance(_, ref id, amount) = locked_vote {
+					if let VotePower::Asset(id, amount) = locked_vote.power() {
 						if id == asset_id {
 							amount_to_freeze = amount_to_freeze.max(amount)
 						}
 					}
 				}
 
-				T::AssetsFreezer::set_frozen(asset_id.clone(), &reason, who, amount_to_freeze, Polite)?;
+				T::AssetsFreezer::set_frozen(asset_id, &reason, who, amount_to_freeze, Polite)?;
 			}
-			(LockUpdateType::Remove, Vote::NativeBalance(_, _)) => {
+			(LockUpdateType::Remove, VotePower::Native(_)) => {
 				let mut amount_to_freeze: NativeBalanceOf<T> = Zero::zero();
 
 				for locked_vote in CommunityVoteLocks::<T>::iter_prefix_values(who) {
-					if let Vote::NativeBalance(_, amount) = locked_vote {
+					if let VotePower::Native(amount) = locked_vote.power() {
 						amount_to_freeze = amount_to_freeze.max(amount)
 					}
 				}
@@ -210,24 +1040,180 @@ impl<T: Config> Pallet<T> {
 		let post = call.dispatch(signer.into()).map_err(|e| e.error)?;
 		Ok(post)
 	}
+
+	pub(crate) fn do_dispatch_as_community_origin(
+		community_id: &CommunityIdOf<T>,
+		maybe_subset: Option<origin::Subset<T>>,
+		call: RuntimeCallFor<T>,
+	) -> DispatchResultWithInfo<PostDispatchInfo> {
+		let mut origin = crate::Origin::<T>::new(*community_id);
+		if let Some(subset) = maybe_subset {
+			origin.with_subset(subset);
+		}
+		let mut origin: RuntimeOriginFor<T> = origin.into();
+		// Spends and their limits can only be approved by the polls of the
+		// community, so they can't be dispatched on its behalf, not even
+		// nested within other calls
+		origin.add_filter(|call: &RuntimeCallFor<T>| {
+			!matches!(
+				call.is_sub_type(),
+				Some(Call::spend { .. } | Call::set_spend_limit { .. })
+			)
+		});
+
+		let post = call.dispatch(origin).map_err(|e| e.error)?;
+		Ok(post)
+	}
+
+	/// Records the approval of `membership_id` to dispatch `call` as a subset
+	/// of the community, dispatching it once the subset has enough approvals.
+	pub(crate) fn do_approve_as_subset(
+		community_id: &CommunityIdOf<T>,
+		membership_id: &MembershipIdOf<T>,
+		subset: origin::Subset<T>,
+		call: RuntimeCallFor<T>,
+	) -> DispatchResultWithPostInfo {
+		use origin::Subset;
+
+		ensure!(
+			!T::ExpiredMemberships::contains(&(*community_id, *membership_id)),
+			Error::<T>::MembershipExpired
+		);
+		match &subset {
+			Subset::Member(m) => ensure!(m == membership_id, Error::<T>::NotInSubset),
+			Subset::AtLeastRank(min) => ensure!(
+				T::MemberMgmt::rank_of(community_id, membership_id).is_some_and(|rank| &rank >= min),
+				Error::<T>::NotInSubset
+			),
+			Subset::Members { count } => ensure!(*count > 0, Error::<T>::InvalidSubset),
+			Subset::Fraction(fraction) => ensure!(!fraction.is_zero(), Error::<T>::InvalidSubset),
+		}
+
+		let call_hash = T::Hashing::hash_of(&(&subset, &call));
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut info = match SubsetApprovalsCount::<T>::get(community_id, call_hash) {
+			Some(info) if info.expires_at >= now => info,
+			_ => {
+				// Approvals that expired are discarded, and the approvals the call
+				// needs are set by the members of the subset at this point
+				let _ = SubsetApprovals::<T>::clear_prefix((community_id, call_hash), u32::MAX, None);
+				SubsetApprovalsOf::<T> {
+					approvals: 0,
+					threshold: Self::subset_threshold(community_id, &subset),
+					expires_at: now.saturating_add(T::SubsetApprovalPeriod::get()),
+				}
+			}
+		};
+		ensure!(
+			!SubsetApprovals::<T>::contains_key((community_id, call_hash, membership_id)),
+			Error::<T>::AlreadyApproved
+		);
+
+		info.approvals.saturating_inc();
+		if info.approvals < info.threshold {
+			let approvals = info.approvals;
+			SubsetApprovals::<T>::insert((community_id, call_hash, membership_id), ());
+			SubsetApprovalsCount::<T>::insert(community_id, call_hash, info);
+			Self::deposit_event(Event::SubsetCallApproved {
+				id: *community_id,
+				membership_id: *membership_id,
+				call_hash,
+				approvals,
+			});
+			return Ok(Some(T::WeightInfo::dispatch_as_subset()).into());
+		}
+
+		let _ = SubsetApprovals::<T>::clear_prefix((community_id, call_hash), u32::MAX, None);
+		SubsetApprovalsCount::<T>::remove(community_id, call_hash);
+
+		let post = Self::do_dispatch_as_community_origin(community_id, Some(subset), call)?;
+		Self::deposit_event(Event::SubsetCallDispatched {
+			id: *community_id,
+			call_hash,
+		});
+		Ok(post)
+	}
+
+	/// The number of approvals needed to dispatch a call as `subset`, which
+	/// scales with the members of the community, as counted until expired
+	/// memberships are pruned.
+	fn subset_threshold(community_id: &CommunityIdOf<T>, subset: &origin::Subset<T>) -> u32 {
+		use origin::Subset;
+		use sp_runtime::PerThing;
+
+		let threshold = match subset {
+			Subset::Member(_) => 1,
+			Subset::Members { count } => *count,
+			Subset::Fraction(fraction) => fraction.mul_ceil(Self::members_count(community_id).members),
+			Subset::AtLeastRank(min) => {
+				// Goes through the ranks held in the community, not its members
+				let ranked = CommunityRankCount::<T>::iter_prefix(community_id)
+					.filter(|(rank, _)| rank >= min)
+					.fold(0u32, |ranked, (_, count)| ranked.saturating_add(count));
+				// A majority of the memberships holding the rank
+				ranked / 2 + 1
+			}
+		};
+		threshold.max(1)
+	}
 }
 
 impl<T: Config> Tally<T> {
-	pub(self) fn add_vote(&mut self, say_aye: bool, multiplied_weight: VoteWeight, weight: VoteWeight) {
-		if say_aye {
-			self.ayes = self.ayes.saturating_add(multiplied_weight);
-			self.bare_ayes = self.bare_ayes.saturating_add(weight);
-		} else {
-			self.nays = self.nays.saturating_add(multiplied_weight);
-		}
+	/// The block at which the voting power of the poll was snapshotted.
+	pub(self) fn snapshot_block(&self) -> BlockNumberFor<T> {
+		self.snapshot_at.unwrap_or_else(frame_system::Pallet::<T>::block_number)
 	}
 
-	pub(self) fn remove_vote(&mut self, say_aye: bool, multiplied_weight: VoteWeight, weight: VoteWeight) {
-		if say_aye {
-			self.ayes = self.ayes.saturating_sub(multiplied_weight);
-			self.bare_ayes = self.bare_ayes.saturating_sub(weight);
-		} else {
-			self.nays = self.nays.saturating_sub(multiplied_weight);
+	pub(crate) fn add_vote(
+		&mut self,
+		decision_method: &DecisionMethodFor<T>,
+		vote: &VoteOf<T>,
+		multiplier: VoteWeight,
+	) {
+		let (ayes, nays, bare_ayes, abstentions) = Self::vote_shares(decision_method, vote, multiplier);
+		self.ayes = self.ayes.saturating_add(ayes);
+		self.nays = self.nays.saturating_add(nays);
+		self.bare_ayes = self.bare_ayes.saturating_add(bare_ayes);
+		self.abstentions = self.abstentions.saturating_add(abstentions);
+	}
+
+	pub(self) fn remove_vote(
+		&mut self,
+		decision_method: &DecisionMethodFor<T>,
+		vote: &VoteOf<T>,
+		multiplier: VoteWeight,
+	) {
+		let (ayes, nays, bare_ayes, abstentions) = Self::vote_shares(decision_method, vote, multiplier);
+		self.ayes = self.ayes.saturating_sub(ayes);
+		self.nays = self.nays.saturating_sub(nays);
+		self.bare_ayes = self.bare_ayes.saturating_sub(bare_ayes);
+		self.abstentions = self.abstentions.saturating_sub(abstentions);
+	}
+
+	/// The ayes, nays, bare ayes and abstentions a vote accounts for. Split
+	/// votes round their ayes and nays down, so the remainder abstains.
+	fn vote_shares(
+		decision_method: &DecisionMethodFor<T>,
+		vote: &VoteOf<T>,
+		multiplier: VoteWeight,
+	) -> (VoteWeight, VoteWeight, VoteWeight, VoteWeight) {
+		let weight = vote.weight();
+		let approval_weight = multiplier.saturating_mul(Pallet::<T>::approval_weight(decision_method, vote));
+
+		match (vote, vote.say()) {
+			(Vote::Split { aye, nay, .. }, _) => {
+				let bare_ayes = aye.mul_floor(weight);
+				let abstentions = weight.saturating_sub(bare_ayes).saturating_sub(nay.mul_floor(weight));
+				(
+					aye.mul_floor(approval_weight),
+					nay.mul_floor(approval_weight),
+					bare_ayes,
+					abstentions,
+				)
+			}
+			(_, Some(true)) => (approval_weight, 0, weight, 0),
+			(_, Some(false)) => (0, approval_weight, 0, 0),
+			(_, None) => (0, 0, 0, weight),
 		}
 	}
 }
@@ -2,61 +2,75 @@ use frame_support::traits::VoteTally;
 use sp_runtime::Perbill;
 
 use crate::{
-	types::{CommunityIdOf, Tally, VoteWeight},
+	types::{Tally, VoteWeight},
 	Config,
 };
 
-impl<T: Config> VoteTally<VoteWeight, CommunityIdOf<T>> for Tally<T> {
-	fn new(_cid: CommunityIdOf<T>) -> Self {
-		Self::default()
+/// Polls are classified by track. Support is measured against the voting
+/// power of the community owning the track, as snapshotted in the tally.
+impl<T: Config> VoteTally<VoteWeight, T::TrackId> for Tally<T> {
+	/// The voting power of the community is snapshotted when the poll is
+	/// submitted.
+	fn new(track_id: T::TrackId) -> Self {
+		Self {
+			max_support: Self::max_support(track_id),
+			snapshot_at: Some(frame_system::Pallet::<T>::block_number()),
+			..Default::default()
+		}
 	}
 
-	fn ayes(&self, _cid: CommunityIdOf<T>) -> VoteWeight {
+	fn ayes(&self, _track_id: T::TrackId) -> VoteWeight {
 		self.ayes
 	}
 
-	fn support(&self, community_id: CommunityIdOf<T>) -> sp_runtime::Perbill {
-		Perbill::from_rational(self.bare_ayes, Self::max_support(community_id))
+	fn support(&self, _track_id: T::TrackId) -> sp_runtime::Perbill {
+		Perbill::from_rational(self.bare_ayes.saturating_add(self.abstentions), 1.max(self.max_support))
 	}
 
-	fn approval(&self, _cid: CommunityIdOf<T>) -> sp_runtime::Perbill {
+	fn approval(&self, _track_id: T::TrackId) -> sp_runtime::Perbill {
 		Perbill::from_rational(self.ayes, 1.max(self.ayes + self.nays))
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn unanimity(community_id: CommunityIdOf<T>) -> Self {
+	fn unanimity(track_id: T::TrackId) -> Self {
 		Self {
-			ayes: Self::max_support(community_id),
-			bare_ayes: Self::max_support(community_id),
+			ayes: Self::max_support(track_id),
+			bare_ayes: Self::max_support(track_id),
 			nays: 0,
+			max_support: Self::max_support(track_id),
+			snapshot_at: Some(frame_system::Pallet::<T>::block_number()),
 			..Default::default()
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn rejection(community_id: CommunityIdOf<T>) -> Self {
+	fn rejection(track_id: T::TrackId) -> Self {
 		Self {
 			ayes: 0,
 			bare_ayes: 0,
-			nays: Self::max_support(community_id),
+			nays: Self::max_support(track_id),
+			max_support: Self::max_support(track_id),
+			snapshot_at: Some(frame_system::Pallet::<T>::block_number()),
 			..Default::default()
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn from_requirements(support: Perbill, approval: Perbill, community_id: CommunityIdOf<T>) -> Self {
-		let approval_weight = approval * Self::max_support(community_id);
-		let rejection_weight = (Perbill::from_percent(100) - approval) * Self::max_support(community_id);
-		let support_weight = support * Self::max_support(community_id);
+	fn from_requirements(support: Perbill, approval: Perbill, track_id: T::TrackId) -> Self {
+		let approval_weight = approval * Self::max_support(track_id);
+		let rejection_weight = (Perbill::from_percent(100) - approval) * Self::max_support(track_id);
+		let support_weight = support * Self::max_support(track_id);
 
 		Self {
 			ayes: approval_weight,
 			nays: rejection_weight,
 			bare_ayes: support_weight,
+			max_support: Self::max_support(track_id),
+			snapshot_at: Some(frame_system::Pallet::<T>::block_number()),
 			..Default::default()
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn setup(_community_id: CommunityIdOf<T>, _granularity: Perbill) {}
+	fn setup(_track_id: T::TrackId, _granularity: Perbill) {}
 }
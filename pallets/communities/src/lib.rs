@@ -39,6 +39,8 @@
 //!   dispatched from a proposal.
 //! - **Decision Method:** Can be either rank weighed, member-counted, or
 //!   asset-weighed and determines how the votes of proposals will be tallied.
+//!   Asset-weighed votes can also be weighed quadratically or by a custom curve
+//!   provided by the runtime.
 //!
 //! ## Lifecycle
 //!
@@ -63,6 +65,15 @@
 //!
 //! - [`apply_for`][c00]: Registers an appliation as a new community, taking an
 //!   [existential deposit][3] used to create the community account.
+//! - `apply_for_membership`: Requests a membership of a community along with a
+//!   message, holding a deposit until the application is decided on or expires.
+//! - `remove_expired_application`: Removes an application the community didn't
+//!   decide on in time, returning its deposit.
+//! - `claim_invitation`: Claims the membership set aside for an invitation
+//!   with a signature of the invitation key over the claiming account.
+//! - `payout`: Pays out an approved spend from the treasury of a community,
+//!   once it's valid and before it expires.
+//! - `remove_expired_spend`: Removes a spend that wasn't paid out in time.
 //!
 //! ### Permissioned Functions
 //!
@@ -71,7 +82,17 @@
 //! - [`add_member`][c02]: Enroll an account as a community member. In theory,
 //!   any community member should be able to add a member. However, this can be
 //!   changed to ensure it is a privileged function.
-//! - `vote`: Adds a vote into a community proposal.
+//! - `vote`: Adds a vote into a community proposal. Votes backed by tokens
+//!   can be casted with a conviction, multiplying their weight in exchange of
+//!   keeping the funds frozen for longer once the poll ends.
+//!   Votes can also be split between ayes and nays, or abstain while counting
+//!   toward support.
+//! - `unlock`: Releases the funds frozen by a vote, once the poll and the
+//!   lock period of its conviction are over.
+//! - `leave`: Releases a membership of the caller, removing its votes on ongoing
+//!   polls and its delegations.
+//! - `prune_expired_member`: Removes a member whose membership has expired.
+//!   Anyone can call it, as expired memberships can no longer vote.
 //!
 //! ### Privileged Functions
 //!
@@ -83,9 +104,37 @@
 //!   any community member. Also, it shouldn't be possible to arbitrarily remove
 //!   the community admin, as some privileged calls would be impossible execute
 //!   thereafter.
+//! - `approve_application`: Enrolls an applicant as a member of the community,
+//!   returning its deposit.
+//! - `reject_application`: Rejects an application, transferring its deposit to
+//!   the community account.
+//! - `invite_member`: Sets aside a membership for whoever holds the private
+//!   part of an invitation key, without knowing their account in advance.
+//! - `revoke_invitation`: Returns the membership of an unclaimed invitation to
+//!   the pool of the community.
+//! - `add_members`, `remove_members` and `set_ranks`: Enroll, remove or rank
+//!   several members at once, up to `MaxMembersPerBatch` per call.
 //! - `promote`: Increases the rank of a member in the community.
 //! - `demote`: Decreases the rank of a member in the community.
-//! - `set_decision_method`: Means for a community to make decisions.
+//! - `set_member_rank`: Sets the rank of a member in the community to any
+//!   rank within the bounds set by the community.
+//! - `set_rank_bounds`: Sets the lowest and highest ranks members of the
+//!   community can be set to.
+//! - `spend`: Approves a spend from the community account (its treasury) in
+//!   the native token or an asset, paid out from a given block on. Spends are
+//!   approved by the polls of a track, and can't exceed the limit set for it.
+//! - `void_spend`: Voids an approved spend before it's paid out.
+//! - `set_spend_limit`: Sets the highest amount of an asset a spend approved by
+//!   the polls of a track can pay out.
+//! - `create_asset`: Creates an asset issued by the community, owned by its
+//!   community account, along with its metadata.
+//! - `set_asset_metadata`, `mint_asset` and `burn_asset`: Manage the metadata
+//!   and supply of an asset issued by the community.
+//! - `claim_asset`: Transfers to the community account the ownership of an
+//!   asset the pallet created on behalf of the community when setting a token
+//!   based decision method.
+//! - `set_decision_method`: Means for a community to make decisions, either by
+//!   default or on the polls of a given track.
 //!
 //! ### Public Functions
 //!
@@ -125,6 +174,8 @@ mod tests;
 
 mod functions;
 mod impls;
+pub mod migrations;
+mod treasury;
 
 pub mod types;
 pub use types::*;
@@ -142,17 +193,20 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::{DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::*,
-		traits::{fungible, fungibles, EnsureOrigin, IsSubType, OriginTrait, Polling},
+		traits::{fungible, fungibles, nonfungibles_v2, Contains, EnsureOrigin, IsSubType, OriginTrait, Polling},
 		Blake2_128Concat, Parameter,
 	};
 	use frame_system::pallet_prelude::{ensure_signed, BlockNumberFor, OriginFor};
-	use sp_runtime::traits::AccountIdConversion;
-	use sp_runtime::traits::{Dispatchable, StaticLookup};
-	use sp_std::prelude::Box;
+	use sp_runtime::traits::{Dispatchable, IdentifyAccount, StaticLookup, TryConvert, Verify};
+	use sp_std::prelude::{Box, Vec};
 
 	const ONE: NonZeroU8 = NonZeroU8::MIN;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it
@@ -193,9 +247,17 @@ pub mod pallet {
 		/// Origin authorized to manage memeberships of an active community
 		type MemberMgmtOrigin: EnsureOrigin<OriginFor<Self>, Success = Self::CommunityId>;
 
+		/// Origin authorized to block and unblock communities that violate
+		/// the norms of the network
+		type BlockOrigin: EnsureOrigin<OriginFor<Self>>;
+
+		/// Identifier of the referenda tracks communities vote on. A
+		/// community can own several tracks, all of them resolving to it.
+		type TrackId: Parameter + MaxEncodedLen + Copy + CommunityTrackId<CommunityIdOf<Self>>;
+
 		type Polls: Polling<
 			Tally<Self>,
-			Class = CommunityIdOf<Self>,
+			Class = Self::TrackId,
 			Index = u32,
 			Votes = VoteWeight,
 			Moment = BlockNumberFor<Self>,
@@ -204,7 +266,10 @@ pub mod pallet {
 		/// Type represents interactions between fungibles (i.e. assets)
 		type Assets: fungibles::Inspect<Self::AccountId, Balance = NativeBalanceOf<Self>>
 			+ fungibles::Mutate<Self::AccountId>
-			+ fungibles::Create<Self::AccountId>;
+			+ fungibles::Create<Self::AccountId>
+			+ fungibles::metadata::Mutate<Self::AccountId>
+			+ fungibles::roles::Inspect<Self::AccountId>
+			+ fungibles::roles::ResetTeam<Self::AccountId>;
 
 		/// Type allows for handling fungibles' freezes
 		type AssetsFreezer: fungibles::Inspect<Self::AccountId, Balance = NativeBalanceOf<Self>, AssetId = AssetIdOf<Self>>
@@ -214,6 +279,7 @@ pub mod pallet {
 		/// Type represents interactions between fungible tokens (native token)
 		type Balances: fungible::Inspect<Self::AccountId>
 			+ fungible::Mutate<Self::AccountId>
+			+ fungible::hold::Mutate<Self::AccountId, Reason = Self::RuntimeHoldReason>
 			+ fungible::freeze::Inspect<Self::AccountId, Id = Self::RuntimeFreezeReason>
 			+ fungible::freeze::Mutate<Self::AccountId, Id = Self::RuntimeFreezeReason>;
 
@@ -236,6 +302,9 @@ pub mod pallet {
 		/// The overarching freeze reason.
 		type RuntimeFreezeReason: From<FreezeReason>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
 		/// Because this pallet emits events, it depends on the runtime's
 		/// definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -247,6 +316,80 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<frame_support::PalletId>;
 
+		/// The maximum number of votes or records to clean up in a single
+		/// [`destroy_votes`][`Pallet::destroy_votes`] or
+		/// [`destroy_records`][`Pallet::destroy_records`] call.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
+		/// The maximum number of memberships that can delegate their voting
+		/// power to a single membership.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+
+		/// The number of blocks a single lock period lasts. Funds voted with a
+		/// [`Conviction`][`types::Conviction`] remain frozen for a multiple of
+		/// this period after the poll ends.
+		#[pallet::constant]
+		type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The curve that weighs the votes of polls decided with
+		/// [`DecisionMethod::CustomCurve`][`types::DecisionMethod::CustomCurve`].
+		type VoteCurve: VoteCurve;
+
+		/// Memberships that have expired. They can't vote, and anyone can
+		/// prune them from their community.
+		type ExpiredMemberships: Contains<(CommunityIdOf<Self>, MembershipIdOf<Self>)>;
+
+		/// Enumerates the memberships held by the members of each community,
+		/// so the ones that expired can be left out when counting them.
+		type Memberships: nonfungibles_v2::InspectEnumerable<
+			AccountIdOf<Self>,
+			CollectionId = CommunityIdOf<Self>,
+			ItemId = MembershipIdOf<Self>,
+		>;
+
+		/// The amount held from an account applying for a membership. It's
+		/// returned once the application is approved or expires, and
+		/// transferred to the community account if it's rejected.
+		#[pallet::constant]
+		type ApplicationDeposit: Get<NativeBalanceOf<Self>>;
+
+		/// The number of blocks a membership application remains pending
+		/// before it expires.
+		#[pallet::constant]
+		type ApplicationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of members that can be added, removed or ranked
+		/// in a single call.
+		#[pallet::constant]
+		type MaxMembersPerBatch: Get<u32>;
+
+		/// The signature an invitation is claimed with, made by its invitation
+		/// key over the community and the account claiming it.
+		type InvitationSignature: Verify<Signer = Self::InvitationPublic> + Parameter;
+
+		/// The public part of an invitation key.
+		type InvitationPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Resolves the track a community origin belongs to, so the spends
+		/// approved by the polls of each track can be limited.
+		type TrackForOrigin: TryConvert<PalletsOriginOf<Self>, Self::TrackId>;
+
+		/// Origin authorized to approve spends from the treasury of an active
+		/// community, resolving to the track whose spend limits apply.
+		type SpendOrigin: EnsureOrigin<OriginFor<Self>, Success = Self::TrackId>;
+
+		/// The number of blocks an approved spend can be paid out for, once
+		/// it becomes valid.
+		#[pallet::constant]
+		type PayoutPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks the approvals to dispatch a call as a subset
+		/// of a community are kept, counting from the first approval.
+		#[pallet::constant]
+		type SubsetApprovalPeriod: Get<BlockNumberFor<Self>>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<Self>;
 	}
@@ -262,22 +405,100 @@ pub mod pallet {
 		VoteCasted,
 	}
 
+	/// A reason for the pallet communities placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		// An account has applied for a membership of a community
+		MembershipApplication,
+	}
+
 	/// Stores the basic information of the community. If a value exists for a
 	/// specified [`ComumunityId`][`Config::CommunityId`], this means a
 	/// community exists.
 	#[pallet::storage]
 	pub(super) type Info<T> = StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, CommunityInfo>;
 
+	/// Stores the metadata regarding a community.
+	#[pallet::storage]
+	pub(super) type Metadata<T> = StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, CommunityMetadataFor<T>>;
+
 	/// List of origins and how they map to communities
 	#[pallet::storage]
 	pub(super) type CommunityIdFor<T> = StorageMap<_, Blake2_128Concat, PalletsOriginOf<T>, CommunityIdOf<T>>;
 
+	/// Stores the admin origin of each community, the reverse of
+	/// [`CommunityIdFor`], so it can be released once the community is
+	/// destroyed.
+	#[pallet::storage]
+	pub(super) type CommunityAdminOrigin<T> = StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, PalletsOriginOf<T>>;
+
 	/// Stores the decision method for a community
 	#[pallet::storage]
 	pub(super) type CommunityDecisionMethod<T> =
 		StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, DecisionMethodFor<T>, ValueQuery>;
 
-	/// Stores the list of votes for a community.
+	/// Stores the decision method for the tracks of a community that don't
+	/// use the default decision method of the community.
+	#[pallet::storage]
+	pub(super) type TrackDecisionMethod<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, T::TrackId, DecisionMethodFor<T>>;
+
+	/// Ranks a membership had before its latest rank changes, along with the
+	/// block each change happened at. Used to weigh votes by the rank members
+	/// had when the voting power of a poll was snapshotted.
+	#[pallet::storage]
+	pub(super) type MembershipRankHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdOf<T>,
+		Blake2_128Concat,
+		MembershipIdOf<T>,
+		RankHistoryOf<T>,
+		ValueQuery,
+	>;
+
+	/// The block the rank history of a membership starts at, once its oldest
+	/// entries were dropped to make room for newer ones. The rank the
+	/// membership had by the end of any earlier block is no longer known.
+	#[pallet::storage]
+	pub(super) type MembershipRankHistoryStart<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, MembershipIdOf<T>, BlockNumberFor<T>>;
+
+	/// Stores the polls decided by rank that members of a community have
+	/// casted a vote on, along with the block their voting power was
+	/// snapshotted at, so the ranks they depend on are kept while they're
+	/// ongoing. Polls are forgotten once they're found to be over.
+	#[pallet::storage]
+	pub(super) type CommunityRankedPolls<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, PollIndexOf<T>, BlockNumberFor<T>>;
+
+	/// Stores the number of members of a community and the sum of their
+	/// ranks. Expired memberships are counted until they're pruned.
+	#[pallet::storage]
+	pub(super) type CommunityMembersCount<T> =
+		StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, MembersCount, ValueQuery>;
+
+	/// Stores how many members of a community hold each rank. Expired
+	/// memberships are counted until they're pruned.
+	#[pallet::storage]
+	pub(super) type CommunityRankCount<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdOf<T>,
+		Blake2_128Concat,
+		membership::GenericRank,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Stores the lowest and highest ranks the members of a community can be
+	/// set to.
+	#[pallet::storage]
+	pub(super) type CommunityRankBounds<T> = StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, RankBounds, ValueQuery>;
+
+	/// Stores the list of votes for a community, along with the account that
+	/// casted them and the multiplier they were tallied with, so they're
+	/// removed from the tally with the same weight.
 	#[pallet::storage]
 	pub(super) type CommunityVotes<T> = StorageDoubleMap<
 		_,
@@ -285,14 +506,128 @@ pub mod pallet {
 		PollIndexOf<T>,
 		Blake2_128Concat,
 		MembershipIdOf<T>,
-		(VoteOf<T>, AccountIdOf<T>),
+		(VoteOf<T>, AccountIdOf<T>, VoteWeight),
 	>;
 
+	/// Stores the polls where members of a community have casted a vote, so
+	/// they can be cleaned up once the community is destroyed.
+	#[pallet::storage]
+	pub(super) type CommunityPolls<T> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, PollIndexOf<T>, ()>;
+
 	/// Stores the list of votes for a community.
 	#[pallet::storage]
 	pub(super) type CommunityVoteLocks<T> =
 		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, PollIndexOf<T>, VoteOf<T>>;
 
+	/// Stores the membership a membership has delegated its voting power to.
+	#[pallet::storage]
+	pub(super) type CommunityDelegations<T> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, MembershipIdOf<T>, MembershipIdOf<T>>;
+
+	/// Stores the list of memberships that delegated their voting power to a
+	/// membership.
+	#[pallet::storage]
+	pub(super) type CommunityDelegators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdOf<T>,
+		Blake2_128Concat,
+		MembershipIdOf<T>,
+		BoundedVec<MembershipIdOf<T>, T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// Stores the memberships that approved dispatching a call as a subset of
+	/// a community, keyed by the community and the hash of the subset and the
+	/// call.
+	#[pallet::storage]
+	pub(super) type SubsetApprovals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CommunityIdOf<T>>,
+			NMapKey<Blake2_128Concat, T::Hash>,
+			NMapKey<Blake2_128Concat, MembershipIdOf<T>>,
+		),
+		(),
+	>;
+
+	/// Stores the number of approvals to dispatch a call as a subset of a
+	/// community, along with the approvals it needs and when they expire.
+	#[pallet::storage]
+	pub(super) type SubsetApprovalsCount<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, T::Hash, SubsetApprovalsOf<T>>;
+
+	/// Stores the polls where the vote of a membership was casted by its
+	/// delegate, so it can be removed along with the delegation.
+	#[pallet::storage]
+	pub(super) type CommunityDelegatedVotes<T> =
+		StorageDoubleMap<_, Blake2_128Concat, MembershipIdOf<T>, Blake2_128Concat, PollIndexOf<T>, ()>;
+
+	/// Stores the polls a membership casted a vote on by itself, so its votes
+	/// can be removed when it leaves the community.
+	#[pallet::storage]
+	pub(super) type MembershipVotes<T> =
+		StorageDoubleMap<_, Blake2_128Concat, MembershipIdOf<T>, Blake2_128Concat, PollIndexOf<T>, ()>;
+
+	/// Stores the memberships of a community set aside for whoever can sign
+	/// with the key of an invitation.
+	#[pallet::storage]
+	pub(super) type MembershipInvitations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, AccountIdOf<T>, MembershipIdOf<T>>;
+
+	/// Stores the memberships of a community that are set aside for an
+	/// invitation, so they aren't given out to new members.
+	#[pallet::storage]
+	pub(super) type InvitedMemberships<T> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, MembershipIdOf<T>, ()>;
+
+	/// Stores the pending applications of accounts willing to become members
+	/// of a community.
+	#[pallet::storage]
+	pub(super) type MembershipApplications<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		MembershipApplicationOf<T>,
+	>;
+
+	/// Stores the highest amount of an asset a single spend approved by the
+	/// polls of a track of a community can pay out.
+	#[pallet::storage]
+	pub(super) type TrackSpendLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CommunityIdOf<T>,
+		Blake2_128Concat,
+		(T::TrackId, SpendAssetOf<T>),
+		NativeBalanceOf<T>,
+	>;
+
+	/// Stores the number of spends a community has approved, used to index
+	/// the next one.
+	#[pallet::storage]
+	pub(super) type SpendCount<T> = StorageMap<_, Blake2_128Concat, CommunityIdOf<T>, SpendIndex, ValueQuery>;
+
+	/// Stores the spends approved by a community that are yet to be paid out
+	/// of its treasury.
+	#[pallet::storage]
+	pub(super) type Spends<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, SpendIndex, SpendInfoOf<T>>;
+
+	/// Stores the assets issued by a community, which it manages through its
+	/// community account.
+	#[pallet::storage]
+	pub(super) type CommunityAssets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CommunityIdOf<T>, Blake2_128Concat, AssetIdOf<T>, ()>;
+
+	/// Stores the community each asset created by the pallet was created for,
+	/// so only that community can claim it.
+	#[pallet::storage]
+	pub(super) type AssetCreators<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, CommunityIdOf<T>>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -309,6 +644,41 @@ pub mod pallet {
 		},
 		DecisionMethodSet {
 			id: T::CommunityId,
+			track_id: Option<T::TrackId>,
+		},
+		MetadataSet {
+			id: T::CommunityId,
+			name: ConstSizedField<64>,
+		},
+		MetadataCleared {
+			id: T::CommunityId,
+		},
+		CommunityBlocked {
+			id: T::CommunityId,
+			reason: ConstSizedField<256>,
+		},
+		CommunityUnblocked {
+			id: T::CommunityId,
+		},
+		/// The destruction of a community has started.
+		DestructionStarted {
+			id: T::CommunityId,
+		},
+		/// Votes of a community being destroyed were removed, and their locks
+		/// released.
+		VotesDestroyed {
+			id: T::CommunityId,
+			votes_destroyed: u32,
+		},
+		/// Records of a community being destroyed were removed, and the
+		/// deposits of its pending applications released.
+		RecordsDestroyed {
+			id: T::CommunityId,
+			records_destroyed: u32,
+		},
+		/// A community has been completely destroyed.
+		CommunityDestroyed {
+			id: T::CommunityId,
 		},
 		MemberAdded {
 			who: AccountIdOf<T>,
@@ -320,7 +690,14 @@ pub mod pallet {
 		},
 		MembershipRankUpdated {
 			membership_id: MembershipIdOf<T>,
-			rank: membership::GenericRank,
+			old_rank: membership::GenericRank,
+			new_rank: membership::GenericRank,
+		},
+		/// The bounds the ranks of the members of a community can be set to
+		/// have changed.
+		RankBoundsSet {
+			id: T::CommunityId,
+			bounds: RankBounds,
 		},
 		VoteCasted {
 			who: AccountIdOf<T>,
@@ -331,6 +708,114 @@ pub mod pallet {
 			who: AccountIdOf<T>,
 			poll_index: PollIndexOf<T>,
 		},
+		/// A membership has delegated its voting power to another membership.
+		Delegated {
+			membership_id: MembershipIdOf<T>,
+			delegate: MembershipIdOf<T>,
+		},
+		/// A membership has taken back the voting power it delegated.
+		Undelegated {
+			membership_id: MembershipIdOf<T>,
+		},
+		/// A membership approved dispatching a call as a subset of the
+		/// community, which is yet to get enough approvals.
+		SubsetCallApproved {
+			id: T::CommunityId,
+			membership_id: MembershipIdOf<T>,
+			call_hash: T::Hash,
+			approvals: u32,
+		},
+		/// A call got enough approvals and was dispatched as a subset of the
+		/// community.
+		SubsetCallDispatched {
+			id: T::CommunityId,
+			call_hash: T::Hash,
+		},
+		/// An account has applied for a membership of a community.
+		MembershipApplied {
+			id: T::CommunityId,
+			who: AccountIdOf<T>,
+		},
+		/// A membership application has been approved, and the applicant
+		/// added as a member.
+		MembershipApplicationApproved {
+			id: T::CommunityId,
+			who: AccountIdOf<T>,
+		},
+		/// A membership application has been rejected, and its deposit
+		/// transferred to the community account.
+		MembershipApplicationRejected {
+			id: T::CommunityId,
+			who: AccountIdOf<T>,
+		},
+		/// A membership application has expired, and its deposit returned to
+		/// the applicant.
+		MembershipApplicationExpired {
+			id: T::CommunityId,
+			who: AccountIdOf<T>,
+		},
+		/// A membership has been set aside for an invitation.
+		MemberInvited {
+			id: T::CommunityId,
+			membership_id: MembershipIdOf<T>,
+			invitation_key: AccountIdOf<T>,
+		},
+		/// An invitation has been claimed, and its membership given to the
+		/// claimer.
+		InvitationClaimed {
+			id: T::CommunityId,
+			who: AccountIdOf<T>,
+			membership_id: MembershipIdOf<T>,
+		},
+		/// An invitation has been revoked, and its membership returned to the
+		/// pool of the community.
+		InvitationRevoked {
+			id: T::CommunityId,
+			invitation_key: AccountIdOf<T>,
+		},
+		/// A spend from the treasury of a community has been approved.
+		SpendApproved {
+			id: T::CommunityId,
+			index: SpendIndex,
+			asset_kind: SpendAssetOf<T>,
+			amount: NativeBalanceOf<T>,
+			beneficiary: AccountIdOf<T>,
+			valid_from: BlockNumberFor<T>,
+		},
+		/// An approved spend has been paid out to its beneficiary.
+		SpendPaid {
+			id: T::CommunityId,
+			index: SpendIndex,
+		},
+		/// An approved spend has been voided before being paid out.
+		SpendVoided {
+			id: T::CommunityId,
+			index: SpendIndex,
+		},
+		/// An approved spend has expired before being paid out, and was
+		/// removed.
+		SpendExpired {
+			id: T::CommunityId,
+			index: SpendIndex,
+		},
+		/// The limit of the spends approved by the polls of a track has
+		/// changed.
+		SpendLimitSet {
+			track_id: T::TrackId,
+			asset_kind: SpendAssetOf<T>,
+			limit: Option<NativeBalanceOf<T>>,
+		},
+		/// An asset has been created and linked to a community.
+		CommunityAssetCreated {
+			id: T::CommunityId,
+			asset_id: AssetIdOf<T>,
+		},
+		/// A community has claimed the ownership of an asset created on its
+		/// behalf by the pallet.
+		CommunityAssetClaimed {
+			id: T::CommunityId,
+			asset_id: AssetIdOf<T>,
+		},
 	}
 
 	// Errors inform users that something worked or went wrong.
@@ -365,6 +850,98 @@ pub mod pallet {
 		AlreadyAdmin,
 		/// The vote is below the minimum requried
 		VoteBelowMinimum,
+		/// The community has no metadata to clear
+		NoMetadataSet,
+		/// The community is blocked and can't perform this action
+		CommunityBlocked,
+		/// The community is already blocked
+		AlreadyBlocked,
+		/// The community is not blocked, therefore cannot be unblocked
+		NotBlocked,
+		/// The community is being destroyed
+		CommunityDestroying,
+		/// The community must be in the `Destroying` state to perform this
+		/// action
+		NotBeingDestroyed,
+		/// The community still has an ongoing poll, so its votes can't be
+		/// destroyed yet
+		PollOngoing,
+		/// The community still has votes to be destroyed
+		VotesRemaining,
+		/// The community still has records to be destroyed
+		RecordsRemaining,
+		/// The membership has already delegated its voting power
+		AlreadyDelegating,
+		/// The membership has not delegated its voting power
+		NotDelegating,
+		/// The delegate is not a membership of the same community, or it has
+		/// delegated its own voting power
+		InvalidDelegate,
+		/// The membership has received delegations, so it can't delegate its
+		/// voting power
+		HasDelegators,
+		/// The delegate has reached the maximum number of delegators
+		TooManyDelegators,
+		/// The lock period of a vote casted with conviction hasn't elapsed yet
+		LockPeriodNotElapsed,
+		/// The membership can't speak for the given subset of the community
+		NotInSubset,
+		/// The membership already approved dispatching this call
+		AlreadyApproved,
+		/// The ayes and nays of a split vote exceed its voting power
+		InvalidVoteSplit,
+		/// The membership has expired
+		MembershipExpired,
+		/// The membership hasn't expired, so it can't be pruned
+		MembershipNotExpired,
+		/// The account is already a member of the community
+		AlreadyAMember,
+		/// The account already has a pending application to the community
+		AlreadyApplied,
+		/// The account hasn't applied for a membership of the community
+		ApplicationNotFound,
+		/// The application has expired, so it can't be approved nor rejected
+		ApplicationExpired,
+		/// The application hasn't expired yet
+		ApplicationNotExpired,
+		/// There's already an invitation with the same key
+		InvitationAlreadyExists,
+		/// There's no invitation matching the given key
+		InvitationNotFound,
+		/// The rank is out of the bounds set by the community
+		RankOutOfBounds,
+		/// The lowest rank of the bounds is higher than the highest one
+		InvalidRankBounds,
+		/// The rank history of the membership is full, and its oldest entry
+		/// is still needed by an ongoing poll
+		RankHistoryInUse,
+		/// The rank the membership had when the voting power of the poll was
+		/// snapshotted is no longer known
+		UnknownRank,
+		/// The track has no spend limit set for the asset
+		SpendNotAllowed,
+		/// The amount exceeds the spend limit of the track
+		SpendLimitExceeded,
+		/// There's no approved spend with the given index
+		SpendNotFound,
+		/// The spend isn't valid yet, so it can't be paid out
+		EarlyPayout,
+		/// The spend has expired, so it can't be paid out
+		SpendExpired,
+		/// The spend hasn't expired yet
+		SpendNotExpired,
+		/// The asset wasn't issued by the community
+		NotACommunityAsset,
+		/// The asset isn't held by the pallet on behalf of the community, so
+		/// it can't be claimed
+		AssetNotClaimable,
+		/// The conviction is kept for votes casted before convictions existed
+		InvalidConviction,
+		/// The subset doesn't include any member
+		InvalidSubset,
+		/// The signature wasn't made by the invitation key over the community
+		/// and the claiming account
+		InvalidInvitationSignature,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke
@@ -395,6 +972,7 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		pub fn set_admin_origin(origin: OriginFor<T>, admin_origin: PalletsOriginOf<T>) -> DispatchResult {
 			let community_id = T::AdminOrigin::ensure_origin(origin.clone())?;
+			Self::ensure_active(&community_id)?;
 
 			ensure!(
 				CommunityIdFor::<T>::get(origin.clone().caller()) == Some(community_id),
@@ -403,6 +981,7 @@ pub mod pallet {
 
 			CommunityIdFor::<T>::remove(origin.caller());
 			CommunityIdFor::<T>::insert(admin_origin.clone(), community_id);
+			CommunityAdminOrigin::<T>::insert(community_id, admin_origin.clone());
 
 			Self::deposit_event(Event::AdminOriginSet {
 				id: community_id,
@@ -411,6 +990,116 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Sets the metadata (name, description, main URL and logo) used to
+		/// identify the community.
+		#[pallet::call_index(2)]
+		pub fn set_metadata(origin: OriginFor<T>, metadata: CommunityMetadataFor<T>) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_set_metadata(&community_id, metadata);
+			Ok(())
+		}
+
+		/// Removes the metadata of the community.
+		#[pallet::call_index(13)]
+		pub fn clear_metadata(origin: OriginFor<T>) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			ensure!(Metadata::<T>::contains_key(community_id), Error::<T>::NoMetadataSet);
+
+			Metadata::<T>::remove(community_id);
+
+			Self::deposit_event(Event::MetadataCleared { id: community_id });
+			Ok(())
+		}
+
+		/// Blocks a community, typically as a result of violating the norms of
+		/// the network. A blocked community can't be administered, add
+		/// members, vote or dispatch calls until it's unblocked.
+		#[pallet::call_index(14)]
+		pub fn block(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			reason: ConstSizedField<256>,
+		) -> DispatchResult {
+			T::BlockOrigin::ensure_origin(origin)?;
+			let info = Info::<T>::get(community_id).ok_or(Error::<T>::CommunityDoesNotExist)?;
+			ensure!(
+				info.state != CommunityState::Destroying,
+				Error::<T>::CommunityDestroying
+			);
+			ensure!(info.state == CommunityState::Active, Error::<T>::AlreadyBlocked);
+
+			Self::force_state(&community_id, CommunityState::Blocked);
+
+			Self::deposit_event(Event::CommunityBlocked {
+				id: community_id,
+				reason,
+			});
+			Ok(())
+		}
+
+		/// Lifts the block on a community, allowing it to operate normally
+		/// again.
+		#[pallet::call_index(15)]
+		pub fn unblock(origin: OriginFor<T>, community_id: T::CommunityId) -> DispatchResult {
+			T::BlockOrigin::ensure_origin(origin)?;
+			let info = Info::<T>::get(community_id).ok_or(Error::<T>::CommunityDoesNotExist)?;
+			ensure!(info.state == CommunityState::Blocked, Error::<T>::NotBlocked);
+
+			Self::force_state(&community_id, CommunityState::Active);
+
+			Self::deposit_event(Event::CommunityUnblocked { id: community_id });
+			Ok(())
+		}
+
+		/// Starts the destruction of a community. Either the community admin or
+		/// the block origin can start it. Once started, the community can no
+		/// longer operate, and its votes and records can be removed via
+		/// [`destroy_votes`][`Pallet::destroy_votes`] and
+		/// [`destroy_records`][`Pallet::destroy_records`] before calling
+		/// [`finish_destroy`][`Pallet::finish_destroy`].
+		#[pallet::call_index(16)]
+		pub fn start_destroy(origin: OriginFor<T>, community_id: T::CommunityId) -> DispatchResult {
+			if T::BlockOrigin::ensure_origin(origin.clone()).is_err() {
+				let id = T::AdminOrigin::ensure_origin(origin)?;
+				ensure!(id == community_id, DispatchError::BadOrigin);
+			}
+			Self::do_start_destroy(&community_id)
+		}
+
+		/// Removes up to [`RemoveItemsLimit`][`Config::RemoveItemsLimit`] votes
+		/// of a community being destroyed, releasing the funds locked by them.
+		/// Fails if any of the polls the community voted on is still ongoing.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::destroy_votes(T::RemoveItemsLimit::get()))]
+		pub fn destroy_votes(origin: OriginFor<T>, community_id: T::CommunityId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let removed = Self::do_destroy_votes(&community_id, T::RemoveItemsLimit::get())?;
+			Ok(Some(T::WeightInfo::destroy_votes(removed)).into())
+		}
+
+		/// Completes the destruction of a community once all its votes and
+		/// records have been destroyed, removing every remaining information
+		/// about it.
+		#[pallet::call_index(18)]
+		pub fn finish_destroy(origin: OriginFor<T>, community_id: T::CommunityId) -> DispatchResult {
+			T::BlockOrigin::ensure_origin(origin)?;
+			Self::do_finish_destroy(&community_id)
+		}
+
+		/// Removes up to [`RemoveItemsLimit`][`Config::RemoveItemsLimit`]
+		/// records of a community being destroyed, such as its pending
+		/// applications (releasing their deposits), invitations, subset
+		/// approvals, rank histories and spends.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::destroy_records(T::RemoveItemsLimit::get()))]
+		pub fn destroy_records(origin: OriginFor<T>, community_id: T::CommunityId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let removed = Self::do_destroy_records(&community_id, T::RemoveItemsLimit::get())?;
+			Ok(Some(T::WeightInfo::destroy_records(removed)).into())
+		}
+
 		// === Memberships management ===
 
 		/// Enroll an account as a community member that receives a membership
@@ -418,18 +1107,10 @@ pub mod pallet {
 		#[pallet::call_index(3)]
 		pub fn add_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
 			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
 			let who = T::Lookup::lookup(who)?;
 
-			let account = Self::community_account(&community_id);
-			// assume the community has memberships to give out to the new member
-			let (_, membership_id) = T::MemberMgmt::user_memberships(&account, None)
-				.next()
-				.ok_or(Error::<T>::CommunityAtCapacity)?;
-
-			T::MemberMgmt::assign(&community_id, &membership_id, &who)?;
-
-			Self::deposit_event(Event::MemberAdded { who, membership_id });
-			Ok(())
+			Self::do_add_member(&community_id, who)
 		}
 
 		/// Removes an account as a community member. While
@@ -439,74 +1120,86 @@ pub mod pallet {
 		/// to arbitrarily remove the community admin, as some privileged calls
 		/// would be impossible to execute thereafter.
 		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::remove_member(T::MaxDelegators::get()))]
 		pub fn remove_member(
 			origin: OriginFor<T>,
 			who: AccountIdLookupOf<T>,
 			membership_id: MembershipIdOf<T>,
 		) -> DispatchResult {
 			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
 			let who = T::Lookup::lookup(who)?;
 
 			ensure!(T::MemberMgmt::is_member_of(&community_id, &who), Error::<T>::NotAMember);
 
-			T::MemberMgmt::release(&community_id, &membership_id)?;
-
-			Self::deposit_event(Event::MemberRemoved { who, membership_id });
-			Ok(())
+			Self::do_remove_member(&community_id, who, membership_id)
 		}
 
 		/// Increases the rank of a member in the community
 		#[pallet::call_index(5)]
 		pub fn promote(origin: OriginFor<T>, membership_id: MembershipIdOf<T>) -> DispatchResult {
 			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
 
 			let rank = T::MemberMgmt::rank_of(&community_id, &membership_id)
 				.ok_or(Error::<T>::NotAMember)?
 				.promote_by(ONE);
-			T::MemberMgmt::set_rank(&community_id, &membership_id, rank)?;
-
-			Self::deposit_event(Event::MembershipRankUpdated { membership_id, rank });
-			Ok(())
+			Self::do_set_rank(&community_id, &membership_id, rank)
 		}
 
 		/// Decreases the rank of a member in the community
 		#[pallet::call_index(6)]
 		pub fn demote(origin: OriginFor<T>, membership_id: MembershipIdOf<T>) -> DispatchResult {
 			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
 
 			let rank = T::MemberMgmt::rank_of(&community_id, &membership_id).ok_or(Error::<T>::NotAMember)?;
-			T::MemberMgmt::set_rank(&community_id, &membership_id, rank.demote_by(ONE))?;
-
-			Self::deposit_event(Event::MembershipRankUpdated { membership_id, rank });
-			Ok(())
+			Self::do_set_rank(&community_id, &membership_id, rank.demote_by(ONE))
 		}
 
 		// === Governance ===
 
-		/// Decide the method used by the community to vote on proposals
+		/// Decide the method used by the community to vote on proposals. When
+		/// a track is given, the method only applies to the polls of that
+		/// track, otherwise it becomes the default method of the community.
 		#[pallet::call_index(7)]
 		pub fn set_decision_method(
 			origin: OriginFor<T>,
 			community_id: T::CommunityId,
+			maybe_track_id: Option<T::TrackId>,
 			decision_method: DecisionMethodFor<T>,
 		) -> DispatchResult {
-			T::AdminOrigin::ensure_origin(origin)?;
-			if let DecisionMethod::CommunityAsset(ref asset, min_vote) = decision_method {
-				// best effort attemt to create the asset if it doesn't exist
-				let _ = <T::Assets as fungibles::Create<T::AccountId>>::create(
-					asset.clone(),
-					T::PalletId::get().into_account_truncating(),
-					false,
-					min_vote,
-				);
+			let id = T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(id == community_id, DispatchError::BadOrigin);
+			Self::ensure_active(&community_id)?;
+			if let Some(track_id) = maybe_track_id {
+				ensure!(track_id.community_id() == community_id, Error::<T>::InvalidTrack);
 			}
-			CommunityDecisionMethod::<T>::set(community_id, decision_method);
-			Self::deposit_event(Event::DecisionMethodSet { id: community_id });
+			if let DecisionMethod::CommunityAsset(ref asset, min_vote)
+			| DecisionMethod::Quadratic(ref asset, min_vote)
+			| DecisionMethod::CustomCurve(ref asset, min_vote) = decision_method
+			{
+				// the asset is created as a community asset if it doesn't exist
+				if !<T::Assets as fungibles::Inspect<T::AccountId>>::asset_exists(asset.clone()) {
+					Self::do_create_asset(&community_id, asset.clone(), false, min_vote)?;
+				}
+			}
+			match maybe_track_id {
+				Some(track_id) => TrackDecisionMethod::<T>::insert(community_id, track_id, decision_method),
+				None => CommunityDecisionMethod::<T>::set(community_id, decision_method),
+			}
+			Self::deposit_event(Event::DecisionMethodSet {
+				id: community_id,
+				track_id: maybe_track_id,
+			});
 			Ok(())
 		}
 
-		/// Cast a vote on an on-going referendum
+		/// Cast a vote on an on-going referendum. When the community decides
+		/// by membership or rank, the vote is also casted on behalf of the
+		/// memberships that delegated their voting power and haven't voted.
 		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::vote(T::MaxDelegators::get()))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			membership_id: MembershipIdOf<T>,
@@ -514,13 +1207,21 @@ pub mod pallet {
 			vote: VoteOf<T>,
 		) -> DispatchResult {
 			ensure!(VoteWeight::from(&vote).gt(&0), Error::<T>::VoteBelowMinimum);
+			ensure!(
+				vote.conviction() != Some(Conviction::Unlocked1x),
+				Error::<T>::InvalidConviction
+			);
 			let who = ensure_signed(origin)?;
 			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
-			let decision_method = CommunityDecisionMethod::<T>::get(community_id);
+			Self::ensure_active(&community_id)?;
+			ensure!(
+				!T::ExpiredMemberships::contains(&(community_id, membership_id)),
+				Error::<T>::MembershipExpired
+			);
 			if CommunityVotes::<T>::contains_key(poll_index, membership_id) {
-				Self::try_remove_vote(&community_id, &decision_method, &membership_id, poll_index)?;
+				Self::try_remove_vote(&community_id, &membership_id, poll_index)?;
 			}
-			Self::try_vote(&community_id, &decision_method, &who, &membership_id, poll_index, &vote)?;
+			Self::try_vote(&community_id, &who, &membership_id, poll_index, &vote)?;
 			Self::deposit_event(Event::<T>::VoteCasted {
 				who: who.clone(),
 				poll_index,
@@ -529,8 +1230,10 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Remove any previous vote on a given referendum
+		/// Remove any previous vote on a given referendum, along with the
+		/// votes casted on behalf of its delegators
 		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::remove_vote(T::MaxDelegators::get()))]
 		pub fn remove_vote(
 			origin: OriginFor<T>,
 			membership_id: MembershipIdOf<T>,
@@ -538,8 +1241,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
-			let decision_method = CommunityDecisionMethod::<T>::get(community_id);
-			Self::try_remove_vote(&community_id, &decision_method, &membership_id, poll_index)?;
+			Self::try_remove_vote(&community_id, &membership_id, poll_index)?;
 			Self::deposit_event(Event::<T>::VoteRemoved {
 				who: who.clone(),
 				poll_index,
@@ -554,6 +1256,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			ensure!(T::Polls::as_ongoing(poll_index).is_none(), Error::<T>::AlreadyOngoing);
 			let vote = CommunityVoteLocks::<T>::get(&who, poll_index).ok_or(Error::<T>::NoLocksInPlace)?;
+			Self::ensure_lock_expired(poll_index, &vote)?;
 			Self::update_locks(&who, poll_index, &vote, LockUpdateType::Remove)
 		}
 
@@ -568,23 +1271,447 @@ pub mod pallet {
 		})]
 		pub fn dispatch_as_account(origin: OriginFor<T>, call: Box<RuntimeCallFor<T>>) -> DispatchResultWithPostInfo {
 			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
 			Self::do_dispatch_as_community_account(&community_id, *call)
 		}
 
-		// /// Dispatch a callable as the community account
-		// #[pallet::call_index(12)]
-		// #[pallet::weight({
-		// 	let di = call.get_dispatch_info();
-		// 	let weight = T::WeightInfo::dispatch_as_account()
-		// 		.saturating_add(T::DbWeight::get().reads_writes(1, 1))
-		// 		.saturating_add(di.weight);
-		// 	(weight, di.class)
-		// })]
-		// pub fn dispatch_as_origin(origin: OriginFor<T>, call: Box<RuntimeCallFor<T>>)
-		// -> DispatchResultWithPostInfo { 	let community_id =
-		// T::MemberMgmtOrigin::ensure_origin(origin)?; 	let origin =
-		// crate::Origin::<T>::new(community_id); 	let post =
-		// call.dispatch(origin.into()).map_err(|e| e.error)?; 	Ok(post)
-		// }
+		/// Dispatch a callable as the community origin, optionally as a
+		/// subset of its members. Spends and spend limits can't be dispatched
+		/// this way, as they must be approved by the polls of the community.
+		#[pallet::call_index(12)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			let weight = T::WeightInfo::dispatch_as_origin()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.weight);
+			(weight, di.class)
+		})]
+		pub fn dispatch_as_origin(
+			origin: OriginFor<T>,
+			maybe_subset: Option<origin::Subset<T>>,
+			call: Box<RuntimeCallFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_dispatch_as_community_origin(&community_id, maybe_subset, *call)
+		}
+
+		/// Delegate the voting power of a membership to another membership of
+		/// the same community
+		#[pallet::call_index(19)]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			membership_id: MembershipIdOf<T>,
+			delegate: MembershipIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_delegate(&community_id, &membership_id, &delegate)?;
+			Self::deposit_event(Event::Delegated {
+				membership_id,
+				delegate,
+			});
+			Ok(())
+		}
+
+		/// Take back the voting power delegated by a membership, removing the
+		/// votes its delegate casted on its behalf on ongoing polls
+		#[pallet::call_index(20)]
+		pub fn undelegate(origin: OriginFor<T>, membership_id: MembershipIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
+			Self::do_undelegate(&community_id, &membership_id)?;
+			Self::deposit_event(Event::Undelegated { membership_id });
+			Ok(())
+		}
+
+		/// Approve dispatching a call as a subset of the community. The call
+		/// is dispatched once enough memberships belonging to the subset
+		/// approve it:
+		/// - `Member`: the given membership.
+		/// - `Members { count }`: `count` memberships.
+		/// - `Fraction`: the given fraction of the memberships.
+		/// - `AtLeastRank`: a majority of the memberships holding at least the
+		///   given rank.
+		///
+		/// Expired memberships can't approve calls, nor are counted for the
+		/// approvals a call needs. Approvals are discarded if the call isn't
+		/// dispatched within [`SubsetApprovalPeriod`][Config::SubsetApprovalPeriod].
+		#[pallet::call_index(21)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			let weight = T::WeightInfo::dispatch_as_subset()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.weight);
+			(weight, di.class)
+		})]
+		pub fn dispatch_as_subset(
+			origin: OriginFor<T>,
+			membership_id: MembershipIdOf<T>,
+			subset: origin::Subset<T>,
+			call: Box<RuntimeCallFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_approve_as_subset(&community_id, &membership_id, subset, *call)
+		}
+
+		/// Leave the community, releasing the given membership. Its votes
+		/// on ongoing polls are removed, along with its delegations.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::leave(T::MaxDelegators::get()))]
+		pub fn leave(origin: OriginFor<T>, membership_id: MembershipIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
+			Self::do_remove_member(&community_id, who, membership_id)
+		}
+
+		/// Remove an expired membership from its community. Anyone can
+		/// prune it, the same way the holder could leave the community.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::prune_expired_member(T::MaxDelegators::get()))]
+		pub fn prune_expired_member(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			membership_id: MembershipIdOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let community_id = T::MemberMgmt::check_membership(&who, &membership_id).ok_or(Error::<T>::NotAMember)?;
+			ensure!(
+				T::ExpiredMemberships::contains(&(community_id, membership_id)),
+				Error::<T>::MembershipNotExpired
+			);
+			Self::do_remove_member(&community_id, who, membership_id)
+		}
+
+		// === Membership applications ===
+
+		/// Apply for a membership of a community, along with a message for
+		/// it. A deposit is held from the applicant until the community
+		/// approves or rejects the application, or it expires.
+		#[pallet::call_index(24)]
+		pub fn apply_for_membership(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			message: ConstSizedField<256>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_apply_for_membership(&community_id, who, message)
+		}
+
+		/// Approve the pending application of an account, enrolling it as a
+		/// member of the community.
+		#[pallet::call_index(25)]
+		pub fn approve_application(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_approve_application(&community_id, who)
+		}
+
+		/// Reject the pending application of an account. Its deposit is
+		/// transferred to the community account.
+		#[pallet::call_index(26)]
+		pub fn reject_application(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_reject_application(&community_id, who)
+		}
+
+		/// Remove an application that expired before the community decided on
+		/// it, returning its deposit to the applicant. Anyone can call it.
+		#[pallet::call_index(27)]
+		pub fn remove_expired_application(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_remove_expired_application(&community_id, who)
+		}
+
+		// === Membership invitations ===
+
+		/// Set aside a membership from the pool of the community for whoever
+		/// holds the private part of `invitation_key`, without knowing their
+		/// account in advance.
+		#[pallet::call_index(28)]
+		pub fn invite_member(origin: OriginFor<T>, invitation_key: AccountIdOf<T>) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_invite_member(&community_id, invitation_key)
+		}
+
+		/// Claim the membership set aside for an invitation. The `signature`
+		/// must be made by `invitation_key` over the SCALE-encoded community
+		/// id and account of the caller, so it can't be reused by whoever sees
+		/// it submitted.
+		#[pallet::call_index(29)]
+		pub fn claim_invitation(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			invitation_key: AccountIdOf<T>,
+			signature: T::InvitationSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_claim_invitation(&community_id, who, invitation_key, signature)
+		}
+
+		/// Revoke an invitation that hasn't been claimed yet, returning its
+		/// membership to the pool of the community.
+		#[pallet::call_index(30)]
+		pub fn revoke_invitation(origin: OriginFor<T>, invitation_key: AccountIdOf<T>) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_revoke_invitation(&community_id, invitation_key)
+		}
+
+		// === Batch memberships management ===
+
+		/// Enroll several accounts as community members, each one receiving
+		/// a membership from the available pool of memberships of the
+		/// community.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::add_members(who.len() as u32))]
+		pub fn add_members(
+			origin: OriginFor<T>,
+			who: BoundedVec<AccountIdLookupOf<T>, T::MaxMembersPerBatch>,
+		) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			let who = who.into_iter().map(T::Lookup::lookup).collect::<Result<Vec<_>, _>>()?;
+
+			Self::do_add_members(&community_id, who)
+		}
+
+		/// Remove several accounts as community members, the same way
+		/// [`remove_member`][`Pallet::remove_member`] does.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::remove_members(members.len() as u32).saturating_add(
+			T::WeightInfo::remove_member(T::MaxDelegators::get())
+				.saturating_sub(T::WeightInfo::remove_member(0))
+				.saturating_mul(members.len() as u64)
+		))]
+		pub fn remove_members(
+			origin: OriginFor<T>,
+			members: BoundedVec<(AccountIdLookupOf<T>, MembershipIdOf<T>), T::MaxMembersPerBatch>,
+		) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+
+			for (who, membership_id) in members {
+				let who = T::Lookup::lookup(who)?;
+				ensure!(T::MemberMgmt::is_member_of(&community_id, &who), Error::<T>::NotAMember);
+				Self::do_remove_member(&community_id, who, membership_id)?;
+			}
+			Ok(())
+		}
+
+		/// Set the rank of several members of the community at once.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_ranks(ranks.len() as u32))]
+		pub fn set_ranks(
+			origin: OriginFor<T>,
+			ranks: BoundedVec<(MembershipIdOf<T>, membership::GenericRank), T::MaxMembersPerBatch>,
+		) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+
+			for (membership_id, rank) in ranks {
+				Self::do_set_rank(&community_id, &membership_id, rank)?;
+			}
+			Ok(())
+		}
+
+		/// Set the rank of a member in the community, within the bounds set
+		/// by the community, if any.
+		#[pallet::call_index(34)]
+		pub fn set_member_rank(
+			origin: OriginFor<T>,
+			membership_id: MembershipIdOf<T>,
+			rank: membership::GenericRank,
+		) -> DispatchResult {
+			let community_id = T::MemberMgmtOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_set_rank(&community_id, &membership_id, rank)
+		}
+
+		/// Set the lowest and highest ranks the members of the community can
+		/// be set to. Ranks members already have aren't changed.
+		#[pallet::call_index(35)]
+		pub fn set_rank_bounds(origin: OriginFor<T>, bounds: RankBounds) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			if let (Some(min), Some(max)) = (bounds.min, bounds.max) {
+				ensure!(min <= max, Error::<T>::InvalidRankBounds);
+			}
+
+			CommunityRankBounds::<T>::insert(community_id, bounds.clone());
+
+			Self::deposit_event(Event::RankBoundsSet {
+				id: community_id,
+				bounds,
+			});
+			Ok(())
+		}
+
+		// === Treasury ===
+
+		/// Approve a spend from the treasury of the community, paying
+		/// `amount` of an asset to `beneficiary`. It can be paid out from
+		/// `valid_from` (or right away, if not given) until the payout period
+		/// ends. Spends can only be approved by the polls of a track of the
+		/// community, and can't exceed the limit set for the track and asset.
+		#[pallet::call_index(36)]
+		pub fn spend(
+			origin: OriginFor<T>,
+			asset_kind: SpendAssetOf<T>,
+			amount: NativeBalanceOf<T>,
+			beneficiary: AccountIdLookupOf<T>,
+			valid_from: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let track_id = T::SpendOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&track_id.community_id())?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			Self::do_spend(&track_id, asset_kind, amount, beneficiary, valid_from)
+		}
+
+		/// Pay out an approved spend from the treasury of the community to its
+		/// beneficiary, once it's valid and before it expires. Anyone can
+		/// call it.
+		#[pallet::call_index(37)]
+		pub fn payout(origin: OriginFor<T>, community_id: T::CommunityId, index: SpendIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_payout(&community_id, index)
+		}
+
+		/// Void an approved spend of the community before it's paid out.
+		#[pallet::call_index(38)]
+		pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_void_spend(&community_id, index)
+		}
+
+		/// Remove an approved spend that expired before being paid out. Anyone
+		/// can call it.
+		#[pallet::call_index(39)]
+		pub fn remove_expired_spend(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			index: SpendIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_remove_expired_spend(&community_id, index)
+		}
+
+		/// Set the highest amount of an asset a single spend approved by the
+		/// polls of a track of the community can pay out. Removing the limit
+		/// prevents the track from approving spends of the asset. Limits can
+		/// only be set by the polls of the default track of the community, so
+		/// a track can't raise its own limit.
+		#[pallet::call_index(40)]
+		pub fn set_spend_limit(
+			origin: OriginFor<T>,
+			track_id: T::TrackId,
+			asset_kind: SpendAssetOf<T>,
+			limit: Option<NativeBalanceOf<T>>,
+		) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin.clone())?;
+			Self::ensure_active(&community_id)?;
+			let origin_track =
+				T::TrackForOrigin::try_convert(origin.into_caller()).map_err(|_| Error::<T>::InvalidTrack)?;
+			ensure!(
+				origin_track == T::TrackId::for_community(community_id, 0),
+				DispatchError::BadOrigin
+			);
+			ensure!(track_id.community_id() == community_id, Error::<T>::InvalidTrack);
+
+			TrackSpendLimits::<T>::set(community_id, (track_id, asset_kind.clone()), limit);
+
+			Self::deposit_event(Event::SpendLimitSet {
+				track_id,
+				asset_kind,
+				limit,
+			});
+			Ok(())
+		}
+
+		// === Community assets ===
+
+		/// Create an asset issued by the community, owned by its community
+		/// account, along with its metadata.
+		#[pallet::call_index(41)]
+		pub fn create_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			min_balance: NativeBalanceOf<T>,
+			is_sufficient: bool,
+			metadata: CommunityAssetMetadata,
+		) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+
+			Self::do_create_asset(&community_id, asset_id.clone(), is_sufficient, min_balance)?;
+			Self::do_set_asset_metadata(&community_id, asset_id, metadata)
+		}
+
+		/// Set the metadata of an asset issued by the community.
+		#[pallet::call_index(42)]
+		pub fn set_asset_metadata(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			metadata: CommunityAssetMetadata,
+		) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_set_asset_metadata(&community_id, asset_id, metadata)
+		}
+
+		/// Mint an amount of an asset issued by the community into the
+		/// account of `beneficiary`.
+		#[pallet::call_index(43)]
+		pub fn mint_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			beneficiary: AccountIdLookupOf<T>,
+			amount: NativeBalanceOf<T>,
+		) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			Self::do_mint_asset(&community_id, asset_id, beneficiary, amount)
+		}
+
+		/// Burn an amount of an asset issued by the community out of the
+		/// community account.
+		#[pallet::call_index(44)]
+		pub fn burn_asset(origin: OriginFor<T>, asset_id: AssetIdOf<T>, amount: NativeBalanceOf<T>) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_burn_asset(&community_id, asset_id, amount)
+		}
+
+		/// Transfer the ownership of an asset the pallet created on behalf of
+		/// the community, when setting a decision method that votes with it,
+		/// to the community account. Only the community the asset was created
+		/// for can claim it.
+		#[pallet::call_index(45)]
+		pub fn claim_asset(origin: OriginFor<T>, asset_id: AssetIdOf<T>) -> DispatchResult {
+			let community_id = T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active(&community_id)?;
+			Self::do_claim_asset(&community_id, asset_id)
+		}
 	}
 }
@@ -0,0 +1,265 @@
+//! Storage migrations of the communities pallet.
+
+use super::*;
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+	use sp_runtime::Saturating;
+
+	/// A vote as it was stored before votes were casted with a
+	/// [`Conviction`].
+	#[derive(Decode, Encode)]
+	pub enum OldVote<AssetId, AssetBalance, NativeBalance> {
+		AssetBalance(bool, AssetId, AssetBalance),
+		NativeBalance(bool, NativeBalance),
+		Standard(bool),
+	}
+
+	pub type OldVoteOf<T> = OldVote<AssetIdOf<T>, AssetBalanceOf<T>, NativeBalanceOf<T>>;
+
+	impl<A, B, N> From<OldVote<A, B, N>> for Vote<A, B, N> {
+		/// Token votes used to weigh as much as the funds they locked, and
+		/// their funds were unlocked as soon as the poll ended. They're given
+		/// [`Conviction::Unlocked1x`], which keeps both the weight and the
+		/// lock they were casted with.
+		fn from(vote: OldVote<A, B, N>) -> Self {
+			match vote {
+				OldVote::AssetBalance(say, asset_id, amount) => {
+					Vote::AssetBalance(say, asset_id, amount, Conviction::Unlocked1x)
+				}
+				OldVote::NativeBalance(say, amount) => Vote::NativeBalance(say, amount, Conviction::Unlocked1x),
+				OldVote::Standard(say) => Vote::Standard(say),
+			}
+		}
+	}
+
+	/// Adds a conviction to the votes casted on community polls, as well as
+	/// to the votes keeping funds locked. Existing votes keep the weight they
+	/// were tallied with, and their funds unlock when they used to. Votes are
+	/// given a multiplier of one, which is set to the one they're counted
+	/// with when their tally is translated to [`v2`].
+	pub type MigrateVotesToV1<T> =
+		VersionedMigration<0, 1, UncheckedMigrateVotesToV1<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+	pub struct UncheckedMigrateVotesToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateVotesToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			CommunityVotes::<T>::translate::<(OldVoteOf<T>, AccountIdOf<T>), _>(|_, _, (vote, who)| {
+				translated.saturating_inc();
+				Some((vote.into(), who, 1))
+			});
+			CommunityVoteLocks::<T>::translate::<OldVoteOf<T>, _>(|_, _, vote| {
+				translated.saturating_inc();
+				Some(vote.into())
+			});
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::nonfungibles_v2::InspectEnumerable};
+
+	/// The tally of a community poll as it was stored while [`VoteWeight`]
+	/// was a `u32`, before abstentions were counted and the voting power of
+	/// polls was snapshotted.
+	#[derive(Decode)]
+	pub struct OldTally<T> {
+		pub _phantom: PhantomData<T>,
+		pub ayes: u32,
+		pub nays: u32,
+		pub bare_ayes: u32,
+	}
+
+	/// Translates the tally of an in-flight poll. Polls are stored by the
+	/// [`Polls`][Config::Polls] implementation of the runtime, so the runtime
+	/// migrates its storage, calling this for every ongoing poll.
+	///
+	/// The weights of the old tally could have saturated, and its voting power
+	/// was never snapshotted, so the voting power is snapshotted as of now and
+	/// the tally is counted again from the votes casted on the poll, storing
+	/// the multiplier each vote is counted with. Returns the new tally along
+	/// with the number of votes and memberships counted.
+	pub fn translate_tally<T: Config>(
+		poll_index: PollIndexOf<T>,
+		track_id: &T::TrackId,
+		_old: OldTally<T>,
+	) -> (Tally<T>, u64) {
+		let community_id = track_id.community_id();
+		let decision_method = Pallet::<T>::decision_method(track_id);
+		let mut counted = 0;
+
+		// Members aren't counted until the storage is migrated to v5, so the
+		// memberships of the community are counted here
+		let max_support = match decision_method {
+			DecisionMethod::Membership | DecisionMethod::Rank => {
+				let mut max_support: VoteWeight = 0;
+				for membership_id in T::Memberships::items(&community_id) {
+					max_support += match decision_method {
+						DecisionMethod::Rank => {
+							VoteWeight::from(u32::from(Pallet::<T>::member_rank(&community_id, &membership_id)))
+						}
+						_ => 1,
+					};
+					counted += 1;
+				}
+				max_support
+			}
+			_ => Tally::<T>::max_support(*track_id),
+		};
+
+		let snapshot_at = frame_system::Pallet::<T>::block_number();
+		let mut tally = Tally::<T> {
+			max_support,
+			snapshot_at: Some(snapshot_at),
+			..Default::default()
+		};
+
+		for (membership_id, (vote, who, _)) in CommunityVotes::<T>::iter_prefix(poll_index).collect::<Vec<_>>() {
+			// Ranks are snapshotted as of now, so they're always known
+			let vote_multiplier =
+				Pallet::<T>::vote_multiplier(&community_id, &decision_method, &membership_id, snapshot_at)
+					.unwrap_or_default();
+			tally.add_vote(&decision_method, &vote, vote_multiplier);
+			CommunityVotes::<T>::insert(poll_index, membership_id, (vote, who, vote_multiplier));
+			counted += 1;
+		}
+
+		(tally, counted)
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use frame_support::{migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+	use sp_runtime::Saturating;
+
+	/// Indexes the admin origin of every community by its id, so destroying
+	/// a community doesn't need to go through the origins of every other
+	/// community.
+	pub type MigrateAdminOriginsToV3<T> =
+		VersionedMigration<2, 3, UncheckedMigrateAdminOriginsToV3<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+	pub struct UncheckedMigrateAdminOriginsToV3<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateAdminOriginsToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut indexed = 0u64;
+
+			for (origin, community_id) in CommunityIdFor::<T>::iter() {
+				indexed.saturating_inc();
+				CommunityAdminOrigin::<T>::insert(community_id, origin);
+			}
+
+			T::DbWeight::get().reads_writes(indexed, indexed)
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::{fungibles::roles::Inspect, UncheckedOnRuntimeUpgrade},
+	};
+	use sp_runtime::{traits::AccountIdConversion, Saturating};
+	use sp_std::vec::Vec;
+
+	/// Records the community the assets held by the pallet were created for.
+	/// Those assets were created when a community first set a decision method
+	/// voting with them, so the creator is only recorded when a single
+	/// community decides with the asset.
+	pub type MigrateAssetCreatorsToV4<T> = VersionedMigration<
+		3,
+		4,
+		UncheckedMigrateAssetCreatorsToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	pub struct UncheckedMigrateAssetCreatorsToV4<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateAssetCreatorsToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+
+			let mut communities_of = Vec::<(AssetIdOf<T>, Vec<CommunityIdOf<T>>)>::new();
+			let community_methods = CommunityDecisionMethod::<T>::iter();
+			let track_methods = TrackDecisionMethod::<T>::iter()
+				.map(|(community_id, _, decision_method)| (community_id, decision_method));
+			for (community_id, decision_method) in community_methods.chain(track_methods) {
+				reads.saturating_inc();
+				let Some(asset_id) = decision_method.asset() else {
+					continue;
+				};
+				match communities_of.iter_mut().find(|(asset, _)| asset == asset_id) {
+					Some((_, communities)) if !communities.contains(&community_id) => communities.push(community_id),
+					Some(_) => (),
+					None => communities_of.push((asset_id.clone(), Vec::from([community_id]))),
+				}
+			}
+
+			let pallet_account: AccountIdOf<T> = T::PalletId::get().into_account_truncating();
+			for (asset_id, communities) in communities_of {
+				reads.saturating_inc();
+				let [community_id] = communities[..] else {
+					continue;
+				};
+				if T::Assets::owner(asset_id.clone()) == Some(pallet_account.clone()) {
+					writes.saturating_inc();
+					AssetCreators::<T>::insert(asset_id, community_id);
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
+
+pub mod v5 {
+	use super::*;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::{nonfungibles_v2::InspectEnumerable, UncheckedOnRuntimeUpgrade},
+	};
+	use sp_runtime::Saturating;
+	use sp_std::vec::Vec;
+
+	/// Counts the members of every community, along with the ranks they
+	/// hold, so the maximum support of a poll is known without going through
+	/// the memberships of the community.
+	pub type MigrateMembersCountToV5<T> =
+		VersionedMigration<4, 5, UncheckedMigrateMembersCountToV5<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+	pub struct UncheckedMigrateMembersCountToV5<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateMembersCountToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+
+			for community_id in Info::<T>::iter_keys().collect::<Vec<_>>() {
+				reads.saturating_inc();
+				for membership_id in T::Memberships::items(&community_id) {
+					reads.saturating_accrue(2);
+					writes.saturating_accrue(2);
+					Pallet::<T>::count_member_in(
+						&community_id,
+						Pallet::<T>::member_rank(&community_id, &membership_id),
+					);
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
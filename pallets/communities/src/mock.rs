@@ -5,7 +5,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration, tokens::nonfungible_v2::ItemOf, AsEnsureOriginWithArg, ConstU32, ConstU64,
-		EitherOf, EnsureOriginWithArg, EqualPrivilegeOnly, Footprint, VariantCountOf,
+		Contains, EitherOf, EnsureOriginWithArg, EqualPrivilegeOnly, Footprint, TryMapSuccess, VariantCountOf,
 	},
 	weights::{
 		constants::{WEIGHT_REF_TIME_PER_NANOS, WEIGHT_REF_TIME_PER_SECOND},
@@ -17,15 +17,15 @@ use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
 use pallet_referenda::{TrackIdOf, TrackInfoOf, TracksInfo};
 use sp_io::TestExternalities;
 use sp_runtime::{
-	traits::{Convert, IdentifyAccount, IdentityLookup, Verify},
+	traits::{AccountIdConversion, Convert, IdentifyAccount, IdentityLookup, TryConvert, Verify},
 	BuildStorage, MultiSignature, Perbill,
 };
 pub use virto_common::{CommunityId, MembershipId};
 
 use crate::{
 	self as pallet_communities,
-	origin::{EnsureCommunity, EnsureSignedPays},
-	types::{Tally, VoteWeight},
+	origin::{EnsureCommunity, EnsureCommunityTrack, EnsureSignedPays},
+	types::{CommunityTrackId, Tally, VoteWeight},
 	Config, DecisionMethod,
 };
 
@@ -232,7 +232,14 @@ impl EnsureOriginWithArg<RuntimeOrigin, TrackIdOf<Test, ()>> for EnsureOriginToT
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin(id: &TrackIdOf<Test, ()>) -> Result<RuntimeOrigin, ()> {
-		Ok(pallet_communities::Origin::<Test>::new(id.clone()).into())
+		Ok(pallet_communities::Origin::<Test>::new(id.community_id()).into())
+	}
+}
+
+pub struct TrackForOrigin;
+impl TryConvert<OriginCaller, TrackIdOf<Test, ()>> for TrackForOrigin {
+	fn try_convert(origin: OriginCaller) -> Result<TrackIdOf<Test, ()>, OriginCaller> {
+		Tracks::track_for(&origin).map_err(|_| origin)
 	}
 }
 
@@ -254,7 +261,7 @@ parameter_types! {
 }
 impl pallet_referenda_tracks::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type TrackId = CommunityId;
+	type TrackId = u32;
 	type MaxTracks = MaxTracks;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureOriginToTrack;
@@ -294,6 +301,13 @@ parameter_types! {
 	pub const MembershipsManagerCollectionId: CommunityId = 0;
 	pub const MembershipNftAttr: &'static [u8; 10] = b"membership";
 	pub const TestCommunity: CommunityId = COMMUNITY;
+	pub static ExpiredMemberships: Vec<(CommunityId, MembershipId)> = vec![];
+}
+
+impl Contains<(CommunityId, MembershipId)> for ExpiredMemberships {
+	fn contains(membership: &(CommunityId, MembershipId)) -> bool {
+		Self::get().contains(membership)
+	}
 }
 
 type MembershipCollection = ItemOf<Nfts, MembershipsManagerCollectionId, AccountId>;
@@ -348,6 +362,22 @@ impl BenchmarkHelper<Test> for CommunityBenchmarkHelper {
 		Ok(())
 	}
 
+	fn expire_membership(
+		community_id: CommunityIdOf<Test>,
+		membership_id: MembershipIdOf<Test>,
+	) -> Result<(), BenchmarkError> {
+		ExpiredMemberships::mutate(|expired| expired.push((community_id, membership_id)));
+		Ok(())
+	}
+
+	fn invitation_signer() -> (AccountPublic, AccountId) {
+		<NftsBenchmarksHelper as pallet_nfts::BenchmarkHelper<_, _, _, _, _>>::signer()
+	}
+
+	fn sign_invitation(signer: &AccountPublic, message: &[u8]) -> MultiSignature {
+		<NftsBenchmarksHelper as pallet_nfts::BenchmarkHelper<_, _, _, _, _>>::sign(signer, message)
+	}
+
 	fn prepare_track(track_origin: PalletsOriginOf<Test>) -> Result<(), BenchmarkError> {
 		let id = Self::community_id();
 		let info = TrackInfo {
@@ -370,7 +400,7 @@ impl BenchmarkHelper<Test> for CommunityBenchmarkHelper {
 			},
 		};
 
-		Tracks::insert(RuntimeOrigin::root(), id, info, track_origin.clone())?;
+		Tracks::insert(RuntimeOrigin::root(), id.into(), info, track_origin.clone())?;
 
 		Ok(())
 	}
@@ -412,6 +442,12 @@ impl BenchmarkHelper<Test> for CommunityBenchmarkHelper {
 parameter_types! {
 	pub const NoPay: Option<(Balance, AccountId, AccountId)> = None;
 }
+sp_runtime::morph_types! {
+	pub type AccountToCommunityId: TryMorph = |a: AccountId| -> Result<CommunityId, ()> {
+		PalletId::try_from_sub_account(&a).map(|(_, id)| id).ok_or(())
+	};
+}
+type EnsureCommunityAccount = TryMapSuccess<EnsureSigned<AccountId>, AccountToCommunityId>;
 type RootCreatesCommunitiesForFree = EnsureRootWithSuccess<AccountId, NoPay>;
 type AnyoneElsePays = EnsureSignedPays<Test, ConstU64<10>, RootAccount>;
 
@@ -419,8 +455,24 @@ pub type MembershipsManager = NonFungiblesMemberships<Nfts>;
 
 impl Config for Test {
 	type PalletId = CommunitiesPalletId;
+	type RemoveItemsLimit = ConstU32<10>;
+	type MaxDelegators = ConstU32<5>;
+	type VoteLockingPeriod = ConstU64<2>;
+	type VoteCurve = ();
+	type ExpiredMemberships = ExpiredMemberships;
+	type Memberships = Nfts;
+	type ApplicationDeposit = ConstU64<5>;
+	type ApplicationPeriod = ConstU64<10>;
+	type MaxMembersPerBatch = ConstU32<10>;
+	type InvitationSignature = MultiSignature;
+	type InvitationPublic = AccountPublic;
+	type TrackForOrigin = TrackForOrigin;
+	type SpendOrigin = EnsureCommunityTrack<Self>;
+	type PayoutPeriod = ConstU64<10>;
+	type SubsetApprovalPeriod = ConstU64<10>;
 	type CommunityId = CommunityId;
 	type MembershipId = MembershipId;
+	type TrackId = TrackIdOf<Test, ()>;
 
 	type Assets = Assets;
 	type AssetsFreezer = AssetsFreezer;
@@ -430,13 +482,15 @@ impl Config for Test {
 	type Polls = Referenda;
 
 	type CreateOrigin = EitherOf<RootCreatesCommunitiesForFree, AnyoneElsePays>;
-	type AdminOrigin = EnsureCommunity<Self>;
-	type MemberMgmtOrigin = EnsureCommunity<Self>;
+	type AdminOrigin = EitherOf<EnsureCommunity<Self>, EnsureCommunityAccount>;
+	type MemberMgmtOrigin = EitherOf<EnsureCommunity<Self>, EnsureCommunityAccount>;
+	type BlockOrigin = EnsureRoot<AccountId>;
 
 	type RuntimeCall = RuntimeCall;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = WeightInfo;
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -518,7 +572,7 @@ impl TestEnvBuilder {
 				.collect::<Vec<_>>(),
 		);
 		if let Some(track) = maybe_track {
-			self.tracks.push((community_id, track));
+			self.tracks.push((community_id.into(), track));
 		}
 
 		self
@@ -559,8 +613,13 @@ impl TestEnvBuilder {
 				Communities::create(RuntimeOrigin::root(), community_origin.caller.clone(), *community_id)
 					.expect("can add community");
 
-				Communities::set_decision_method(community_origin.clone(), *community_id, decision_method.clone())
-					.expect("can set decision info");
+				Communities::set_decision_method(
+					community_origin.clone(),
+					*community_id,
+					None,
+					decision_method.clone(),
+				)
+				.expect("can set decision info");
 
 				let mut members = self.members.iter().filter(|(cid, _)| cid == community_id);
 				let memberships = self.memberships.iter().filter(|(cid, _)| cid == community_id);
@@ -582,10 +641,10 @@ impl TestEnvBuilder {
 					}
 				}
 
-				for (_, track_info) in self.tracks.iter().filter(|(cid, _)| cid == community_id) {
+				for (track_id, track_info) in self.tracks.iter().filter(|(t, _)| &t.community_id() == community_id) {
 					Tracks::insert(
 						RuntimeOrigin::root(),
-						*community_id,
+						*track_id,
 						track_info.clone(),
 						community_origin.caller.clone(),
 					)
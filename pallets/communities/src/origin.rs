@@ -1,5 +1,5 @@
 use crate::{
-	types::{CommunityIdOf, CommunityState::Active, MembershipIdOf, RuntimeOriginFor},
+	types::{CommunityIdOf, CommunityState::Active, CommunityTrackId, MembershipIdOf, RuntimeOriginFor},
 	AccountIdOf, CommunityIdFor, Config, Info, Pallet,
 };
 use core::marker::PhantomData;
@@ -9,9 +9,7 @@ use frame_support::{
 	traits::{EnsureOriginWithArg, MapSuccess, OriginTrait},
 };
 use frame_system::EnsureSigned;
-#[cfg(feature = "xcm")]
-use sp_runtime::traits::TryConvert;
-use sp_runtime::{morph_types, Permill};
+use sp_runtime::{morph_types, traits::TryConvert, Permill};
 
 pub struct EnsureCommunity<T>(PhantomData<T>);
 
@@ -28,7 +26,12 @@ where
 			return Err(o);
 		}
 		let id = match o.clone().into() {
-			Ok(RawOrigin { community_id, .. }) => community_id,
+			Ok(RawOrigin {
+				community_id,
+				subset: None,
+			}) => community_id,
+			// A subset doesn't speak for the whole community
+			Ok(_) => return Err(o),
 			Err(_) => {
 				let origin = o.clone().into_caller();
 				CommunityIdFor::<T>::get(origin).ok_or_else(|| o.clone())?
@@ -46,6 +49,148 @@ where
 	}
 }
 
+/// Ensures the origin is the voice of an active community or of one of its
+/// subsets, resolving the track of the community the origin belongs to.
+pub struct EnsureCommunityTrack<T>(PhantomData<T>);
+
+impl<T> EnsureOrigin<RuntimeOriginFor<T>> for EnsureCommunityTrack<T>
+where
+	RuntimeOriginFor<T>: Into<Result<RawOrigin<T>, RuntimeOriginFor<T>>> + From<RawOrigin<T>>,
+	T: Config,
+{
+	type Success = T::TrackId;
+
+	fn try_origin(o: RuntimeOriginFor<T>) -> Result<Self::Success, RuntimeOriginFor<T>> {
+		let Ok(RawOrigin { community_id, .. }) = o.clone().into() else {
+			return Err(o);
+		};
+		if !Info::<T>::get(community_id).is_some_and(|c| c.state.eq(&Active)) {
+			return Err(o);
+		}
+		T::TrackForOrigin::try_convert(o.clone().into_caller())
+			.ok()
+			.filter(|track_id| track_id.community_id() == community_id)
+			.ok_or(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOriginFor<T>, ()> {
+		use crate::BenchmarkHelper;
+		Ok(RawOrigin::new(T::BenchmarkHelper::community_id()).into())
+	}
+}
+
+/// Ensures the origin is a subset of an active community that satisfies the
+/// condition `f`.
+fn ensure_subset<T>(
+	o: RuntimeOriginFor<T>,
+	f: impl FnOnce(&CommunityIdOf<T>, &Subset<T>) -> bool,
+) -> Result<CommunityIdOf<T>, RuntimeOriginFor<T>>
+where
+	RuntimeOriginFor<T>: Into<Result<RawOrigin<T>, RuntimeOriginFor<T>>>,
+	T: Config,
+{
+	let Ok(RawOrigin {
+		community_id,
+		subset: Some(subset),
+	}) = o.clone().into()
+	else {
+		return Err(o);
+	};
+	Info::<T>::get(community_id)
+		.and_then(|c| (c.state.eq(&Active) && f(&community_id, &subset)).then_some(community_id))
+		.ok_or(o)
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+fn successful_subset_origin<T: Config>(subset: Subset<T>) -> RuntimeOriginFor<T>
+where
+	RuntimeOriginFor<T>: From<RawOrigin<T>>,
+{
+	use crate::BenchmarkHelper;
+	let mut origin = RawOrigin::new(T::BenchmarkHelper::community_id());
+	origin.with_subset(subset);
+	origin.into()
+}
+
+/// Ensures the origin is a subset of at least `N` members of a community.
+pub struct EnsureCommunityMembers<T, N>(PhantomData<(T, N)>);
+
+impl<T, N> EnsureOrigin<RuntimeOriginFor<T>> for EnsureCommunityMembers<T, N>
+where
+	RuntimeOriginFor<T>: Into<Result<RawOrigin<T>, RuntimeOriginFor<T>>> + From<RawOrigin<T>>,
+	T: Config,
+	N: Get<u32>,
+{
+	type Success = CommunityIdOf<T>;
+
+	fn try_origin(o: RuntimeOriginFor<T>) -> Result<Self::Success, RuntimeOriginFor<T>> {
+		ensure_subset(
+			o,
+			|_, subset| matches!(subset, Subset::Members { count } if *count >= N::get()),
+		)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOriginFor<T>, ()> {
+		Ok(successful_subset_origin::<T>(Subset::Members { count: N::get() }))
+	}
+}
+
+/// Ensures the origin is a subset of at least the fraction `F` of the members
+/// of a community.
+pub struct EnsureCommunityFraction<T, F>(PhantomData<(T, F)>);
+
+impl<T, F> EnsureOrigin<RuntimeOriginFor<T>> for EnsureCommunityFraction<T, F>
+where
+	RuntimeOriginFor<T>: Into<Result<RawOrigin<T>, RuntimeOriginFor<T>>> + From<RawOrigin<T>>,
+	T: Config,
+	F: Get<Permill>,
+{
+	type Success = CommunityIdOf<T>;
+
+	fn try_origin(o: RuntimeOriginFor<T>) -> Result<Self::Success, RuntimeOriginFor<T>> {
+		ensure_subset(o, |community_id, subset| match subset {
+			Subset::Fraction(fraction) => *fraction >= F::get(),
+			Subset::Members { count } => {
+				let total = Pallet::<T>::members_count(community_id).members;
+				total > 0 && Permill::from_rational(*count, total) >= F::get()
+			}
+			_ => false,
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOriginFor<T>, ()> {
+		Ok(successful_subset_origin::<T>(Subset::Fraction(F::get())))
+	}
+}
+
+/// Ensures the origin is a subset of the members of a community holding a
+/// rank of at least `R`.
+pub struct EnsureCommunityRank<T, R>(PhantomData<(T, R)>);
+
+impl<T, R> EnsureOrigin<RuntimeOriginFor<T>> for EnsureCommunityRank<T, R>
+where
+	RuntimeOriginFor<T>: Into<Result<RawOrigin<T>, RuntimeOriginFor<T>>> + From<RawOrigin<T>>,
+	T: Config,
+	R: Get<GenericRank>,
+{
+	type Success = CommunityIdOf<T>;
+
+	fn try_origin(o: RuntimeOriginFor<T>) -> Result<Self::Success, RuntimeOriginFor<T>> {
+		ensure_subset(
+			o,
+			|_, subset| matches!(subset, Subset::AtLeastRank(rank) if *rank >= R::get()),
+		)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOriginFor<T>, ()> {
+		Ok(successful_subset_origin::<T>(Subset::AtLeastRank(R::get())))
+	}
+}
+
 morph_types! {
 	pub type PaymentForCreate<
 		AccountId,
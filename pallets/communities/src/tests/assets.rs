@@ -0,0 +1,308 @@
+use super::*;
+use crate::{types::CommunityAssetMetadata, AssetCreators, CommunityAssets, DecisionMethod};
+use frame_support::{
+	assert_noop,
+	traits::fungibles::{metadata::Inspect as MetadataInspect, roles::Inspect as RolesInspect, Create, Inspect},
+	BoundedVec,
+};
+use sp_runtime::traits::AccountIdConversion;
+
+const ALICE: AccountId = AccountId::new([1; 32]);
+const BOB: AccountId = AccountId::new([2; 32]);
+const MEMBERSHIP: MembershipId = 1;
+const ASSET_ID: AssetId = 10;
+const FOREIGN_ASSET_ID: AssetId = 11;
+
+fn community_origin() -> RuntimeOrigin {
+	COMMUNITY_ORIGIN.into()
+}
+
+fn metadata() -> CommunityAssetMetadata {
+	CommunityAssetMetadata {
+		name: BoundedVec::truncate_from(b"Community Token".to_vec()),
+		symbol: BoundedVec::truncate_from(b"CT".to_vec()),
+		decimals: 2,
+	}
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	TestEnvBuilder::new()
+		.with_balances(&[(Communities::community_account(&COMMUNITY), 1_000)])
+		.add_community(COMMUNITY, DecisionMethod::Membership, &[ALICE], &[MEMBERSHIP], None)
+		.add_asset(&FOREIGN_ASSET_ID, &BOB, true, 1, None, None)
+		.build()
+}
+
+mod create_asset {
+	use super::*;
+
+	#[test]
+	fn fails_if_the_asset_exists() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::create_asset(community_origin(), FOREIGN_ASSET_ID, 1, false, metadata()),
+				pallet_assets::Error::<Test>::InUse
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::create_asset(
+				community_origin(),
+				ASSET_ID,
+				1,
+				true,
+				metadata()
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::CommunityAssetCreated {
+					id: COMMUNITY,
+					asset_id: ASSET_ID,
+				}
+				.into(),
+			);
+			assert!(CommunityAssets::<Test>::contains_key(COMMUNITY, ASSET_ID));
+			assert_eq!(AssetCreators::<Test>::get(ASSET_ID), Some(COMMUNITY));
+			assert_eq!(
+				Assets::owner(ASSET_ID),
+				Some(Communities::community_account(&COMMUNITY))
+			);
+			assert_eq!(Assets::name(ASSET_ID), b"Community Token".to_vec());
+			assert_eq!(Assets::decimals(ASSET_ID), 2);
+		});
+	}
+
+	#[test]
+	fn setting_a_decision_method_creates_a_community_asset() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::set_decision_method(
+				community_origin(),
+				COMMUNITY,
+				None,
+				DecisionMethod::CommunityAsset(ASSET_ID, 10)
+			));
+
+			assert!(CommunityAssets::<Test>::contains_key(COMMUNITY, ASSET_ID));
+			assert_eq!(Assets::minimum_balance(ASSET_ID), 10);
+		});
+	}
+}
+
+mod set_asset_metadata {
+	use super::*;
+
+	#[test]
+	fn fails_if_not_a_community_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::set_asset_metadata(community_origin(), FOREIGN_ASSET_ID, metadata()),
+				Error::NotACommunityAsset
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::create_asset(
+				community_origin(),
+				ASSET_ID,
+				1,
+				true,
+				metadata()
+			));
+			assert_ok!(Communities::set_asset_metadata(
+				community_origin(),
+				ASSET_ID,
+				CommunityAssetMetadata {
+					symbol: BoundedVec::truncate_from(b"CTK".to_vec()),
+					..metadata()
+				}
+			));
+
+			assert_eq!(Assets::symbol(ASSET_ID), b"CTK".to_vec());
+		});
+	}
+}
+
+mod mint_and_burn {
+	use super::*;
+
+	#[test]
+	fn fails_if_not_a_community_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::mint_asset(community_origin(), FOREIGN_ASSET_ID, ALICE, 10),
+				Error::NotACommunityAsset
+			);
+			assert_noop!(
+				Communities::burn_asset(community_origin(), FOREIGN_ASSET_ID, 10),
+				Error::NotACommunityAsset
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			let community_account = Communities::community_account(&COMMUNITY);
+			assert_ok!(Communities::create_asset(
+				community_origin(),
+				ASSET_ID,
+				1,
+				true,
+				metadata()
+			));
+
+			assert_ok!(Communities::mint_asset(community_origin(), ASSET_ID, ALICE, 10));
+			assert_ok!(Communities::mint_asset(
+				community_origin(),
+				ASSET_ID,
+				community_account.clone(),
+				100
+			));
+			assert_eq!(Assets::balance(ASSET_ID, &ALICE), 10);
+
+			assert_ok!(Communities::burn_asset(community_origin(), ASSET_ID, 40));
+			assert_eq!(Assets::balance(ASSET_ID, &community_account), 60);
+			assert_eq!(Assets::total_issuance(ASSET_ID), 70);
+		});
+	}
+}
+
+mod claim_asset {
+	use super::*;
+
+	const OTHER_COMMUNITY: CommunityId = 2;
+	const OTHER_ASSET_ID: AssetId = 12;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = super::new_test_ext();
+
+		t.execute_with(|| {
+			// Assets created on behalf of communities before they could manage
+			// their own assets.
+			for (asset_id, community_id) in [(ASSET_ID, COMMUNITY), (OTHER_ASSET_ID, OTHER_COMMUNITY)] {
+				assert_ok!(<Assets as Create<AccountId>>::create(
+					asset_id,
+					CommunitiesPalletId::get().into_account_truncating(),
+					false,
+					10
+				));
+				AssetCreators::<Test>::insert(asset_id, community_id);
+			}
+		});
+
+		t
+	}
+
+	#[test]
+	fn fails_if_the_community_does_not_vote_with_the_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::claim_asset(community_origin(), ASSET_ID),
+				Error::AssetNotClaimable
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_the_asset_is_not_held_by_the_pallet() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::set_decision_method(
+				community_origin(),
+				COMMUNITY,
+				None,
+				DecisionMethod::CommunityAsset(FOREIGN_ASSET_ID, 10)
+			));
+
+			assert_noop!(
+				Communities::claim_asset(community_origin(), FOREIGN_ASSET_ID),
+				Error::AssetNotClaimable
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_the_asset_was_created_for_another_community() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::set_decision_method(
+				community_origin(),
+				COMMUNITY,
+				None,
+				DecisionMethod::CommunityAsset(OTHER_ASSET_ID, 10)
+			));
+
+			assert_noop!(
+				Communities::claim_asset(community_origin(), OTHER_ASSET_ID),
+				Error::AssetNotClaimable
+			);
+		});
+	}
+
+	#[test]
+	fn migrating_records_the_creator_of_assets_held_by_the_pallet() {
+		use crate::migrations::v4::MigrateAssetCreatorsToV4;
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(3).put::<Communities>();
+			let _ = AssetCreators::<Test>::clear(u32::MAX, None);
+			assert_ok!(Communities::set_decision_method(
+				community_origin(),
+				COMMUNITY,
+				None,
+				DecisionMethod::CommunityAsset(ASSET_ID, 10)
+			));
+			// Assets not held by the pallet aren't claimable anyway
+			assert_ok!(Communities::set_decision_method(
+				community_origin(),
+				COMMUNITY,
+				Some(COMMUNITY.into()),
+				DecisionMethod::CommunityAsset(FOREIGN_ASSET_ID, 10)
+			));
+
+			MigrateAssetCreatorsToV4::<Test>::on_runtime_upgrade();
+
+			assert_eq!(AssetCreators::<Test>::get(ASSET_ID), Some(COMMUNITY));
+			assert_eq!(AssetCreators::<Test>::get(FOREIGN_ASSET_ID), None);
+			assert_eq!(AssetCreators::<Test>::get(OTHER_ASSET_ID), None);
+			assert_eq!(Communities::on_chain_storage_version(), 4);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::set_decision_method(
+				community_origin(),
+				COMMUNITY,
+				None,
+				DecisionMethod::CommunityAsset(ASSET_ID, 10)
+			));
+
+			assert_ok!(Communities::claim_asset(community_origin(), ASSET_ID));
+
+			System::assert_has_event(
+				crate::Event::<Test>::CommunityAssetClaimed {
+					id: COMMUNITY,
+					asset_id: ASSET_ID,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Assets::owner(ASSET_ID),
+				Some(Communities::community_account(&COMMUNITY))
+			);
+			assert_ok!(Communities::mint_asset(
+				community_origin(),
+				ASSET_ID,
+				Communities::community_account(&COMMUNITY),
+				10
+			));
+		});
+	}
+}
@@ -3,10 +3,10 @@ use super::*;
 use frame_support::{parameter_types, traits::OriginTrait};
 use pallet_referenda::{BoundedCallOf, Curve, TrackInfoOf};
 use parity_scale_codec::Encode;
-use sp_runtime::{str_array as s, BoundedVec, TokenError};
+use sp_runtime::{str_array as s, BoundedVec, DispatchError, TokenError};
 
 use crate::{
-	types::{Tally, Vote},
+	types::{Conviction, Tally, Vote},
 	Call, DecisionMethod,
 };
 use frame_support::assert_noop;
@@ -162,7 +162,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
 			pallet_referenda::Event::<Test>::Submitted {
 				index: 0,
 				proposal: ProposalCallAddBob::get(),
-				track: COMMUNITY_A,
+				track: COMMUNITY_A.into(),
 			}
 			.into(),
 		);
@@ -170,7 +170,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
 			pallet_referenda::Event::<Test>::Submitted {
 				index: 1,
 				proposal: ProposalCallAddAlice::get(),
-				track: COMMUNITY_B,
+				track: COMMUNITY_B.into(),
 			}
 			.into(),
 		);
@@ -178,7 +178,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
 			pallet_referenda::Event::<Test>::Submitted {
 				index: 2,
 				proposal: ProposalCallRemoveCharlieFromC::get(),
-				track: COMMUNITY_C,
+				track: COMMUNITY_C.into(),
 			}
 			.into(),
 		);
@@ -207,7 +207,7 @@ mod vote {
 						RuntimeOrigin::signed(BOB),
 						membership(COMMUNITY_B, 1),
 						1,
-						Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 0)
+						Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 0, Conviction::Locked1x)
 					),
 					Error::VoteBelowMinimum
 				);
@@ -309,9 +309,11 @@ mod vote {
 							ayes: 1,
 							bare_ayes: 1,
 							nays: 0,
+							max_support: 1,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
-						COMMUNITY_A
+						COMMUNITY_A.into()
 					))
 				);
 			});
@@ -330,9 +332,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 0,
-						track: COMMUNITY_A,
+						track: COMMUNITY_A.into(),
 						proposal: ProposalCallAddBob::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 1,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -359,6 +365,8 @@ mod vote {
 							ayes: 1,
 							nays: 0,
 							bare_ayes: 1,
+							max_support: 1,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -390,9 +398,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 0,
-						track: COMMUNITY_A,
+						track: COMMUNITY_A.into(),
 						proposal: ProposalCallAddBob::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 1,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -414,6 +426,8 @@ mod vote {
 							ayes: 0,
 							nays: 1,
 							bare_ayes: 0,
+							max_support: 1,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -428,28 +442,44 @@ mod vote {
 				let mut ext = new_test_ext();
 
 				ext.execute_with(|| {
-					// For now, this community will vote membership-based
+					// For now, this community will vote membership-based. The
+					// voting power of a poll is snapshotted when it's submitted,
+					// so the decision method is set beforehand
 					assert_ok!(Communities::set_decision_method(
-						TestEnvBuilder::create_community_origin(&COMMUNITY_C),
-						COMMUNITY_C,
+						TestEnvBuilder::create_community_origin(&COMMUNITY_D),
+						COMMUNITY_D,
+						None,
 						DecisionMethod::Membership
 					));
+					assert_ok!(Referenda::submit(
+						RuntimeOrigin::signed(CHARLIE),
+						OriginForCommunityD::get(),
+						ProposalCallPromoteCharlie::get(),
+						frame_support::traits::schedule::DispatchTime::After(1),
+					));
+					assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(CHARLIE), 3));
+
+					tick_block();
 
 					// Before voting, the poll is ongoing
 					System::assert_has_event(
 						pallet_referenda::Event::<Test>::DecisionStarted {
-							index: 2,
-							track: COMMUNITY_C,
-							proposal: ProposalCallRemoveCharlieFromC::get(),
-							tally: Tally::default(),
+							index: 3,
+							track: COMMUNITY_D.into(),
+							proposal: ProposalCallPromoteCharlie::get(),
+							tally: Tally {
+								max_support: 3,
+								snapshot_at: Some(2),
+								..Default::default()
+							},
 						}
 						.into(),
 					);
 
 					assert_ok!(Communities::vote(
 						RuntimeOrigin::signed(ALICE),
-						membership(COMMUNITY_C, 1),
-						2,
+						membership(COMMUNITY_D, 1),
+						3,
 						Vote::Standard(true)
 					));
 
@@ -457,15 +487,15 @@ mod vote {
 
 					assert_ok!(Communities::vote(
 						RuntimeOrigin::signed(CHARLIE),
-						membership(COMMUNITY_C, 3),
-						2,
+						membership(COMMUNITY_D, 3),
+						3,
 						Vote::Standard(false)
 					));
 
 					tick_blocks(4);
 
 					// After voting, the poll starts confirmation
-					System::assert_has_event(pallet_referenda::Event::<Test>::ConfirmStarted { index: 2 }.into());
+					System::assert_has_event(pallet_referenda::Event::<Test>::ConfirmStarted { index: 3 }.into());
 				});
 
 				ext
@@ -474,8 +504,8 @@ mod vote {
 			run_referenda().execute_with(|| {
 				assert_ok!(Communities::vote(
 					RuntimeOrigin::signed(BOB),
-					membership(COMMUNITY_C, 2),
-					2,
+					membership(COMMUNITY_D, 2),
+					3,
 					Vote::Standard(true)
 				));
 
@@ -484,11 +514,13 @@ mod vote {
 				// After confirmation, vote should be completed and approved
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::Confirmed {
-						index: 2,
+						index: 3,
 						tally: Tally {
 							ayes: 2,
 							nays: 1,
 							bare_ayes: 2,
+							max_support: 3,
+							snapshot_at: Some(2),
 							..Default::default()
 						},
 					}
@@ -499,8 +531,8 @@ mod vote {
 			run_referenda().execute_with(|| {
 				assert_ok!(Communities::vote(
 					RuntimeOrigin::signed(BOB),
-					membership(COMMUNITY_C, 2),
-					2,
+					membership(COMMUNITY_D, 2),
+					3,
 					Vote::Standard(false)
 				));
 
@@ -509,11 +541,13 @@ mod vote {
 				// After voting, the poll starts confirmation
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::Rejected {
-						index: 2,
+						index: 3,
 						tally: Tally {
 							ayes: 1,
 							nays: 2,
 							bare_ayes: 1,
+							max_support: 3,
+							snapshot_at: Some(2),
 							..Default::default()
 						},
 					}
@@ -535,7 +569,7 @@ mod vote {
 						RuntimeOrigin::signed(CHARLIE),
 						membership(COMMUNITY_B, 2),
 						1,
-						Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 51)
+						Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 51, Conviction::Locked1x)
 					),
 					TokenError::FundsUnavailable
 				);
@@ -550,7 +584,7 @@ mod vote {
 						RuntimeOrigin::signed(BOB),
 						membership(COMMUNITY_B, 1),
 						1,
-						Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 9)
+						Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 9, Conviction::Locked1x)
 					),
 					Error::VoteBelowMinimum
 				);
@@ -558,7 +592,7 @@ mod vote {
 					RuntimeOrigin::signed(BOB),
 					membership(COMMUNITY_B, 1),
 					1,
-					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 10)
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 10, Conviction::Locked1x)
 				));
 			});
 		}
@@ -570,9 +604,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 1,
-						track: COMMUNITY_B,
+						track: COMMUNITY_B.into(),
 						proposal: ProposalCallAddAlice::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 100,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -584,7 +622,7 @@ mod vote {
 					RuntimeOrigin::signed(BOB),
 					membership(COMMUNITY_B, 1),
 					1,
-					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 30)
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 30, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -593,7 +631,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_B, 2),
 					1,
-					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 30)
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 30, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -609,6 +647,8 @@ mod vote {
 							ayes: 60,
 							nays: 0,
 							bare_ayes: 60,
+							max_support: 100,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -624,9 +664,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 1,
-						track: COMMUNITY_B,
+						track: COMMUNITY_B.into(),
 						proposal: ProposalCallAddAlice::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 100,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -638,7 +682,7 @@ mod vote {
 					RuntimeOrigin::signed(BOB),
 					membership(COMMUNITY_B, 1),
 					1,
-					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 12)
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 12, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -647,7 +691,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_B, 2),
 					1,
-					Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 11)
+					Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 11, Conviction::Locked1x)
 				));
 
 				tick_blocks(4);
@@ -663,6 +707,8 @@ mod vote {
 							ayes: 12,
 							nays: 11,
 							bare_ayes: 12,
+							max_support: 100,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -678,9 +724,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 1,
-						track: COMMUNITY_B,
+						track: COMMUNITY_B.into(),
 						proposal: ProposalCallAddAlice::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 100,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -694,7 +744,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_B, 2),
 					1,
-					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 11)
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 11, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -703,7 +753,7 @@ mod vote {
 					RuntimeOrigin::signed(BOB),
 					membership(COMMUNITY_B, 1),
 					1,
-					Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 12)
+					Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 12, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -712,7 +762,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_B, 2),
 					1,
-					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 13)
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 13, Conviction::Locked1x)
 				));
 
 				tick_blocks(3);
@@ -724,6 +774,8 @@ mod vote {
 							ayes: 13,
 							nays: 12,
 							bare_ayes: 13,
+							max_support: 100,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -744,7 +796,7 @@ mod vote {
 						RuntimeOrigin::signed(BOB),
 						membership(COMMUNITY_C, 2),
 						2,
-						Vote::NativeBalance(true, 16)
+						Vote::NativeBalance(true, 16, Conviction::Locked1x)
 					),
 					TokenError::FundsUnavailable
 				);
@@ -758,9 +810,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 2,
-						track: COMMUNITY_C,
+						track: COMMUNITY_C.into(),
 						proposal: ProposalCallRemoveCharlieFromC::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 45,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -775,7 +831,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_C, 3),
 					2,
-					Vote::NativeBalance(false, 14)
+					Vote::NativeBalance(false, 14, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -784,7 +840,7 @@ mod vote {
 					RuntimeOrigin::signed(ALICE),
 					membership(COMMUNITY_C, 1),
 					2,
-					Vote::NativeBalance(true, 7)
+					Vote::NativeBalance(true, 7, Conviction::Locked1x)
 				));
 
 				tick_blocks(3);
@@ -796,6 +852,8 @@ mod vote {
 							ayes: 7,
 							nays: 14,
 							bare_ayes: 7,
+							max_support: 45,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -811,9 +869,13 @@ mod vote {
 				System::assert_has_event(
 					pallet_referenda::Event::<Test>::DecisionStarted {
 						index: 2,
-						track: COMMUNITY_C,
+						track: COMMUNITY_C.into(),
 						proposal: ProposalCallRemoveCharlieFromC::get(),
-						tally: Tally::default(),
+						tally: Tally {
+							max_support: 45,
+							snapshot_at: Some(1),
+							..Default::default()
+						},
 					}
 					.into(),
 				);
@@ -827,7 +889,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_C, 3),
 					2,
-					Vote::NativeBalance(false, 6)
+					Vote::NativeBalance(false, 6, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -836,7 +898,7 @@ mod vote {
 					RuntimeOrigin::signed(ALICE),
 					membership(COMMUNITY_C, 1),
 					2,
-					Vote::NativeBalance(true, 7)
+					Vote::NativeBalance(true, 7, Conviction::Locked1x)
 				));
 
 				tick_block();
@@ -845,7 +907,7 @@ mod vote {
 					RuntimeOrigin::signed(CHARLIE),
 					membership(COMMUNITY_C, 3),
 					2,
-					Vote::NativeBalance(false, 8)
+					Vote::NativeBalance(false, 8, Conviction::Locked1x)
 				));
 
 				tick_blocks(2);
@@ -857,6 +919,8 @@ mod vote {
 							ayes: 7,
 							nays: 8,
 							bare_ayes: 7,
+							max_support: 45,
+							snapshot_at: Some(1),
 							..Default::default()
 						},
 					}
@@ -866,260 +930,1366 @@ mod vote {
 		}
 	}
 
-	mod rank {
+	mod conviction {
 		use frame_support::traits::Polling;
 
 		use super::*;
 
-		fn new_test_ext() -> sp_io::TestExternalities {
-			let mut ext = super::new_test_ext();
-
-			ext.execute_with(|| {
-				assert_ok!(Communities::promote(
-					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
-					membership(COMMUNITY_D, 1)
-				));
-				assert_ok!(Communities::promote(
-					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
-					membership(COMMUNITY_D, 2)
-				));
-				assert_ok!(Communities::promote(
-					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
-					membership(COMMUNITY_D, 3)
-				));
-
-				assert_ok!(Referenda::submit(
+		#[test]
+		fn conviction_multiplies_vote_weight_but_not_support() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(Communities::vote(
 					RuntimeOrigin::signed(CHARLIE),
-					OriginForCommunityD::get(),
-					ProposalCallPromoteCharlie::get(),
-					frame_support::traits::schedule::DispatchTime::After(1),
+					membership(COMMUNITY_C, 3),
+					2,
+					Vote::NativeBalance(false, 10, Conviction::Locked6x)
+				));
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_C, 1),
+					2,
+					Vote::NativeBalance(true, 10, Conviction::None)
 				));
 
-				System::assert_has_event(
-					pallet_referenda::Event::<Test>::Submitted {
-						index: 3,
-						proposal: ProposalCallPromoteCharlie::get(),
-						track: COMMUNITY_D,
+				assert_eq!(
+					Referenda::as_ongoing(2).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 1,
+						nays: 60,
+						bare_ayes: 10,
+						max_support: 45,
+						snapshot_at: Some(1),
+						..Default::default()
 					}
-					.into(),
 				);
+			});
+		}
 
-				assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(CHARLIE), 3));
+		#[test]
+		fn fails_if_conviction_is_kept_for_migrated_votes() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					Communities::vote(
+						RuntimeOrigin::signed(ALICE),
+						membership(COMMUNITY_C, 1),
+						2,
+						Vote::NativeBalance(true, 10, Conviction::Unlocked1x)
+					),
+					Error::InvalidConviction
+				);
+			});
+		}
+	}
 
-				tick_block();
+	mod split_and_abstain {
+		use frame_support::traits::{fungible::InspectFreeze, Polling, VoteTally};
+
+		use super::*;
+		use crate::types::VotePower;
+
+		#[test]
+		fn fails_if_split_exceeds_voting_power() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					Communities::vote(
+						RuntimeOrigin::signed(ALICE),
+						membership(COMMUNITY_C, 1),
+						2,
+						Vote::Split {
+							power: VotePower::Native(10),
+							aye: Perbill::from_percent(60),
+							nay: Perbill::from_percent(50),
+						}
+					),
+					Error::InvalidVoteSplit
+				);
 			});
+		}
 
-			ext
+		#[test]
+		fn fails_if_power_does_not_match_decision_method() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					Communities::vote(
+						RuntimeOrigin::signed(ALICE),
+						membership(COMMUNITY_A, 1),
+						0,
+						Vote::Abstain(VotePower::Native(10))
+					),
+					Error::InvalidVoteType
+				);
+			});
 		}
 
 		#[test]
-		fn it_works() {
+		fn abstentions_count_toward_support_but_not_approval() {
 			new_test_ext().execute_with(|| {
 				assert_ok!(Communities::vote(
 					RuntimeOrigin::signed(ALICE),
-					membership(COMMUNITY_D, 1),
-					3,
-					Vote::Standard(true)
+					membership(COMMUNITY_A, 1),
+					0,
+					Vote::Abstain(VotePower::Membership)
 				));
 
-				tick_block();
+				let tally = Referenda::as_ongoing(0).expect("the poll was initiated; qed").0;
+				assert_eq!(
+					tally,
+					Tally {
+						abstentions: 1,
+						max_support: 1,
+						snapshot_at: Some(1),
+						..Default::default()
+					}
+				);
+				assert_eq!(tally.support(COMMUNITY_A.into()), Perbill::one());
+				assert_eq!(tally.approval(COMMUNITY_A.into()), Perbill::zero());
+			});
+		}
 
+		#[test]
+		fn split_votes_spread_their_weight_and_lock_their_funds() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_C, 1),
+					2,
+					Vote::Split {
+						power: VotePower::Native(10),
+						aye: Perbill::from_percent(50),
+						nay: Perbill::from_percent(20),
+					}
+				));
 				assert_ok!(Communities::vote(
 					RuntimeOrigin::signed(BOB),
-					membership(COMMUNITY_D, 2),
-					3,
-					Vote::Standard(false)
+					membership(COMMUNITY_C, 2),
+					2,
+					Vote::Abstain(VotePower::Native(6))
 				));
 
-				tick_block();
+				assert_eq!(
+					Referenda::as_ongoing(2).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 5,
+						nays: 2,
+						bare_ayes: 5,
+						abstentions: 9,
+						max_support: 45,
+						snapshot_at: Some(1),
+						..Default::default()
+					}
+				);
+				assert_eq!(
+					Balances::balance_frozen(&crate::FreezeReason::VoteCasted.into(), &BOB),
+					6
+				);
 
-				assert_ok!(Communities::vote(
-					RuntimeOrigin::signed(CHARLIE),
-					membership(COMMUNITY_D, 3),
-					3,
-					Vote::Standard(true)
+				assert_ok!(Communities::remove_vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_C, 2),
+					2
 				));
 
-				tick_blocks(2);
-
-				System::assert_has_event(pallet_referenda::Event::<Test>::ConfirmStarted { index: 3 }.into());
+				assert_eq!(
+					Referenda::as_ongoing(2).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 5,
+						nays: 2,
+						bare_ayes: 5,
+						abstentions: 3,
+						max_support: 45,
+						snapshot_at: Some(1),
+						..Default::default()
+					}
+				);
+				assert_eq!(
+					Balances::balance_frozen(&crate::FreezeReason::VoteCasted.into(), &BOB),
+					0
+				);
 			});
 		}
+	}
+
+	mod quadratic {
+		use frame_support::traits::Polling;
+
+		use super::*;
 
 		#[test]
-		fn it_works_with_different_ranks() {
+		fn vote_weight_is_the_square_root_of_the_locked_amount() {
 			new_test_ext().execute_with(|| {
-				assert_ok!(Communities::promote(
-					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
-					membership(COMMUNITY_D, 1)
-				));
-
-				assert_ok!(Communities::vote(
-					RuntimeOrigin::signed(ALICE),
-					membership(COMMUNITY_D, 1),
-					3,
-					Vote::Standard(false)
+				assert_ok!(Communities::set_decision_method(
+					TestEnvBuilder::create_community_origin(&COMMUNITY_B),
+					COMMUNITY_B,
+					None,
+					DecisionMethod::Quadratic(COMMUNITY_B_ASSET_ID, 10)
 				));
 
-				tick_block();
+				assert_noop!(
+					Communities::vote(
+						RuntimeOrigin::signed(BOB),
+						membership(COMMUNITY_B, 1),
+						1,
+						Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 9, Conviction::Locked1x)
+					),
+					Error::VoteBelowMinimum
+				);
 
 				assert_ok!(Communities::vote(
 					RuntimeOrigin::signed(BOB),
-					membership(COMMUNITY_D, 2),
-					3,
-					Vote::Standard(true)
+					membership(COMMUNITY_B, 1),
+					1,
+					Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 49, Conviction::Locked1x)
+				));
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(CHARLIE),
+					membership(COMMUNITY_B, 2),
+					1,
+					Vote::AssetBalance(false, COMMUNITY_B_ASSET_ID, 16, Conviction::Locked2x)
 				));
 
-				tick_block();
+				// Support still counts the locked amount
+				assert_eq!(
+					Referenda::as_ongoing(1).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 7,
+						nays: 8,
+						bare_ayes: 49,
+						max_support: 100,
+						snapshot_at: Some(1),
+						..Default::default()
+					}
+				);
 
-				assert_ok!(Communities::vote(
+				assert_ok!(Communities::remove_vote(
 					RuntimeOrigin::signed(CHARLIE),
-					membership(COMMUNITY_D, 3),
-					3,
-					Vote::Standard(true)
+					membership(COMMUNITY_B, 2),
+					1
 				));
 
 				assert_eq!(
-					Referenda::as_ongoing(3).expect("the poll was initiated; qed").0,
+					Referenda::as_ongoing(1).expect("the poll was initiated; qed").0,
 					Tally {
-						ayes: 2,
-						nays: 2,
-						bare_ayes: 2,
+						ayes: 7,
+						nays: 0,
+						bare_ayes: 49,
+						max_support: 100,
+						snapshot_at: Some(1),
 						..Default::default()
 					}
-				)
+				);
 			});
 		}
 	}
-}
 
-mod remove_vote {
-	use frame_support::traits::{fungible::Inspect, Polling};
+	mod track_decision_method {
+		use frame_support::traits::Polling;
 
-	use super::*;
+		use super::*;
+		use crate::{origin::Subset, types::CommunityTrackId, Origin as CommunityOrigin};
 
-	#[test]
-	fn fails_if_not_a_member() {
-		new_test_ext().execute_with(|| {
-			assert_noop!(
-				Communities::remove_vote(RuntimeOrigin::signed(BOB), membership(COMMUNITY_A, 2), 0,),
-				Error::NotAMember
-			);
-		});
-	}
+		fn members_track() -> u32 {
+			u32::for_community(COMMUNITY_D, 1)
+		}
 
-	#[test]
-	fn fails_if_trying_to_remove_vote_from_invalid_track() {
-		new_test_ext().execute_with(|| {
-			assert_noop!(
-				Communities::remove_vote(RuntimeOrigin::signed(ALICE), membership(COMMUNITY_A, 1), 1),
-				Error::InvalidTrack
-			);
-		});
-	}
+		fn new_test_ext() -> sp_io::TestExternalities {
+			let mut ext = super::new_test_ext();
+
+			ext.execute_with(|| {
+				let mut track_origin = CommunityOrigin::<Test>::new(COMMUNITY_D);
+				track_origin.with_subset(Subset::Members { count: 2 });
+				let track_origin = OriginCaller::Communities(track_origin);
+
+				assert_ok!(Tracks::insert(
+					RuntimeOrigin::root(),
+					members_track(),
+					CommunityTrack::get(),
+					track_origin.clone(),
+				));
+				assert_ok!(Communities::set_decision_method(
+					TestEnvBuilder::create_community_origin(&COMMUNITY_D),
+					COMMUNITY_D,
+					Some(members_track()),
+					DecisionMethod::Membership
+				));
+
+				assert_ok!(Referenda::submit(
+					RuntimeOrigin::signed(CHARLIE),
+					Box::new(track_origin),
+					ProposalCallPromoteCharlie::get(),
+					frame_support::traits::schedule::DispatchTime::After(1),
+				));
+				assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(CHARLIE), 3));
+
+				tick_block();
+			});
+
+			ext
+		}
+
+		#[test]
+		fn fails_if_track_belongs_to_another_community() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					Communities::set_decision_method(
+						TestEnvBuilder::create_community_origin(&COMMUNITY_C),
+						COMMUNITY_C,
+						Some(members_track()),
+						DecisionMethod::Membership
+					),
+					Error::InvalidTrack
+				);
+			});
+		}
+
+		#[test]
+		fn fails_if_origin_is_of_another_community() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					Communities::set_decision_method(
+						TestEnvBuilder::create_community_origin(&COMMUNITY_C),
+						COMMUNITY_D,
+						Some(members_track()),
+						DecisionMethod::Membership
+					),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					Communities::set_decision_method(
+						TestEnvBuilder::create_community_origin(&COMMUNITY_C),
+						COMMUNITY_D,
+						None,
+						DecisionMethod::Membership
+					),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn polls_use_the_decision_method_of_their_track() {
+			new_test_ext().execute_with(|| {
+				assert_eq!(members_track().community_id(), COMMUNITY_D);
+				assert_eq!(
+					Communities::decision_method(&members_track()),
+					DecisionMethod::Membership
+				);
+				assert_eq!(Communities::decision_method(&COMMUNITY_D.into()), DecisionMethod::Rank);
+
+				// Ranks are zero, yet every member has a say on this track
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					3,
+					Vote::Standard(true)
+				));
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					3,
+					Vote::Standard(false)
+				));
+
+				assert_eq!(
+					Referenda::as_ongoing(3).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 1,
+						nays: 1,
+						bare_ayes: 1,
+						max_support: 3,
+						snapshot_at: Some(2),
+						..Default::default()
+					}
+				);
+			});
+		}
+	}
+
+	mod rank {
+		use frame_support::traits::Polling;
+
+		use super::*;
+
+		fn new_test_ext() -> sp_io::TestExternalities {
+			let mut ext = super::new_test_ext();
+
+			ext.execute_with(|| {
+				for _ in 0..2 {
+					assert_ok!(Communities::promote(
+						Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+						membership(COMMUNITY_D, 1)
+					));
+				}
+				assert_ok!(Communities::promote(
+					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+					membership(COMMUNITY_D, 2)
+				));
+				assert_ok!(Communities::promote(
+					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+					membership(COMMUNITY_D, 3)
+				));
+
+				assert_ok!(Referenda::submit(
+					RuntimeOrigin::signed(CHARLIE),
+					OriginForCommunityD::get(),
+					ProposalCallPromoteCharlie::get(),
+					frame_support::traits::schedule::DispatchTime::After(1),
+				));
+
+				System::assert_has_event(
+					pallet_referenda::Event::<Test>::Submitted {
+						index: 3,
+						proposal: ProposalCallPromoteCharlie::get(),
+						track: COMMUNITY_D.into(),
+					}
+					.into(),
+				);
+
+				assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(CHARLIE), 3));
+
+				tick_block();
+			});
+
+			ext
+		}
+
+		#[test]
+		fn it_works() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					3,
+					Vote::Standard(true)
+				));
+
+				tick_block();
+
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					3,
+					Vote::Standard(false)
+				));
+
+				tick_block();
+
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(CHARLIE),
+					membership(COMMUNITY_D, 3),
+					3,
+					Vote::Standard(true)
+				));
+
+				tick_blocks(2);
+
+				System::assert_has_event(pallet_referenda::Event::<Test>::ConfirmStarted { index: 3 }.into());
+			});
+		}
+
+		#[test]
+		fn it_works_with_different_ranks() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					3,
+					Vote::Standard(false)
+				));
+
+				tick_block();
+
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					3,
+					Vote::Standard(true)
+				));
+
+				tick_block();
+
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(CHARLIE),
+					membership(COMMUNITY_D, 3),
+					3,
+					Vote::Standard(true)
+				));
+
+				assert_eq!(
+					Referenda::as_ongoing(3).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 2,
+						nays: 2,
+						bare_ayes: 2,
+						max_support: 4,
+						snapshot_at: Some(2),
+						..Default::default()
+					}
+				)
+			});
+		}
+
+		#[test]
+		fn rank_changes_after_the_snapshot_do_not_change_voting_power() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					3,
+					Vote::Standard(true)
+				));
+
+				tick_block();
+
+				// Once the poll has been submitted, promoting a member neither
+				// changes their voting power nor the maximum support of the poll
+				assert_ok!(Communities::promote(
+					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+					membership(COMMUNITY_D, 2)
+				));
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					3,
+					Vote::Standard(false)
+				));
+
+				assert_eq!(
+					Referenda::as_ongoing(3).expect("the poll was initiated; qed").0,
+					Tally {
+						ayes: 2,
+						nays: 1,
+						bare_ayes: 2,
+						max_support: 4,
+						snapshot_at: Some(2),
+						..Default::default()
+					}
+				);
+
+				// Votes are removed with the weight they were counted with
+				assert_eq!(
+					crate::CommunityVotes::<Test>::get(3, membership(COMMUNITY_D, 1)),
+					Some((Vote::Standard(true), ALICE, 2))
+				);
+				assert_ok!(Communities::remove_vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					3
+				));
+				assert_eq!(
+					Referenda::as_ongoing(3).expect("the poll was initiated; qed").0,
+					Tally {
+						nays: 1,
+						max_support: 4,
+						snapshot_at: Some(2),
+						..Default::default()
+					}
+				);
+			});
+		}
+
+		/// Changes the rank of a membership once per block, `times` times in a
+		/// row, without letting ongoing polls progress.
+		fn change_rank(membership_id: MembershipId, times: usize) -> Result<(), DispatchError> {
+			let origin = || Into::<RuntimeOrigin>::into(*OriginForCommunityD::get());
+			for i in 0..times {
+				System::set_block_number(System::block_number() + 1);
+				if i % 2 == 0 {
+					Communities::demote(origin(), membership_id)?;
+				} else {
+					Communities::promote(origin(), membership_id)?;
+				}
+			}
+			Ok(())
+		}
+
+		#[test]
+		fn ranks_needed_by_ongoing_polls_are_kept() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					3,
+					Vote::Standard(true)
+				));
+
+				// The rank BOB had before the poll was snapshotted can be dropped,
+				// but not the ones it had afterwards
+				assert_ok!(change_rank(membership(COMMUNITY_D, 2), 16));
+				System::set_block_number(System::block_number() + 1);
+				assert_noop!(
+					Communities::demote(
+						Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+						membership(COMMUNITY_D, 2)
+					),
+					Error::RankHistoryInUse
+				);
+
+				// BOB votes with the rank it had when the poll was snapshotted
+				assert_ok!(Communities::vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					3,
+					Vote::Standard(false)
+				));
+				assert_eq!(
+					crate::CommunityVotes::<Test>::get(3, membership(COMMUNITY_D, 2)),
+					Some((Vote::Standard(false), BOB, 1))
+				);
+
+				// Once the poll is over, the rank can be changed again
+				assert_ok!(Referenda::cancel(RuntimeOrigin::root(), 3));
+				assert_ok!(change_rank(membership(COMMUNITY_D, 2), 1));
+				assert!(!crate::CommunityRankedPolls::<Test>::contains_key(COMMUNITY_D, 3));
+			});
+		}
+
+		#[test]
+		fn fails_if_the_rank_at_the_snapshot_was_dropped() {
+			new_test_ext().execute_with(|| {
+				// No votes were casted on the poll yet, so the ranks CHARLIE had
+				// when it was snapshotted are dropped
+				assert_ok!(change_rank(membership(COMMUNITY_D, 3), 17));
+
+				assert_noop!(
+					Communities::vote(
+						RuntimeOrigin::signed(CHARLIE),
+						membership(COMMUNITY_D, 3),
+						3,
+						Vote::Standard(true)
+					),
+					Error::UnknownRank
+				);
+			});
+		}
+	}
+}
+
+mod remove_vote {
+	use frame_support::traits::{fungible::Inspect, Polling};
+
+	use super::*;
+
+	#[test]
+	fn fails_if_not_a_member() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::remove_vote(RuntimeOrigin::signed(BOB), membership(COMMUNITY_A, 2), 0,),
+				Error::NotAMember
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_trying_to_remove_vote_from_invalid_track() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::remove_vote(RuntimeOrigin::signed(ALICE), membership(COMMUNITY_A, 1), 1),
+				Error::InvalidTrack
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_poll_is_no_vote_casted() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::remove_vote(RuntimeOrigin::signed(ALICE), membership(COMMUNITY_A, 1), 0),
+				Error::NoVoteCasted
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_A, 1),
+				0,
+				Vote::Standard(true)
+			));
+
+			tick_block();
+
+			assert_ok!(Communities::remove_vote(
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_A, 1),
+				0
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::VoteRemoved {
+					who: ALICE,
+					poll_index: 0,
+				}
+				.into(),
+			);
+
+			assert_eq!(
+				Referenda::as_ongoing(0).expect("we already created poll 0; qed").0,
+				Tally {
+					max_support: 1,
+					snapshot_at: Some(1),
+					..Default::default()
+				}
+			);
+		});
+
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_C, 1),
+				2,
+				Vote::NativeBalance(true, 15, Conviction::Locked1x)
+			));
+
+			assert_eq!(
+				Balances::reducible_balance(
+					&ALICE,
+					frame_support::traits::tokens::Preservation::Expendable,
+					frame_support::traits::tokens::Fortitude::Polite
+				),
+				0
+			);
+
+			tick_block();
+
+			assert_ok!(Communities::remove_vote(
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_C, 1),
+				2
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::VoteRemoved {
+					who: ALICE,
+					poll_index: 2,
+				}
+				.into(),
+			);
+
+			assert_eq!(
+				Referenda::as_ongoing(2).expect("we already created poll 2; qed").0,
+				Tally {
+					max_support: 45,
+					snapshot_at: Some(1),
+					..Default::default()
+				}
+			);
+
+			assert_eq!(
+				Balances::reducible_balance(
+					&ALICE,
+					frame_support::traits::tokens::Preservation::Expendable,
+					frame_support::traits::tokens::Fortitude::Polite
+				),
+				7
+			);
+		});
+	}
+}
+
+mod unlock {
+	use super::*;
+
+	#[test]
+	fn fails_if_trying_to_unlock_on_an_ongoing_poll() {
+		new_test_ext().execute_with(|| {
+			// Since BOB never casted a vote, a lock wasn't put in place
+			assert_noop!(
+				Communities::unlock(RuntimeOrigin::signed(BOB), 1),
+				Error::AlreadyOngoing
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_B, 1),
+				1,
+				Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 15, Conviction::None)
+			));
+
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_C, 3),
+				2,
+				Vote::NativeBalance(true, 15, Conviction::None)
+			));
+
+			tick_blocks(6);
+
+			assert_ok!(Communities::unlock(RuntimeOrigin::signed(BOB), 1));
+
+			assert_ok!(Communities::unlock(RuntimeOrigin::signed(CHARLIE), 2));
+		});
+	}
+
+	#[test]
+	fn fails_while_lock_period_has_not_elapsed() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_C, 3),
+				2,
+				Vote::NativeBalance(false, 15, Conviction::Locked2x)
+			));
+
+			tick_blocks(6);
+
+			// The poll got rejected, so the winning nay stays locked for two periods
+			assert_noop!(
+				Communities::unlock(RuntimeOrigin::signed(CHARLIE), 2),
+				Error::LockPeriodNotElapsed
+			);
+
+			tick_blocks(4);
+
+			assert_ok!(Communities::unlock(RuntimeOrigin::signed(CHARLIE), 2));
+		});
+	}
+}
+
+mod destroy_votes {
+	use super::*;
+
+	#[test]
+	fn fails_if_community_is_not_being_destroyed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::destroy_votes(RuntimeOrigin::signed(ALICE), COMMUNITY_C),
+				Error::NotBeingDestroyed
+			);
+		});
+	}
+
+	#[test]
+	fn fails_while_polls_are_ongoing() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_C, 3),
+				2,
+				Vote::NativeBalance(true, 15, Conviction::Locked1x)
+			));
+			assert_ok!(Communities::start_destroy(
+				TestEnvBuilder::create_community_origin(&COMMUNITY_C),
+				COMMUNITY_C
+			));
+
+			assert_noop!(
+				Communities::destroy_votes(RuntimeOrigin::signed(ALICE), COMMUNITY_C),
+				Error::PollOngoing
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_C, 3),
+				2,
+				Vote::NativeBalance(true, 15, Conviction::Locked1x)
+			));
+			assert_ok!(Communities::start_destroy(
+				TestEnvBuilder::create_community_origin(&COMMUNITY_C),
+				COMMUNITY_C
+			));
+
+			// Members can't vote anymore once the destruction has started
+			assert_noop!(
+				Communities::vote(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_C, 2),
+					2,
+					Vote::NativeBalance(true, 10, Conviction::Locked1x)
+				),
+				Error::CommunityDestroying
+			);
+
+			tick_blocks(6);
+
+			assert_noop!(
+				Communities::finish_destroy(RuntimeOrigin::root(), COMMUNITY_C),
+				Error::VotesRemaining
+			);
+
+			assert_ok!(Communities::destroy_votes(RuntimeOrigin::signed(ALICE), COMMUNITY_C));
+			System::assert_last_event(
+				crate::Event::VotesDestroyed {
+					id: COMMUNITY_C,
+					votes_destroyed: 1,
+				}
+				.into(),
+			);
+
+			// Funds locked by the vote are released
+			assert_noop!(
+				Communities::unlock(RuntimeOrigin::signed(CHARLIE), 2),
+				Error::NoLocksInPlace
+			);
+			assert_eq!(
+				<Balances as frame_support::traits::fungible::InspectFreeze<_>>::balance_frozen(
+					&crate::FreezeReason::VoteCasted.into(),
+					&CHARLIE
+				),
+				0
+			);
+
+			assert_ok!(Communities::destroy_records(RuntimeOrigin::signed(ALICE), COMMUNITY_C));
+			assert_ok!(Communities::finish_destroy(RuntimeOrigin::root(), COMMUNITY_C));
+		});
+	}
+}
+
+mod delegate {
+	use super::*;
+	use frame_support::traits::Polling;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext = super::new_test_ext();
+
+		ext.execute_with(|| {
+			for m in memberships_of(COMMUNITY_D) {
+				assert_ok!(Communities::promote(
+					Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+					*m
+				));
+			}
+
+			assert_ok!(Referenda::submit(
+				RuntimeOrigin::signed(CHARLIE),
+				OriginForCommunityD::get(),
+				ProposalCallPromoteCharlie::get(),
+				frame_support::traits::schedule::DispatchTime::After(1),
+			));
+			assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(CHARLIE), 3));
+
+			tick_block();
+		});
+
+		ext
+	}
+
+	fn tally_of(poll_index: u32) -> Tally<Test> {
+		Referenda::as_ongoing(poll_index)
+			.expect("the poll was initiated; qed")
+			.0
+	}
+
+	#[test]
+	fn fails_if_delegate_is_invalid() {
+		new_test_ext().execute_with(|| {
+			// Not a membership of the same community
+			assert_noop!(
+				Communities::delegate(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					membership(COMMUNITY_A, 1)
+				),
+				Error::InvalidDelegate
+			);
+			// Delegating to itself
+			assert_noop!(
+				Communities::delegate(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					membership(COMMUNITY_D, 2)
+				),
+				Error::InvalidDelegate
+			);
+		});
+	}
+
+	#[test]
+	fn fails_on_chained_delegations() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::delegate(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_D, 2),
+				membership(COMMUNITY_D, 1)
+			));
+			System::assert_last_event(
+				crate::Event::Delegated {
+					membership_id: membership(COMMUNITY_D, 2),
+					delegate: membership(COMMUNITY_D, 1),
+				}
+				.into(),
+			);
+
+			assert_noop!(
+				Communities::delegate(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					membership(COMMUNITY_D, 3)
+				),
+				Error::AlreadyDelegating
+			);
+			assert_noop!(
+				Communities::delegate(
+					RuntimeOrigin::signed(CHARLIE),
+					membership(COMMUNITY_D, 3),
+					membership(COMMUNITY_D, 2)
+				),
+				Error::InvalidDelegate
+			);
+			assert_noop!(
+				Communities::delegate(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_D, 1),
+					membership(COMMUNITY_D, 3)
+				),
+				Error::HasDelegators
+			);
+		});
+	}
 
 	#[test]
-	fn fails_if_poll_is_no_vote_casted() {
+	fn fails_if_membership_is_expired() {
 		new_test_ext().execute_with(|| {
+			ExpiredMemberships::set(vec![(COMMUNITY_D, membership(COMMUNITY_D, 2))]);
+
+			// An expired membership can't delegate its voting power
 			assert_noop!(
-				Communities::remove_vote(RuntimeOrigin::signed(ALICE), membership(COMMUNITY_A, 1), 0),
-				Error::NoVoteCasted
+				Communities::delegate(
+					RuntimeOrigin::signed(BOB),
+					membership(COMMUNITY_D, 2),
+					membership(COMMUNITY_D, 1)
+				),
+				Error::MembershipExpired
+			);
+			// Nor can it be delegated to
+			assert_noop!(
+				Communities::delegate(
+					RuntimeOrigin::signed(CHARLIE),
+					membership(COMMUNITY_D, 3),
+					membership(COMMUNITY_D, 2)
+				),
+				Error::InvalidDelegate
 			);
 		});
 	}
 
 	#[test]
-	fn it_works() {
+	fn delegate_doesnt_vote_on_behalf_of_expired_delegators() {
 		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::delegate(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_D, 2),
+				membership(COMMUNITY_D, 1)
+			));
+			ExpiredMemberships::set(vec![(COMMUNITY_D, membership(COMMUNITY_D, 2))]);
+
 			assert_ok!(Communities::vote(
 				RuntimeOrigin::signed(ALICE),
-				membership(COMMUNITY_A, 1),
-				0,
+				membership(COMMUNITY_D, 1),
+				3,
 				Vote::Standard(true)
 			));
+			assert_eq!(
+				tally_of(3),
+				Tally {
+					ayes: 1,
+					nays: 0,
+					bare_ayes: 1,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn pruned_memberships_dont_count_towards_max_support() {
+		new_test_ext().execute_with(|| {
+			ExpiredMemberships::set(vec![(COMMUNITY_D, membership(COMMUNITY_D, 3))]);
+			assert_ok!(Communities::prune_expired_member(
+				RuntimeOrigin::signed(BOB),
+				CHARLIE,
+				membership(COMMUNITY_D, 3)
+			));
 
-			tick_block();
+			assert_ok!(Referenda::submit(
+				RuntimeOrigin::signed(CHARLIE),
+				OriginForCommunityD::get(),
+				ProposalCallPromoteCharlie::get(),
+				frame_support::traits::schedule::DispatchTime::After(1),
+			));
+			assert_eq!(
+				tally_of(4),
+				Tally {
+					max_support: 2,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
+			);
+		});
+	}
 
-			assert_ok!(Communities::remove_vote(
+	#[test]
+	fn delegate_votes_on_behalf_of_delegators() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::delegate(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_D, 2),
+				membership(COMMUNITY_D, 1)
+			));
+
+			assert_ok!(Communities::vote(
 				RuntimeOrigin::signed(ALICE),
-				membership(COMMUNITY_A, 1),
-				0
+				membership(COMMUNITY_D, 1),
+				3,
+				Vote::Standard(false)
 			));
+			assert_eq!(
+				tally_of(3),
+				Tally {
+					ayes: 0,
+					nays: 2,
+					bare_ayes: 0,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
+			);
 
-			System::assert_has_event(
-				crate::Event::<Test>::VoteRemoved {
-					who: ALICE,
-					poll_index: 0,
+			// A delegator voting directly overrides the vote of its delegate
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_D, 2),
+				3,
+				Vote::Standard(true)
+			));
+			assert_eq!(
+				tally_of(3),
+				Tally {
+					ayes: 1,
+					nays: 1,
+					bare_ayes: 1,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
 				}
-				.into(),
 			);
 
+			// Removing the delegate vote doesn't affect the delegator's own vote
+			assert_ok!(Communities::remove_vote(
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_D, 1),
+				3
+			));
 			assert_eq!(
-				Referenda::as_ongoing(0).expect("we already created poll 0; qed").0,
-				Tally::default()
+				tally_of(3),
+				Tally {
+					ayes: 1,
+					nays: 0,
+					bare_ayes: 1,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
 			);
 		});
+	}
 
+	#[test]
+	fn undelegate_removes_delegated_votes() {
 		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::undelegate(RuntimeOrigin::signed(BOB), membership(COMMUNITY_D, 2)),
+				Error::NotDelegating
+			);
+
+			assert_ok!(Communities::delegate(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_D, 2),
+				membership(COMMUNITY_D, 1)
+			));
+			assert_ok!(Communities::delegate(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_D, 3),
+				membership(COMMUNITY_D, 1)
+			));
+
 			assert_ok!(Communities::vote(
 				RuntimeOrigin::signed(ALICE),
-				membership(COMMUNITY_C, 1),
-				2,
-				Vote::NativeBalance(true, 15)
+				membership(COMMUNITY_D, 1),
+				3,
+				Vote::Standard(true)
+			));
+			assert_eq!(
+				tally_of(3),
+				Tally {
+					ayes: 3,
+					nays: 0,
+					bare_ayes: 3,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
+			);
+
+			assert_ok!(Communities::undelegate(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_D, 3)
 			));
+			System::assert_last_event(
+				crate::Event::Undelegated {
+					membership_id: membership(COMMUNITY_D, 3),
+				}
+				.into(),
+			);
+			assert_eq!(
+				tally_of(3),
+				Tally {
+					ayes: 2,
+					nays: 0,
+					bare_ayes: 2,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
+			);
 
+			// The undelegated membership can vote on its own again
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_D, 3),
+				3,
+				Vote::Standard(false)
+			));
 			assert_eq!(
-				Balances::reducible_balance(
-					&ALICE,
-					frame_support::traits::tokens::Preservation::Expendable,
-					frame_support::traits::tokens::Fortitude::Polite
-				),
-				0
+				tally_of(3),
+				Tally {
+					ayes: 2,
+					nays: 1,
+					bare_ayes: 2,
+					max_support: 3,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
 			);
+		});
+	}
+}
 
-			tick_block();
+mod leave {
+	use super::*;
 
-			assert_ok!(Communities::remove_vote(
-				RuntimeOrigin::signed(ALICE),
-				membership(COMMUNITY_C, 1),
-				2
+	#[test]
+	fn fails_if_not_a_member() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::leave(RuntimeOrigin::signed(BOB), membership(COMMUNITY_A, 1)),
+				Error::NotAMember
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_C, 3),
+				2,
+				Vote::NativeBalance(true, 15, Conviction::None)
+			));
+
+			assert_ok!(Communities::leave(
+				RuntimeOrigin::signed(CHARLIE),
+				membership(COMMUNITY_C, 3)
 			));
 
 			System::assert_has_event(
-				crate::Event::<Test>::VoteRemoved {
-					who: ALICE,
-					poll_index: 2,
+				crate::Event::<Test>::MemberRemoved {
+					who: CHARLIE,
+					membership_id: membership(COMMUNITY_C, 3),
 				}
 				.into(),
 			);
+			assert!(!Communities::is_member(&COMMUNITY_C, &CHARLIE));
 
+			// The vote is removed from the poll, releasing the funds it locked
 			assert_eq!(
 				Referenda::as_ongoing(2).expect("we already created poll 2; qed").0,
-				Tally::default()
+				Tally {
+					max_support: 45,
+					snapshot_at: Some(1),
+					..Default::default()
+				}
 			);
-
 			assert_eq!(
-				Balances::reducible_balance(
-					&ALICE,
-					frame_support::traits::tokens::Preservation::Expendable,
-					frame_support::traits::tokens::Fortitude::Polite
+				<Balances as frame_support::traits::fungible::InspectFreeze<_>>::balance_frozen(
+					&crate::FreezeReason::VoteCasted.into(),
+					&CHARLIE
 				),
-				7
+				0
 			);
 		});
 	}
 }
 
-mod unlock {
+mod prune_expired_member {
 	use super::*;
+	use frame_support::traits::Polling;
 
 	#[test]
-	fn fails_if_trying_to_unlock_on_an_ongoing_poll() {
+	fn fails_if_membership_is_not_expired() {
 		new_test_ext().execute_with(|| {
-			// Since BOB never casted a vote, a lock wasn't put in place
 			assert_noop!(
-				Communities::unlock(RuntimeOrigin::signed(BOB), 1),
-				Error::AlreadyOngoing
+				Communities::prune_expired_member(RuntimeOrigin::signed(BOB), ALICE, membership(COMMUNITY_A, 1)),
+				Error::MembershipNotExpired
+			);
+		});
+	}
+
+	#[test]
+	fn expired_memberships_cannot_vote() {
+		new_test_ext().execute_with(|| {
+			ExpiredMemberships::set(vec![(COMMUNITY_A, membership(COMMUNITY_A, 1))]);
+
+			assert_noop!(
+				Communities::vote(
+					RuntimeOrigin::signed(ALICE),
+					membership(COMMUNITY_A, 1),
+					0,
+					Vote::Standard(true)
+				),
+				Error::MembershipExpired
+			);
+		});
+	}
+
+	#[test]
+	fn pruned_memberships_dont_count_towards_max_support() {
+		new_test_ext().execute_with(|| {
+			ExpiredMemberships::set(vec![(COMMUNITY_A, membership(COMMUNITY_A, 1))]);
+			assert_ok!(Communities::prune_expired_member(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				membership(COMMUNITY_A, 1)
+			));
+
+			assert_ok!(Referenda::submit(
+				RuntimeOrigin::signed(CHARLIE),
+				OriginForCommunityA::get(),
+				ProposalCallAddBob::get(),
+				frame_support::traits::schedule::DispatchTime::After(1),
+			));
+			assert_eq!(
+				Referenda::as_ongoing(3).expect("we just created poll 3; qed").0,
+				Tally {
+					max_support: 0,
+					snapshot_at: Some(2),
+					..Default::default()
+				}
 			);
 		});
 	}
@@ -1128,24 +2298,164 @@ mod unlock {
 	fn it_works() {
 		new_test_ext().execute_with(|| {
 			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_A, 1),
+				0,
+				Vote::Standard(true)
+			));
+
+			ExpiredMemberships::set(vec![(COMMUNITY_A, membership(COMMUNITY_A, 1))]);
+
+			// Anyone can prune an expired membership
+			assert_ok!(Communities::prune_expired_member(
 				RuntimeOrigin::signed(BOB),
-				membership(COMMUNITY_B, 1),
-				1,
-				Vote::AssetBalance(true, COMMUNITY_B_ASSET_ID, 15)
+				ALICE,
+				membership(COMMUNITY_A, 1)
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::MemberRemoved {
+					who: ALICE,
+					membership_id: membership(COMMUNITY_A, 1),
+				}
+				.into(),
+			);
+			assert!(!Communities::is_member(&COMMUNITY_A, &ALICE));
+			assert_eq!(
+				Referenda::as_ongoing(0).expect("we already created poll 0; qed").0,
+				Tally {
+					max_support: 1,
+					snapshot_at: Some(1),
+					..Default::default()
+				}
+			);
+		});
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::migrations::v2::{translate_tally, OldTally};
+	use frame_support::traits::Polling;
+	use parity_scale_codec::Decode;
+
+	#[test]
+	fn migrating_votes_keeps_their_weight() {
+		use crate::{
+			migrations::v1::{MigrateVotesToV1, OldVote},
+			CommunityVoteLocks, CommunityVotes,
+		};
+		use frame_support::{
+			storage::unhashed,
+			traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		};
+
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Communities>();
+
+			let old_vote = OldVote::<AssetId, Balance, Balance>::NativeBalance(true, 10);
+			unhashed::put(
+				&CommunityVotes::<Test>::hashed_key_for(2, membership(COMMUNITY_C, 1)),
+				&(&old_vote, ALICE),
+			);
+			unhashed::put(&CommunityVoteLocks::<Test>::hashed_key_for(ALICE, 2), &old_vote);
+			unhashed::put(
+				&CommunityVotes::<Test>::hashed_key_for(2, membership(COMMUNITY_C, 2)),
+				&(OldVote::<AssetId, Balance, Balance>::Standard(false), BOB),
+			);
+
+			MigrateVotesToV1::<Test>::on_runtime_upgrade();
+
+			let vote = Vote::NativeBalance(true, 10, Conviction::Unlocked1x);
+			assert_eq!(
+				CommunityVotes::<Test>::get(2, membership(COMMUNITY_C, 1)),
+				Some((vote.clone(), ALICE, 1))
+			);
+			assert_eq!(CommunityVoteLocks::<Test>::get(ALICE, 2), Some(vote.clone()));
+			// Old votes counted as much as the funds they locked, which were
+			// unlocked as soon as the poll ended
+			assert_eq!(vote.conviction_weight(), 10);
+			assert_eq!(Conviction::Unlocked1x.lock_periods(), 0);
+			assert_eq!(
+				CommunityVotes::<Test>::get(2, membership(COMMUNITY_C, 2)),
+				Some((Vote::Standard(false), BOB, 1))
+			);
+			assert_eq!(Communities::on_chain_storage_version(), 1);
+		});
+	}
+
+	#[test]
+	fn migrating_indexes_the_admin_origin_of_communities() {
+		use crate::{migrations::v3::MigrateAdminOriginsToV3, CommunityAdminOrigin};
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Communities>();
+			let _ = CommunityAdminOrigin::<Test>::clear(u32::MAX, None);
+
+			MigrateAdminOriginsToV3::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				CommunityAdminOrigin::<Test>::get(COMMUNITY_A),
+				Some(*OriginForCommunityA::get())
+			);
+			assert_eq!(
+				CommunityAdminOrigin::<Test>::get(COMMUNITY_D),
+				Some(*OriginForCommunityD::get())
+			);
+			assert_eq!(Communities::on_chain_storage_version(), 3);
+		});
+	}
+
+	#[test]
+	fn migrating_counts_the_members_of_communities() {
+		use crate::{migrations::v5::MigrateMembersCountToV5, CommunityMembersCount, CommunityRankCount};
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::promote(
+				Into::<RuntimeOrigin>::into(*OriginForCommunityD::get()),
+				membership(COMMUNITY_D, 1)
 			));
+			let members_count = Communities::members_count(&COMMUNITY_D);
+
+			StorageVersion::new(4).put::<Communities>();
+			let _ = CommunityMembersCount::<Test>::clear(u32::MAX, None);
+			let _ = CommunityRankCount::<Test>::clear(u32::MAX, None);
+
+			MigrateMembersCountToV5::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Communities::members_count(&COMMUNITY_D), members_count);
+			assert_eq!(Communities::members_count(&COMMUNITY_C).members, 3);
+			assert_eq!(Communities::on_chain_storage_version(), 5);
+		});
+	}
 
+	#[test]
+	fn translating_a_tally_counts_its_votes_again() {
+		new_test_ext().execute_with(|| {
 			assert_ok!(Communities::vote(
-				RuntimeOrigin::signed(CHARLIE),
-				membership(COMMUNITY_C, 3),
+				RuntimeOrigin::signed(ALICE),
+				membership(COMMUNITY_C, 1),
 				2,
-				Vote::NativeBalance(true, 15)
+				Vote::NativeBalance(true, 10, Conviction::Locked1x)
+			));
+			assert_ok!(Communities::vote(
+				RuntimeOrigin::signed(BOB),
+				membership(COMMUNITY_C, 2),
+				2,
+				Vote::NativeBalance(false, 5, Conviction::Locked2x)
 			));
 
-			tick_blocks(6);
-
-			assert_ok!(Communities::unlock(RuntimeOrigin::signed(BOB), 1));
+			// A tally as stored while weights were a `u32`, saturated
+			let saturated = OldTally::<Test>::decode(&mut &[0xff; 12][..]).expect("ayes, nays and bare ayes; qed");
 
-			assert_ok!(Communities::unlock(RuntimeOrigin::signed(CHARLIE), 2));
+			// The voting power is snapshotted again as of the migration
+			let tally = Tally {
+				snapshot_at: Some(System::block_number()),
+				..Referenda::as_ongoing(2).expect("the poll was initiated; qed").0
+			};
+			assert_eq!(translate_tally::<Test>(2, &COMMUNITY_C.into(), saturated), (tally, 2));
 		});
 	}
 }
@@ -175,6 +175,14 @@ mod member_rank {
 				Communities::promote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_1).expect("can promote");
 				assert_ok!(Communities::demote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_1));
 				assert_eq!(Communities::member_rank(&COMMUNITY, &MEMBERSHIP_1), 1.into());
+				System::assert_last_event(
+					crate::Event::<Test>::MembershipRankUpdated {
+						membership_id: MEMBERSHIP_1,
+						old_rank: 2.into(),
+						new_rank: 1.into(),
+					}
+					.into(),
+				);
 			});
 		}
 
@@ -187,4 +195,577 @@ mod member_rank {
 			});
 		}
 	}
+
+	mod set_member_rank {
+		use super::*;
+		use crate::types::RankBounds;
+
+		#[test]
+		fn fails_when_caller_not_a_privleged_origin() {
+			new_test_ext(&[COMMUNITY_MEMBER_1], &[MEMBERSHIP_1]).execute_with(|| {
+				assert_noop!(
+					Communities::set_member_rank(Root.into(), MEMBERSHIP_1, 5.into()),
+					BadOrigin
+				);
+				assert_noop!(
+					Communities::set_rank_bounds(Root.into(), RankBounds::default()),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn it_works() {
+			new_test_ext(&[COMMUNITY_MEMBER_1], &[MEMBERSHIP_1]).execute_with(|| {
+				assert_ok!(Communities::set_member_rank(
+					COMMUNITY_ORIGIN.into(),
+					MEMBERSHIP_1,
+					5.into()
+				));
+				assert_eq!(Communities::member_rank(&COMMUNITY, &MEMBERSHIP_1), 5.into());
+				System::assert_last_event(
+					crate::Event::<Test>::MembershipRankUpdated {
+						membership_id: MEMBERSHIP_1,
+						old_rank: 0.into(),
+						new_rank: 5.into(),
+					}
+					.into(),
+				);
+			});
+		}
+
+		#[test]
+		fn fails_with_invalid_bounds() {
+			new_test_ext(&[COMMUNITY_MEMBER_1], &[MEMBERSHIP_1]).execute_with(|| {
+				assert_noop!(
+					Communities::set_rank_bounds(
+						COMMUNITY_ORIGIN.into(),
+						RankBounds {
+							min: Some(3.into()),
+							max: Some(2.into()),
+						}
+					),
+					Error::InvalidRankBounds
+				);
+			});
+		}
+
+		#[test]
+		fn ranks_stay_within_bounds() {
+			new_test_ext(&[COMMUNITY_MEMBER_1], &[MEMBERSHIP_1]).execute_with(|| {
+				assert_ok!(Communities::set_rank_bounds(
+					COMMUNITY_ORIGIN.into(),
+					RankBounds {
+						min: Some(1.into()),
+						max: Some(3.into()),
+					}
+				));
+
+				assert_noop!(
+					Communities::set_member_rank(COMMUNITY_ORIGIN.into(), MEMBERSHIP_1, 4.into()),
+					Error::RankOutOfBounds
+				);
+				assert_ok!(Communities::set_member_rank(
+					COMMUNITY_ORIGIN.into(),
+					MEMBERSHIP_1,
+					3.into()
+				));
+				assert_noop!(
+					Communities::promote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_1),
+					Error::RankOutOfBounds
+				);
+				assert_ok!(Communities::set_member_rank(
+					COMMUNITY_ORIGIN.into(),
+					MEMBERSHIP_1,
+					1.into()
+				));
+				assert_noop!(
+					Communities::demote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_1),
+					Error::RankOutOfBounds
+				);
+			});
+		}
+	}
+}
+
+mod membership_applications {
+	use super::*;
+	use crate::{DecisionMethod, HoldReason};
+	use frame_support::traits::fungible::{Inspect, InspectHold};
+	use sp_runtime::BoundedVec;
+
+	const APPLICANT: AccountId = COMMUNITY_MEMBER_2;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = TestEnvBuilder::new()
+			.with_balances(&[(APPLICANT, 10)])
+			.add_community(
+				COMMUNITY,
+				DecisionMethod::Membership,
+				&[COMMUNITY_MEMBER_1],
+				&[MEMBERSHIP_1, MEMBERSHIP_2],
+				None,
+			)
+			.build();
+
+		t.execute_with(|| {
+			assert_ok!(Communities::apply_for_membership(
+				RuntimeOrigin::signed(APPLICANT),
+				COMMUNITY,
+				BoundedVec::truncate_from(b"let me in".to_vec())
+			));
+		});
+
+		t
+	}
+
+	fn deposit_of(who: &AccountId) -> Balance {
+		Balances::balance_on_hold(&HoldReason::MembershipApplication.into(), who)
+	}
+
+	#[test]
+	fn applying_holds_a_deposit() {
+		new_test_ext().execute_with(|| {
+			System::assert_has_event(
+				crate::Event::<Test>::MembershipApplied {
+					id: COMMUNITY,
+					who: APPLICANT,
+				}
+				.into(),
+			);
+			assert_eq!(deposit_of(&APPLICANT), 5);
+		});
+	}
+
+	#[test]
+	fn fails_if_already_a_member_or_applied() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::apply_for_membership(
+					RuntimeOrigin::signed(COMMUNITY_MEMBER_1),
+					COMMUNITY,
+					BoundedVec::new()
+				),
+				Error::AlreadyAMember
+			);
+			assert_noop!(
+				Communities::apply_for_membership(RuntimeOrigin::signed(APPLICANT), COMMUNITY, BoundedVec::new()),
+				Error::AlreadyApplied
+			);
+		});
+	}
+
+	#[test]
+	fn fails_when_caller_not_a_privileged_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::approve_application(RuntimeOrigin::signed(COMMUNITY_MEMBER_1), APPLICANT),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Communities::reject_application(Root.into(), APPLICANT),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn approving_adds_the_applicant_as_member() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::approve_application(COMMUNITY_ORIGIN.into(), APPLICANT));
+
+			System::assert_has_event(
+				crate::Event::<Test>::MembershipApplicationApproved {
+					id: COMMUNITY,
+					who: APPLICANT,
+				}
+				.into(),
+			);
+			assert!(Communities::is_member(&COMMUNITY, &APPLICANT));
+			assert_eq!(deposit_of(&APPLICANT), 0);
+			assert_noop!(
+				Communities::approve_application(COMMUNITY_ORIGIN.into(), APPLICANT),
+				Error::ApplicationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn rejecting_transfers_the_deposit_to_the_community() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::reject_application(COMMUNITY_ORIGIN.into(), APPLICANT));
+
+			System::assert_has_event(
+				crate::Event::<Test>::MembershipApplicationRejected {
+					id: COMMUNITY,
+					who: APPLICANT,
+				}
+				.into(),
+			);
+			assert!(!Communities::is_member(&COMMUNITY, &APPLICANT));
+			assert_eq!(deposit_of(&APPLICANT), 0);
+			assert_eq!(Balances::balance(&APPLICANT), 5);
+			assert_eq!(Balances::balance(&Communities::community_account(&COMMUNITY)), 5);
+		});
+	}
+
+	#[test]
+	fn expired_applications_can_only_be_removed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::remove_expired_application(
+					RuntimeOrigin::signed(COMMUNITY_MEMBER_1),
+					COMMUNITY,
+					APPLICANT
+				),
+				Error::ApplicationNotExpired
+			);
+
+			// Applications expire after `ApplicationPeriod`
+			System::set_block_number(12);
+
+			assert_noop!(
+				Communities::approve_application(COMMUNITY_ORIGIN.into(), APPLICANT),
+				Error::ApplicationExpired
+			);
+			assert_ok!(Communities::remove_expired_application(
+				RuntimeOrigin::signed(COMMUNITY_MEMBER_1),
+				COMMUNITY,
+				APPLICANT
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::MembershipApplicationExpired {
+					id: COMMUNITY,
+					who: APPLICANT,
+				}
+				.into(),
+			);
+			assert_eq!(deposit_of(&APPLICANT), 0);
+			assert_eq!(Balances::balance(&APPLICANT), 10);
+		});
+	}
+}
+
+mod membership_invitations {
+	use super::*;
+	use parity_scale_codec::Encode;
+	use sp_core::{sr25519, Pair};
+	use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+
+	const INVITEE: AccountId = COMMUNITY_MEMBER_2;
+
+	fn invitation_pair() -> sr25519::Pair {
+		sr25519::Pair::from_seed(&[7; 32])
+	}
+
+	fn invitation_key() -> AccountId {
+		MultiSigner::from(invitation_pair().public()).into_account()
+	}
+
+	fn sign_claim(pair: &sr25519::Pair, who: &AccountId) -> MultiSignature {
+		pair.sign(&(COMMUNITY, who).encode()).into()
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = super::new_test_ext(&[], &[MEMBERSHIP_1, MEMBERSHIP_2]);
+		t.execute_with(|| {
+			assert_ok!(Communities::invite_member(COMMUNITY_ORIGIN.into(), invitation_key()));
+		});
+		t
+	}
+
+	#[test]
+	fn fails_when_caller_not_a_privileged_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::invite_member(RuntimeOrigin::signed(INVITEE), invitation_key()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Communities::revoke_invitation(Root.into(), invitation_key()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn invited_memberships_are_set_aside() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::invite_member(COMMUNITY_ORIGIN.into(), invitation_key()),
+				Error::InvitationAlreadyExists
+			);
+
+			// Only one membership is left to be given out
+			assert_ok!(Communities::add_member(COMMUNITY_ORIGIN.into(), COMMUNITY_MEMBER_1));
+			assert_noop!(
+				Communities::add_member(COMMUNITY_ORIGIN.into(), COMMUNITY_NON_MEMBER),
+				Error::CommunityAtCapacity
+			);
+		});
+	}
+
+	#[test]
+	fn fails_when_signature_is_invalid() {
+		new_test_ext().execute_with(|| {
+			// Signed by another key
+			let other_pair = sr25519::Pair::from_seed(&[8; 32]);
+			assert_noop!(
+				Communities::claim_invitation(
+					RuntimeOrigin::signed(INVITEE),
+					COMMUNITY,
+					invitation_key(),
+					sign_claim(&other_pair, &INVITEE)
+				),
+				Error::InvalidInvitationSignature
+			);
+
+			// A signature seen in a claim can't be used by another account
+			assert_noop!(
+				Communities::claim_invitation(
+					RuntimeOrigin::signed(COMMUNITY_NON_MEMBER),
+					COMMUNITY,
+					invitation_key(),
+					sign_claim(&invitation_pair(), &INVITEE)
+				),
+				Error::InvalidInvitationSignature
+			);
+		});
+	}
+
+	#[test]
+	fn fails_when_caller_is_already_a_member() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::add_member(COMMUNITY_ORIGIN.into(), COMMUNITY_MEMBER_1));
+
+			assert_noop!(
+				Communities::claim_invitation(
+					RuntimeOrigin::signed(COMMUNITY_MEMBER_1),
+					COMMUNITY,
+					invitation_key(),
+					sign_claim(&invitation_pair(), &COMMUNITY_MEMBER_1)
+				),
+				Error::AlreadyAMember
+			);
+		});
+	}
+
+	#[test]
+	fn claiming_gives_the_invited_membership() {
+		new_test_ext().execute_with(|| {
+			let membership_id = crate::MembershipInvitations::<Test>::get(COMMUNITY, invitation_key())
+				.expect("the invitation was created; qed");
+
+			assert_noop!(
+				Communities::claim_invitation(
+					RuntimeOrigin::signed(INVITEE),
+					COMMUNITY,
+					INVITEE,
+					sign_claim(&invitation_pair(), &INVITEE)
+				),
+				Error::InvitationNotFound
+			);
+
+			assert_ok!(Communities::claim_invitation(
+				RuntimeOrigin::signed(INVITEE),
+				COMMUNITY,
+				invitation_key(),
+				sign_claim(&invitation_pair(), &INVITEE)
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::InvitationClaimed {
+					id: COMMUNITY,
+					who: INVITEE,
+					membership_id,
+				}
+				.into(),
+			);
+			assert_eq!(Communities::get_memberships(COMMUNITY, &INVITEE), vec![membership_id]);
+
+			// Invitations can only be claimed once
+			assert_noop!(
+				Communities::claim_invitation(
+					RuntimeOrigin::signed(COMMUNITY_MEMBER_1),
+					COMMUNITY,
+					invitation_key(),
+					sign_claim(&invitation_pair(), &COMMUNITY_MEMBER_1)
+				),
+				Error::InvitationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn revoking_returns_the_membership_to_the_pool() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::revoke_invitation(
+				COMMUNITY_ORIGIN.into(),
+				invitation_key()
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::InvitationRevoked {
+					id: COMMUNITY,
+					invitation_key: invitation_key(),
+				}
+				.into(),
+			);
+			assert_noop!(
+				Communities::claim_invitation(
+					RuntimeOrigin::signed(INVITEE),
+					COMMUNITY,
+					invitation_key(),
+					sign_claim(&invitation_pair(), &INVITEE)
+				),
+				Error::InvitationNotFound
+			);
+
+			assert_ok!(Communities::add_member(COMMUNITY_ORIGIN.into(), COMMUNITY_MEMBER_1));
+			assert_ok!(Communities::add_member(COMMUNITY_ORIGIN.into(), INVITEE));
+		});
+	}
+}
+
+mod batch_members {
+	use super::*;
+	use sp_runtime::BoundedVec;
+
+	const COMMUNITY_MEMBER_3: AccountId = AccountId::new([3; 32]);
+	const MEMBERSHIP_3: MembershipId = 3;
+
+	#[test]
+	fn add_members_fails_when_community_lacks_memberships() {
+		new_test_ext(&[], &[MEMBERSHIP_1, MEMBERSHIP_2]).execute_with(|| {
+			assert_noop!(
+				Communities::add_members(
+					COMMUNITY_ORIGIN.into(),
+					BoundedVec::truncate_from(vec![COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2, COMMUNITY_MEMBER_3])
+				),
+				Error::CommunityAtCapacity
+			);
+		});
+	}
+
+	#[test]
+	fn add_members_works() {
+		new_test_ext(&[], &[MEMBERSHIP_1, MEMBERSHIP_2, MEMBERSHIP_3]).execute_with(|| {
+			assert_ok!(Communities::add_members(
+				COMMUNITY_ORIGIN.into(),
+				BoundedVec::truncate_from(vec![COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2, COMMUNITY_MEMBER_3])
+			));
+
+			assert!(Communities::is_member(&COMMUNITY, &COMMUNITY_MEMBER_1));
+			assert!(Communities::is_member(&COMMUNITY, &COMMUNITY_MEMBER_2));
+			assert!(Communities::is_member(&COMMUNITY, &COMMUNITY_MEMBER_3));
+		});
+	}
+
+	#[test]
+	fn remove_members_fails_when_not_a_community_member() {
+		new_test_ext(&[COMMUNITY_MEMBER_1], &[MEMBERSHIP_1]).execute_with(|| {
+			assert_noop!(
+				Communities::remove_members(
+					COMMUNITY_ORIGIN.into(),
+					BoundedVec::truncate_from(vec![
+						(COMMUNITY_MEMBER_1, MEMBERSHIP_1),
+						(COMMUNITY_NON_MEMBER, MEMBERSHIP_2)
+					])
+				),
+				Error::NotAMember
+			);
+		});
+	}
+
+	#[test]
+	fn remove_members_works() {
+		new_test_ext(
+			&[COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2, COMMUNITY_MEMBER_3],
+			&[MEMBERSHIP_1, MEMBERSHIP_2, MEMBERSHIP_3],
+		)
+		.execute_with(|| {
+			let members = [COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2].map(|who| {
+				let membership_id = Communities::get_memberships(COMMUNITY, &who)[0];
+				(who, membership_id)
+			});
+
+			assert_ok!(Communities::remove_members(
+				COMMUNITY_ORIGIN.into(),
+				BoundedVec::truncate_from(members.to_vec())
+			));
+
+			assert!(!Communities::is_member(&COMMUNITY, &COMMUNITY_MEMBER_1));
+			assert!(!Communities::is_member(&COMMUNITY, &COMMUNITY_MEMBER_2));
+			assert!(Communities::is_member(&COMMUNITY, &COMMUNITY_MEMBER_3));
+		});
+	}
+
+	#[test]
+	fn set_ranks_works() {
+		new_test_ext(&[COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2], &[MEMBERSHIP_1, MEMBERSHIP_2]).execute_with(|| {
+			assert_noop!(
+				Communities::set_ranks(
+					COMMUNITY_ORIGIN.into(),
+					BoundedVec::truncate_from(vec![(MEMBERSHIP_1, 2.into()), (MEMBERSHIP_3, 1.into())])
+				),
+				Error::NotAMember
+			);
+
+			assert_ok!(Communities::set_ranks(
+				COMMUNITY_ORIGIN.into(),
+				BoundedVec::truncate_from(vec![(MEMBERSHIP_1, 2.into()), (MEMBERSHIP_2, 1.into())])
+			));
+
+			assert_eq!(Communities::member_rank(&COMMUNITY, &MEMBERSHIP_1), 2.into());
+			assert_eq!(Communities::member_rank(&COMMUNITY, &MEMBERSHIP_2), 1.into());
+		});
+	}
+
+	#[test]
+	fn members_and_their_ranks_are_counted() {
+		use crate::{CommunityRankCount, MembersCount};
+		use fc_traits_memberships::GenericRank;
+
+		new_test_ext(&[], &[MEMBERSHIP_1, MEMBERSHIP_2, MEMBERSHIP_3]).execute_with(|| {
+			assert_ok!(Communities::add_members(
+				COMMUNITY_ORIGIN.into(),
+				BoundedVec::truncate_from(vec![COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2, COMMUNITY_MEMBER_3])
+			));
+			assert_eq!(
+				Communities::members_count(&COMMUNITY),
+				MembersCount { members: 3, ranks: 0 }
+			);
+			assert_eq!(CommunityRankCount::<Test>::get(COMMUNITY, GenericRank::from(0)), 3);
+
+			assert_ok!(Communities::set_ranks(
+				COMMUNITY_ORIGIN.into(),
+				BoundedVec::truncate_from(vec![(MEMBERSHIP_1, 2.into()), (MEMBERSHIP_2, 1.into())])
+			));
+			assert_eq!(
+				Communities::members_count(&COMMUNITY),
+				MembersCount { members: 3, ranks: 3 }
+			);
+			for rank in [0, 1, 2] {
+				assert_eq!(CommunityRankCount::<Test>::get(COMMUNITY, GenericRank::from(rank)), 1);
+			}
+
+			let who = [COMMUNITY_MEMBER_1, COMMUNITY_MEMBER_2, COMMUNITY_MEMBER_3]
+				.into_iter()
+				.find(|who| Communities::get_memberships(COMMUNITY, who) == vec![MEMBERSHIP_1])
+				.expect("a member holds the first membership; qed");
+			assert_ok!(Communities::remove_members(
+				COMMUNITY_ORIGIN.into(),
+				BoundedVec::truncate_from(vec![(who, MEMBERSHIP_1)])
+			));
+			assert_eq!(
+				Communities::members_count(&COMMUNITY),
+				MembersCount { members: 2, ranks: 1 }
+			);
+			assert!(!CommunityRankCount::<Test>::contains_key(
+				COMMUNITY,
+				GenericRank::from(2)
+			));
+		});
+	}
 }
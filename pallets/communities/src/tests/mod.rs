@@ -7,7 +7,9 @@ use helpers::*;
 
 type Error = crate::Error<Test>;
 
+mod assets;
 mod governance;
 mod membership;
 mod registry;
+mod treasury;
 mod weights;
@@ -1,7 +1,11 @@
 use super::*;
-use crate::{types::CommunityInfo, Event, Info};
-use frame_support::assert_noop;
+use crate::{
+	types::{CommunityInfo, CommunityMetadata, CommunityState, Vote},
+	Event, Info,
+};
+use frame_support::{assert_noop, traits::ConstU32, BoundedVec};
 use frame_system::RawOrigin::Root;
+use sp_runtime::DispatchError;
 
 mod create {
 	use super::*;
@@ -74,3 +78,768 @@ mod create {
 		});
 	}
 }
+
+mod set_metadata {
+	use super::*;
+
+	fn metadata() -> CommunityMetadata<<Test as frame_system::Config>::Hash> {
+		CommunityMetadata {
+			name: BoundedVec::truncate_from(b"Virto".to_vec()),
+			description: BoundedVec::truncate_from(b"A community of communities".to_vec()),
+			main_url: BoundedVec::truncate_from(b"https://virto.network".to_vec()),
+			logo: None,
+		}
+	}
+
+	#[test]
+	fn fails_when_caller_not_admin_origin() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::set_metadata(Root.into(), metadata()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Communities::set_metadata(RuntimeOrigin::signed(AccountId::new([1; 32])), metadata()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_eq!(Communities::metadata(&COMMUNITY), None);
+
+			assert_ok!(Communities::set_metadata(COMMUNITY_ORIGIN.into(), metadata()));
+
+			assert_eq!(Communities::metadata(&COMMUNITY), Some(metadata()));
+			System::assert_has_event(
+				Event::MetadataSet {
+					id: COMMUNITY,
+					name: metadata().name,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn replaces_previous_metadata() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::set_metadata(COMMUNITY_ORIGIN.into(), metadata()));
+
+			let new_metadata = CommunityMetadata {
+				name: BoundedVec::truncate_from(b"Kippu".to_vec()),
+				..metadata()
+			};
+			assert_ok!(Communities::set_metadata(COMMUNITY_ORIGIN.into(), new_metadata.clone()));
+
+			assert_eq!(Communities::metadata(&COMMUNITY), Some(new_metadata));
+		});
+	}
+}
+
+mod clear_metadata {
+	use super::*;
+
+	#[test]
+	fn fails_if_no_metadata_is_set() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::clear_metadata(COMMUNITY_ORIGIN.into()),
+				Error::NoMetadataSet
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::set_metadata(
+				COMMUNITY_ORIGIN.into(),
+				CommunityMetadata {
+					name: BoundedVec::truncate_from(b"Virto".to_vec()),
+					..Default::default()
+				}
+			));
+
+			assert_ok!(Communities::clear_metadata(COMMUNITY_ORIGIN.into()));
+
+			assert_eq!(Communities::metadata(&COMMUNITY), None);
+			System::assert_has_event(Event::MetadataCleared { id: COMMUNITY }.into());
+		});
+	}
+}
+
+mod block {
+	use super::*;
+
+	const ALICE: AccountId = AccountId::new([1; 32]);
+
+	fn reason() -> BoundedVec<u8, ConstU32<256>> {
+		BoundedVec::truncate_from(b"Violated the network norms".to_vec())
+	}
+
+	#[test]
+	fn fails_when_caller_not_block_origin() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::block(COMMUNITY_ORIGIN.into(), COMMUNITY, reason()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_community_does_not_exist() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::block(Root.into(), COMMUNITY + 1, reason()),
+				Error::CommunityDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_already_blocked() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::block(Root.into(), COMMUNITY, reason()));
+			assert_noop!(
+				Communities::block(Root.into(), COMMUNITY, reason()),
+				Error::AlreadyBlocked
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::block(Root.into(), COMMUNITY, reason()));
+
+			assert!(Info::<Test>::get(COMMUNITY).is_some_and(|c| c.state == CommunityState::Blocked));
+			System::assert_last_event(
+				Event::CommunityBlocked {
+					id: COMMUNITY,
+					reason: reason(),
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn blocked_community_members_cannot_vote() {
+		new_test_ext(&[ALICE], &[1]).execute_with(|| {
+			assert_ok!(Communities::block(Root.into(), COMMUNITY, reason()));
+
+			assert_noop!(
+				Communities::vote(RuntimeOrigin::signed(ALICE), 1, 0, Vote::Standard(true)),
+				Error::CommunityBlocked
+			);
+		});
+	}
+}
+
+mod unblock {
+	use super::*;
+
+	#[test]
+	fn fails_if_not_blocked() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(Communities::unblock(Root.into(), COMMUNITY), Error::NotBlocked);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::block(
+				Root.into(),
+				COMMUNITY,
+				BoundedVec::truncate_from(b"Violated the network norms".to_vec())
+			));
+			assert_ok!(Communities::unblock(Root.into(), COMMUNITY));
+
+			assert!(Info::<Test>::get(COMMUNITY).is_some_and(|c| c.state == CommunityState::Active));
+			System::assert_last_event(Event::CommunityUnblocked { id: COMMUNITY }.into());
+		});
+	}
+}
+
+mod destroy {
+	use super::*;
+
+	#[test]
+	fn fails_when_caller_not_admin_or_block_origin() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::start_destroy(RuntimeOrigin::signed(AccountId::new([1; 32])), COMMUNITY),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY + 1),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn start_destroy_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY));
+
+			assert!(Info::<Test>::get(COMMUNITY).is_some_and(|c| c.state == CommunityState::Destroying));
+			System::assert_last_event(Event::DestructionStarted { id: COMMUNITY }.into());
+
+			assert_noop!(
+				Communities::start_destroy(Root.into(), COMMUNITY),
+				Error::CommunityDestroying
+			);
+		});
+	}
+
+	#[test]
+	fn blocked_community_can_be_destroyed_by_block_origin() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::block(
+				Root.into(),
+				COMMUNITY,
+				BoundedVec::truncate_from(b"Violated the network norms".to_vec())
+			));
+			assert_noop!(
+				Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Communities::start_destroy(Root.into(), COMMUNITY));
+		});
+	}
+
+	#[test]
+	fn community_cannot_be_managed_while_destroying() {
+		const MEMBER: AccountId = AccountId::new([1; 32]);
+		const MEMBERSHIP: MembershipId = 1;
+
+		new_test_ext(&[MEMBER], &[MEMBERSHIP]).execute_with(|| {
+			// The community account is a valid admin origin regardless of the
+			// state of the community, so the state is checked by the calls
+			let origin = || RuntimeOrigin::signed(Communities::community_account(&COMMUNITY));
+			assert_ok!(Communities::promote(origin(), MEMBERSHIP));
+
+			assert_ok!(Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY));
+
+			assert_noop!(
+				Communities::set_metadata(origin(), Default::default()),
+				Error::CommunityDestroying
+			);
+			assert_noop!(Communities::clear_metadata(origin()), Error::CommunityDestroying);
+			assert_noop!(
+				Communities::remove_member(origin(), MEMBER, MEMBERSHIP),
+				Error::CommunityDestroying
+			);
+			assert_noop!(Communities::promote(origin(), MEMBERSHIP), Error::CommunityDestroying);
+			assert_noop!(Communities::demote(origin(), MEMBERSHIP), Error::CommunityDestroying);
+			assert_noop!(
+				Communities::set_member_rank(origin(), MEMBERSHIP, 2.into()),
+				Error::CommunityDestroying
+			);
+			assert_noop!(
+				Communities::set_ranks(origin(), BoundedVec::truncate_from(vec![(MEMBERSHIP, 2.into())])),
+				Error::CommunityDestroying
+			);
+			assert_noop!(
+				Communities::set_rank_bounds(origin(), Default::default()),
+				Error::CommunityDestroying
+			);
+			assert_noop!(
+				Communities::set_decision_method(origin(), COMMUNITY, None, crate::DecisionMethod::Rank),
+				Error::CommunityDestroying
+			);
+		});
+	}
+
+	#[test]
+	fn finish_destroy_fails_if_not_started() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::finish_destroy(Root.into(), COMMUNITY),
+				Error::NotBeingDestroyed
+			);
+		});
+	}
+
+	#[test]
+	fn finish_destroy_fails_when_caller_not_block_origin() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY));
+			assert_noop!(
+				Communities::finish_destroy(RuntimeOrigin::signed(AccountId::new([1; 32])), COMMUNITY),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn destroy_records_releases_application_deposits() {
+		use crate::HoldReason;
+		use frame_support::traits::fungible::InspectHold;
+
+		const APPLICANT: AccountId = AccountId::new([2; 32]);
+
+		TestEnvBuilder::new()
+			.with_balances(&[(APPLICANT, 10)])
+			.add_community(COMMUNITY, crate::DecisionMethod::Membership, &[], &[], None)
+			.build()
+			.execute_with(|| {
+				assert_ok!(Communities::apply_for_membership(
+					RuntimeOrigin::signed(APPLICANT),
+					COMMUNITY,
+					BoundedVec::new()
+				));
+				assert_noop!(
+					Communities::destroy_records(RuntimeOrigin::signed(APPLICANT), COMMUNITY),
+					Error::NotBeingDestroyed
+				);
+				assert_ok!(Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY));
+
+				assert_noop!(
+					Communities::finish_destroy(Root.into(), COMMUNITY),
+					Error::RecordsRemaining
+				);
+
+				assert_ok!(Communities::destroy_records(
+					RuntimeOrigin::signed(APPLICANT),
+					COMMUNITY
+				));
+				System::assert_last_event(
+					Event::RecordsDestroyed {
+						id: COMMUNITY,
+						records_destroyed: 1,
+					}
+					.into(),
+				);
+				assert_eq!(
+					Balances::balance_on_hold(&HoldReason::MembershipApplication.into(), &APPLICANT),
+					0
+				);
+
+				assert_ok!(Communities::finish_destroy(Root.into(), COMMUNITY));
+			});
+	}
+
+	#[test]
+	fn finish_destroy_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::set_metadata(
+				COMMUNITY_ORIGIN.into(),
+				CommunityMetadata {
+					name: BoundedVec::truncate_from(b"Test Community".to_vec()),
+					..Default::default()
+				}
+			));
+			assert_ok!(Communities::start_destroy(COMMUNITY_ORIGIN.into(), COMMUNITY));
+			assert_ok!(Communities::finish_destroy(Root.into(), COMMUNITY));
+
+			assert!(!Communities::community_exists(&COMMUNITY));
+			assert_eq!(Communities::metadata(&COMMUNITY), None);
+			assert_eq!(crate::CommunityAdminOrigin::<Test>::get(COMMUNITY), None);
+			System::assert_last_event(Event::CommunityDestroyed { id: COMMUNITY }.into());
+
+			// The admin origin is free to create a new community
+			assert_ok!(Communities::create(Root.into(), COMMUNITY_ORIGIN, COMMUNITY));
+		});
+	}
+}
+
+mod dispatch_as_origin {
+	use super::*;
+	use crate::{origin::Subset, Call};
+
+	fn set_metadata_call() -> Box<RuntimeCall> {
+		Box::new(
+			Call::<Test>::set_metadata {
+				metadata: CommunityMetadata {
+					name: BoundedVec::truncate_from(b"Test Community".to_vec()),
+					..Default::default()
+				},
+			}
+			.into(),
+		)
+	}
+
+	#[test]
+	fn fails_when_caller_not_admin_origin() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_noop!(
+				Communities::dispatch_as_origin(Root.into(), None, set_metadata_call()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			assert_ok!(Communities::dispatch_as_origin(
+				COMMUNITY_ORIGIN.into(),
+				None,
+				set_metadata_call()
+			));
+			assert!(Communities::metadata(&COMMUNITY).is_some());
+		});
+	}
+
+	#[test]
+	fn admin_calls_fail_with_a_subset() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			// A subset doesn't speak for the whole community
+			assert_noop!(
+				Communities::dispatch_as_origin(
+					COMMUNITY_ORIGIN.into(),
+					Some(Subset::Members { count: 2 }),
+					set_metadata_call()
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn fails_when_community_is_blocked() {
+		new_test_ext(&[], &[]).execute_with(|| {
+			Communities::force_state(&COMMUNITY, CommunityState::Blocked);
+			assert_noop!(
+				Communities::dispatch_as_origin(COMMUNITY_ORIGIN.into(), None, set_metadata_call()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod dispatch_as_subset {
+	use super::*;
+	use crate::origin::{EnsureCommunityFraction, EnsureCommunityMembers, EnsureCommunityRank, Subset};
+	use fc_traits_memberships::GenericRank;
+	use frame_support::{parameter_types, traits::EnsureOrigin};
+	use sp_runtime::{traits::Hash, Permill};
+	use virto_common::MembershipId;
+
+	const MEMBER_1: AccountId = AccountId::new([1; 32]);
+	const MEMBER_2: AccountId = AccountId::new([2; 32]);
+	const MEMBER_3: AccountId = AccountId::new([3; 32]);
+	const MEMBERSHIP_1: MembershipId = 1;
+	const MEMBERSHIP_2: MembershipId = 2;
+	const MEMBERSHIP_3: MembershipId = 3;
+
+	parameter_types! {
+		pub const TwoMembers: u32 = 2;
+		pub const Majority: Permill = Permill::from_percent(51);
+		pub RankTwo: GenericRank = 2.into();
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		super::new_test_ext(
+			&[MEMBER_1, MEMBER_2, MEMBER_3],
+			&[MEMBERSHIP_1, MEMBERSHIP_2, MEMBERSHIP_3],
+		)
+	}
+
+	fn remark_call() -> Box<RuntimeCall> {
+		Box::new(
+			frame_system::Call::<Test>::remark {
+				remark: b"Hello, world".to_vec(),
+			}
+			.into(),
+		)
+	}
+
+	fn call_hash(subset: &Subset<Test>) -> <Test as frame_system::Config>::Hash {
+		<Test as frame_system::Config>::Hashing::hash_of(&(subset, &remark_call()))
+	}
+
+	fn assert_dispatched(subset: &Subset<Test>) {
+		System::assert_last_event(
+			Event::SubsetCallDispatched {
+				id: COMMUNITY,
+				call_hash: call_hash(subset),
+			}
+			.into(),
+		);
+	}
+
+	fn assert_approved(subset: &Subset<Test>, membership_id: MembershipId, approvals: u32) {
+		System::assert_last_event(
+			Event::SubsetCallApproved {
+				id: COMMUNITY,
+				membership_id,
+				call_hash: call_hash(subset),
+				approvals,
+			}
+			.into(),
+		);
+	}
+
+	fn subset_origin(subset: Subset<Test>) -> RuntimeOrigin {
+		let mut origin = crate::Origin::<Test>::new(COMMUNITY);
+		origin.with_subset(subset);
+		origin.into()
+	}
+
+	#[test]
+	fn fails_if_not_a_member() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_2,
+					Subset::Members { count: 2 },
+					remark_call()
+				),
+				Error::NotAMember
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_membership_is_not_in_subset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_1,
+					Subset::Member(MEMBERSHIP_2),
+					remark_call()
+				),
+				Error::NotInSubset
+			);
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_1,
+					Subset::AtLeastRank(1.into()),
+					remark_call()
+				),
+				Error::NotInSubset
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_subset_is_empty() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_1,
+					Subset::Members { count: 0 },
+					remark_call()
+				),
+				Error::InvalidSubset
+			);
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_1,
+					Subset::Fraction(Permill::from_percent(0)),
+					remark_call()
+				),
+				Error::InvalidSubset
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_membership_expired() {
+		new_test_ext().execute_with(|| {
+			ExpiredMemberships::set(vec![(COMMUNITY, MEMBERSHIP_1)]);
+
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_1,
+					Subset::Members { count: 2 },
+					remark_call()
+				),
+				Error::MembershipExpired
+			);
+		});
+	}
+
+	#[test]
+	fn dispatches_once_enough_members_approve() {
+		new_test_ext().execute_with(|| {
+			let subset = Subset::Members { count: 2 };
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBERSHIP_1,
+				subset.clone(),
+				remark_call()
+			));
+			assert_approved(&subset, MEMBERSHIP_1, 1);
+
+			assert_noop!(
+				Communities::dispatch_as_subset(
+					RuntimeOrigin::signed(MEMBER_1),
+					MEMBERSHIP_1,
+					subset.clone(),
+					remark_call()
+				),
+				Error::AlreadyApproved
+			);
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_2),
+				MEMBERSHIP_2,
+				subset.clone(),
+				remark_call()
+			));
+			assert_dispatched(&subset);
+		});
+	}
+
+	#[test]
+	fn discards_approvals_once_they_expire() {
+		new_test_ext().execute_with(|| {
+			let subset = Subset::Members { count: 2 };
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBERSHIP_1,
+				subset.clone(),
+				remark_call()
+			));
+
+			// SubsetApprovalPeriod is 10 blocks
+			System::set_block_number(12);
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_2),
+				MEMBERSHIP_2,
+				subset.clone(),
+				remark_call()
+			));
+			assert_approved(&subset, MEMBERSHIP_2, 1);
+
+			// The expired approval was discarded, so it can be given again
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBERSHIP_1,
+				subset.clone(),
+				remark_call()
+			));
+			assert_dispatched(&subset);
+		});
+	}
+
+	#[test]
+	fn dispatches_once_a_fraction_of_members_approve() {
+		new_test_ext().execute_with(|| {
+			// 51% of 3 members requires 2 approvals
+			let subset = Subset::Fraction(Permill::from_percent(51));
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBERSHIP_1,
+				subset.clone(),
+				remark_call()
+			));
+			assert_approved(&subset, MEMBERSHIP_1, 1);
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_3),
+				MEMBERSHIP_3,
+				subset.clone(),
+				remark_call()
+			));
+			assert_dispatched(&subset);
+		});
+	}
+
+	#[test]
+	fn fraction_leaves_pruned_memberships_out() {
+		new_test_ext().execute_with(|| {
+			ExpiredMemberships::set(vec![(COMMUNITY, MEMBERSHIP_3)]);
+			assert_ok!(Communities::prune_expired_member(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBER_3,
+				MEMBERSHIP_3
+			));
+			// 50% of the 2 remaining members requires 1 approval
+			let subset = Subset::Fraction(Permill::from_percent(50));
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBERSHIP_1,
+				subset.clone(),
+				remark_call()
+			));
+			assert_dispatched(&subset);
+		});
+	}
+
+	#[test]
+	fn dispatches_once_a_majority_of_ranked_members_approve() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::promote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_1));
+			assert_ok!(Communities::promote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_2));
+			assert_ok!(Communities::promote(COMMUNITY_ORIGIN.into(), MEMBERSHIP_3));
+			let subset = Subset::AtLeastRank(1.into());
+
+			// A single member of the rank doesn't speak for the rest of them
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_1),
+				MEMBERSHIP_1,
+				subset.clone(),
+				remark_call()
+			));
+			assert_approved(&subset, MEMBERSHIP_1, 1);
+
+			assert_ok!(Communities::dispatch_as_subset(
+				RuntimeOrigin::signed(MEMBER_2),
+				MEMBERSHIP_2,
+				subset.clone(),
+				remark_call()
+			));
+			assert_dispatched(&subset);
+		});
+	}
+
+	#[test]
+	fn ensure_origin_adapters_work() {
+		new_test_ext().execute_with(|| {
+			type AtLeastTwoMembers = EnsureCommunityMembers<Test, TwoMembers>;
+			type MajorityOfMembers = EnsureCommunityFraction<Test, Majority>;
+			type AtLeastRankTwo = EnsureCommunityRank<Test, RankTwo>;
+
+			// The voice of the whole community is not a subset
+			assert!(AtLeastTwoMembers::try_origin(COMMUNITY_ORIGIN.into()).is_err());
+
+			assert_eq!(
+				AtLeastTwoMembers::try_origin(subset_origin(Subset::Members { count: 2 })).ok(),
+				Some(COMMUNITY)
+			);
+			assert!(AtLeastTwoMembers::try_origin(subset_origin(Subset::Members { count: 1 })).is_err());
+
+			assert_eq!(
+				MajorityOfMembers::try_origin(subset_origin(Subset::Fraction(Permill::from_percent(60)))).ok(),
+				Some(COMMUNITY)
+			);
+			// 2 out of 3 members
+			assert_eq!(
+				MajorityOfMembers::try_origin(subset_origin(Subset::Members { count: 2 })).ok(),
+				Some(COMMUNITY)
+			);
+			assert!(MajorityOfMembers::try_origin(subset_origin(Subset::Members { count: 1 })).is_err());
+
+			assert_eq!(
+				AtLeastRankTwo::try_origin(subset_origin(Subset::AtLeastRank(3.into()))).ok(),
+				Some(COMMUNITY)
+			);
+			assert!(AtLeastRankTwo::try_origin(subset_origin(Subset::AtLeastRank(1.into()))).is_err());
+
+			// Blocked communities can't dispatch as a subset
+			Communities::force_state(&COMMUNITY, CommunityState::Blocked);
+			assert!(AtLeastTwoMembers::try_origin(subset_origin(Subset::Members { count: 2 })).is_err());
+		});
+	}
+}
@@ -0,0 +1,444 @@
+use super::*;
+use crate::{
+	types::{CommunityState::Blocked, SpendAsset},
+	DecisionMethod, SpendInfo, Spends,
+};
+use frame_support::{
+	assert_noop, parameter_types,
+	traits::{fungible::Inspect, fungibles::Inspect as FunsInspect},
+};
+use pallet_referenda::{Curve, TrackInfo, TrackInfoOf};
+use sp_runtime::{str_array as s, traits::BadOrigin, Perbill};
+
+const ALICE: AccountId = AccountId::new([1; 32]);
+const BOB: AccountId = AccountId::new([2; 32]);
+const MEMBERSHIP: MembershipId = 1;
+const ASSET_ID: AssetId = 1;
+
+parameter_types! {
+	pub CommunityTrack: TrackInfoOf<Test> = TrackInfo {
+		name: s("Community"),
+		max_deciding: 1,
+		decision_deposit: 5,
+		prepare_period: 1,
+		decision_period: 5,
+		confirm_period: 1,
+		min_enactment_period: 1,
+		min_approval: Curve::LinearDecreasing {
+			length: Perbill::from_percent(100),
+			floor: Perbill::from_percent(50),
+			ceil: Perbill::from_percent(100),
+		},
+		min_support: Curve::LinearDecreasing {
+			length: Perbill::from_percent(100),
+			floor: Perbill::from_percent(0),
+			ceil: Perbill::from_percent(100),
+		},
+	};
+}
+
+fn community_origin() -> RuntimeOrigin {
+	COMMUNITY_ORIGIN.into()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let treasury = Communities::community_account(&COMMUNITY);
+	let mut t = TestEnvBuilder::new()
+		.with_balances(&[(treasury.clone(), 100)])
+		.add_community(
+			COMMUNITY,
+			DecisionMethod::Membership,
+			&[ALICE],
+			&[MEMBERSHIP],
+			Some(CommunityTrack::get()),
+		)
+		.add_asset(&ASSET_ID, &treasury, true, 1, None, Some(vec![(treasury.clone(), 100)]))
+		.build();
+
+	t.execute_with(|| {
+		for asset_kind in [SpendAsset::Native, SpendAsset::Asset(ASSET_ID)] {
+			assert_ok!(Communities::set_spend_limit(
+				community_origin(),
+				COMMUNITY.into(),
+				asset_kind,
+				Some(50)
+			));
+		}
+	});
+
+	t
+}
+
+mod spend {
+	use super::*;
+
+	#[test]
+	fn fails_if_not_dispatched_by_the_community() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::spend(RuntimeOrigin::signed(ALICE), SpendAsset::Native, 10, BOB, None),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_dispatched_on_behalf_of_the_community() {
+		new_test_ext().execute_with(|| {
+			let spend = Box::new(RuntimeCall::from(crate::Call::<Test>::spend {
+				asset_kind: SpendAsset::Native,
+				amount: 10,
+				beneficiary: BOB,
+				valid_from: None,
+			}));
+
+			// The admin of the community can't skip its polls
+			assert_noop!(
+				Communities::dispatch_as_origin(community_origin(), None, spend.clone()),
+				frame_system::Error::<Test>::CallFiltered
+			);
+			assert_noop!(
+				Communities::dispatch_as_origin(
+					RuntimeOrigin::signed(Communities::community_account(&COMMUNITY)),
+					None,
+					spend
+				),
+				frame_system::Error::<Test>::CallFiltered
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_exceeding_the_track_limit() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::spend(community_origin(), SpendAsset::Native, 51, BOB, None),
+				Error::SpendLimitExceeded
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_the_track_has_no_limit_for_the_asset() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::spend(community_origin(), SpendAsset::Asset(2), 10, BOB, None),
+				Error::SpendNotAllowed
+			);
+		});
+	}
+
+	#[test]
+	fn other_tracks_can_spend_within_their_limit() {
+		use crate::{origin::Subset, types::CommunityTrackId, Origin as CommunityOrigin};
+
+		new_test_ext().execute_with(|| {
+			let mut board_origin = CommunityOrigin::<Test>::new(COMMUNITY);
+			board_origin.with_subset(Subset::AtLeastRank(1.into()));
+			let board_origin = OriginCaller::Communities(board_origin);
+			let board_track = u32::for_community(COMMUNITY, 1);
+
+			// A subset of the community spends through the track it belongs to
+			assert_noop!(
+				Communities::spend(board_origin.clone().into(), SpendAsset::Native, 10, BOB, None),
+				BadOrigin
+			);
+			assert_ok!(Tracks::insert(
+				RuntimeOrigin::root(),
+				board_track,
+				CommunityTrack::get(),
+				board_origin.clone(),
+			));
+			assert_noop!(
+				Communities::spend(board_origin.clone().into(), SpendAsset::Native, 10, BOB, None),
+				Error::SpendNotAllowed
+			);
+
+			assert_ok!(Communities::set_spend_limit(
+				community_origin(),
+				board_track,
+				SpendAsset::Native,
+				Some(20)
+			));
+			assert_noop!(
+				Communities::spend(board_origin.clone().into(), SpendAsset::Native, 21, BOB, None),
+				Error::SpendLimitExceeded
+			);
+			assert_ok!(Communities::spend(
+				board_origin.into(),
+				SpendAsset::Native,
+				20,
+				BOB,
+				None
+			));
+			System::assert_has_event(
+				crate::Event::<Test>::SpendApproved {
+					id: COMMUNITY,
+					index: 0,
+					asset_kind: SpendAsset::Native,
+					amount: 20,
+					beneficiary: BOB,
+					valid_from: 1,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::spend(
+				community_origin(),
+				SpendAsset::Native,
+				50,
+				BOB,
+				Some(5)
+			));
+			assert_ok!(Communities::spend(
+				community_origin(),
+				SpendAsset::Asset(ASSET_ID),
+				10,
+				BOB,
+				None
+			));
+
+			System::assert_has_event(
+				crate::Event::<Test>::SpendApproved {
+					id: COMMUNITY,
+					index: 1,
+					asset_kind: SpendAsset::Asset(ASSET_ID),
+					amount: 10,
+					beneficiary: BOB,
+					valid_from: 1,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Spends::<Test>::get(COMMUNITY, 0),
+				Some(SpendInfo {
+					asset_kind: SpendAsset::Native,
+					amount: 50,
+					beneficiary: BOB,
+					valid_from: 5,
+					expire_at: 15,
+				})
+			);
+		});
+	}
+}
+
+mod payout {
+	use super::*;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = super::new_test_ext();
+
+		t.execute_with(|| {
+			assert_ok!(Communities::spend(
+				community_origin(),
+				SpendAsset::Native,
+				50,
+				BOB,
+				Some(5)
+			));
+			assert_ok!(Communities::spend(
+				community_origin(),
+				SpendAsset::Asset(ASSET_ID),
+				10,
+				BOB,
+				Some(5)
+			));
+		});
+
+		t
+	}
+
+	#[test]
+	fn fails_if_the_spend_is_not_valid_yet() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::EarlyPayout
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_the_spend_expired() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(15);
+			assert_noop!(
+				Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::SpendExpired
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_the_community_is_blocked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(15);
+			Communities::force_state(&COMMUNITY, Blocked);
+
+			assert_noop!(
+				Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::CommunityBlocked
+			);
+			assert_noop!(
+				Communities::remove_expired_spend(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::CommunityBlocked
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_the_spend_does_not_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 2),
+				Error::SpendNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn it_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(5);
+
+			assert_ok!(Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 0));
+			assert_ok!(Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 1));
+
+			System::assert_has_event(
+				crate::Event::<Test>::SpendPaid {
+					id: COMMUNITY,
+					index: 0,
+				}
+				.into(),
+			);
+			assert_eq!(Balances::balance(&BOB), 50);
+			assert_eq!(Assets::balance(ASSET_ID, &BOB), 10);
+			assert_eq!(Spends::<Test>::get(COMMUNITY, 0), None);
+
+			// A spend can only be paid out once
+			assert_noop!(
+				Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::SpendNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn voided_spends_are_not_paid_out() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(Communities::void_spend(RuntimeOrigin::signed(ALICE), 0), BadOrigin);
+			assert_ok!(Communities::void_spend(community_origin(), 0));
+			System::assert_has_event(
+				crate::Event::<Test>::SpendVoided {
+					id: COMMUNITY,
+					index: 0,
+				}
+				.into(),
+			);
+
+			System::set_block_number(5);
+			assert_noop!(
+				Communities::payout(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::SpendNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn expired_spends_can_be_removed_by_anyone() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::remove_expired_spend(RuntimeOrigin::signed(ALICE), COMMUNITY, 0),
+				Error::SpendNotExpired
+			);
+
+			System::set_block_number(15);
+			assert_ok!(Communities::remove_expired_spend(
+				RuntimeOrigin::signed(ALICE),
+				COMMUNITY,
+				0
+			));
+			System::assert_has_event(
+				crate::Event::<Test>::SpendExpired {
+					id: COMMUNITY,
+					index: 0,
+				}
+				.into(),
+			);
+			assert_eq!(Spends::<Test>::get(COMMUNITY, 0), None);
+		});
+	}
+}
+
+mod set_spend_limit {
+	use super::*;
+
+	#[test]
+	fn fails_if_the_track_belongs_to_another_community() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Communities::set_spend_limit(community_origin(), 2, SpendAsset::Native, Some(10)),
+				Error::InvalidTrack
+			);
+		});
+	}
+
+	#[test]
+	fn fails_if_not_dispatched_by_the_default_track() {
+		new_test_ext().execute_with(|| {
+			// The community account isn't the origin of any track
+			assert_noop!(
+				Communities::set_spend_limit(
+					RuntimeOrigin::signed(Communities::community_account(&COMMUNITY)),
+					COMMUNITY.into(),
+					SpendAsset::Native,
+					Some(100)
+				),
+				Error::InvalidTrack
+			);
+			assert_noop!(
+				Communities::dispatch_as_origin(
+					community_origin(),
+					None,
+					Box::new(RuntimeCall::from(crate::Call::<Test>::set_spend_limit {
+						track_id: COMMUNITY.into(),
+						asset_kind: SpendAsset::Native,
+						limit: Some(100),
+					}))
+				),
+				frame_system::Error::<Test>::CallFiltered
+			);
+		});
+	}
+
+	#[test]
+	fn removing_the_limit_prevents_spending() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Communities::set_spend_limit(
+				community_origin(),
+				COMMUNITY.into(),
+				SpendAsset::Native,
+				None
+			));
+			System::assert_has_event(
+				crate::Event::<Test>::SpendLimitSet {
+					track_id: COMMUNITY.into(),
+					asset_kind: SpendAsset::Native,
+					limit: None,
+				}
+				.into(),
+			);
+
+			assert_noop!(
+				Communities::spend(community_origin(), SpendAsset::Native, 10, BOB, None),
+				Error::SpendNotAllowed
+			);
+		});
+	}
+}
@@ -23,14 +23,56 @@ fn weights() {
 		("create", SubstrateWeight::<Test>::create()),
 		("set_admin_origin", SubstrateWeight::<Test>::set_admin_origin()),
 		("set_decision_method", SubstrateWeight::<Test>::set_decision_method()),
+		("set_metadata", SubstrateWeight::<Test>::set_metadata()),
+		("clear_metadata", SubstrateWeight::<Test>::clear_metadata()),
+		("block", SubstrateWeight::<Test>::block()),
+		("unblock", SubstrateWeight::<Test>::unblock()),
+		("start_destroy", SubstrateWeight::<Test>::start_destroy()),
+		("destroy_votes", SubstrateWeight::<Test>::destroy_votes(100)),
+		("destroy_records", SubstrateWeight::<Test>::destroy_records(100)),
+		("finish_destroy", SubstrateWeight::<Test>::finish_destroy()),
 		("add_member", SubstrateWeight::<Test>::add_member()),
-		("remove_member", SubstrateWeight::<Test>::remove_member()),
+		("remove_member", SubstrateWeight::<Test>::remove_member(64)),
 		("promote", SubstrateWeight::<Test>::promote()),
 		("demote", SubstrateWeight::<Test>::demote()),
-		("vote", SubstrateWeight::<Test>::vote()),
-		("remove_vote", SubstrateWeight::<Test>::remove_vote()),
+		("vote", SubstrateWeight::<Test>::vote(64)),
+		("remove_vote", SubstrateWeight::<Test>::remove_vote(64)),
 		("unlock", SubstrateWeight::<Test>::unlock()),
 		("dispatch_as_account", SubstrateWeight::<Test>::dispatch_as_account()),
+		("dispatch_as_origin", SubstrateWeight::<Test>::dispatch_as_origin()),
+		("delegate", SubstrateWeight::<Test>::delegate()),
+		("undelegate", SubstrateWeight::<Test>::undelegate()),
+		("dispatch_as_subset", SubstrateWeight::<Test>::dispatch_as_subset()),
+		("leave", SubstrateWeight::<Test>::leave(64)),
+		(
+			"prune_expired_member",
+			SubstrateWeight::<Test>::prune_expired_member(64),
+		),
+		("apply_for_membership", SubstrateWeight::<Test>::apply_for_membership()),
+		("approve_application", SubstrateWeight::<Test>::approve_application()),
+		("reject_application", SubstrateWeight::<Test>::reject_application()),
+		(
+			"remove_expired_application",
+			SubstrateWeight::<Test>::remove_expired_application(),
+		),
+		("invite_member", SubstrateWeight::<Test>::invite_member()),
+		("claim_invitation", SubstrateWeight::<Test>::claim_invitation()),
+		("revoke_invitation", SubstrateWeight::<Test>::revoke_invitation()),
+		("add_members", SubstrateWeight::<Test>::add_members(10)),
+		("remove_members", SubstrateWeight::<Test>::remove_members(10)),
+		("set_ranks", SubstrateWeight::<Test>::set_ranks(10)),
+		("set_member_rank", SubstrateWeight::<Test>::set_member_rank()),
+		("set_rank_bounds", SubstrateWeight::<Test>::set_rank_bounds()),
+		("spend", SubstrateWeight::<Test>::spend()),
+		("payout", SubstrateWeight::<Test>::payout()),
+		("void_spend", SubstrateWeight::<Test>::void_spend()),
+		("remove_expired_spend", SubstrateWeight::<Test>::remove_expired_spend()),
+		("set_spend_limit", SubstrateWeight::<Test>::set_spend_limit()),
+		("create_asset", SubstrateWeight::<Test>::create_asset()),
+		("set_asset_metadata", SubstrateWeight::<Test>::set_asset_metadata()),
+		("mint_asset", SubstrateWeight::<Test>::mint_asset()),
+		("burn_asset", SubstrateWeight::<Test>::burn_asset()),
+		("claim_asset", SubstrateWeight::<Test>::claim_asset()),
 	] {
 		println!("{function}: {weight:?}",);
 		println!(
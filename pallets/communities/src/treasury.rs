@@ -0,0 +1,109 @@
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		fungible::Mutate,
+		fungibles::Mutate as _,
+		tokens::Preservation::{Expendable, Preserve},
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Saturating;
+
+impl<T: Config> Pallet<T> {
+	/// Approves a spend out of the treasury of the community that owns the
+	/// track, as long as it doesn't exceed the limit set for the track and
+	/// asset.
+	pub(crate) fn do_spend(
+		track_id: &T::TrackId,
+		asset_kind: SpendAssetOf<T>,
+		amount: NativeBalanceOf<T>,
+		beneficiary: AccountIdOf<T>,
+		valid_from: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let community_id = track_id.community_id();
+		let limit = TrackSpendLimits::<T>::get(community_id, (*track_id, asset_kind.clone()))
+			.ok_or(Error::<T>::SpendNotAllowed)?;
+		ensure!(amount <= limit, Error::<T>::SpendLimitExceeded);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let valid_from = valid_from.unwrap_or(now);
+		let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+		ensure!(expire_at > now, Error::<T>::SpendExpired);
+
+		let index = SpendCount::<T>::mutate(community_id, |count| {
+			let index = *count;
+			count.saturating_inc();
+			index
+		});
+		Spends::<T>::insert(
+			community_id,
+			index,
+			SpendInfo {
+				asset_kind: asset_kind.clone(),
+				amount,
+				beneficiary: beneficiary.clone(),
+				valid_from,
+				expire_at,
+			},
+		);
+
+		Self::deposit_event(Event::SpendApproved {
+			id: community_id,
+			index,
+			asset_kind,
+			amount,
+			beneficiary,
+			valid_from,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_payout(community_id: &CommunityIdOf<T>, index: SpendIndex) -> DispatchResult {
+		let spend = Spends::<T>::get(community_id, index).ok_or(Error::<T>::SpendNotFound)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now >= spend.valid_from, Error::<T>::EarlyPayout);
+		ensure!(now < spend.expire_at, Error::<T>::SpendExpired);
+
+		let treasury = Self::community_account(community_id);
+		match spend.asset_kind {
+			SpendAsset::Native => T::Balances::transfer(&treasury, &spend.beneficiary, spend.amount, Preserve)?,
+			SpendAsset::Asset(asset_id) => {
+				T::Assets::transfer(asset_id, &treasury, &spend.beneficiary, spend.amount, Expendable)?
+			}
+		};
+		Spends::<T>::remove(community_id, index);
+
+		Self::deposit_event(Event::SpendPaid {
+			id: *community_id,
+			index,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_void_spend(community_id: &CommunityIdOf<T>, index: SpendIndex) -> DispatchResult {
+		Spends::<T>::take(community_id, index).ok_or(Error::<T>::SpendNotFound)?;
+
+		Self::deposit_event(Event::SpendVoided {
+			id: *community_id,
+			index,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_remove_expired_spend(community_id: &CommunityIdOf<T>, index: SpendIndex) -> DispatchResult {
+		let spend = Spends::<T>::get(community_id, index).ok_or(Error::<T>::SpendNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= spend.expire_at,
+			Error::<T>::SpendNotExpired
+		);
+
+		Spends::<T>::remove(community_id, index);
+
+		Self::deposit_event(Event::SpendExpired {
+			id: *community_id,
+			index,
+		});
+		Ok(())
+	}
+}
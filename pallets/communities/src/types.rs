@@ -1,13 +1,14 @@
-use crate::{CommunityDecisionMethod, Config};
-use fc_traits_memberships::{Inspect, Rank};
+use crate::{Config, Pallet};
+use fc_traits_memberships::GenericRank;
 use frame_support::pallet_prelude::*;
 use frame_support::traits::{
 	fungible::{self, Inspect as FunInspect},
 	fungibles::{self, Inspect as FunsInspect},
 	Polling,
 };
-use sp_runtime::traits::{StaticLookup, UniqueSaturatedInto};
-use sp_runtime::SaturatedConversion;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{IntegerSquareRoot, StaticLookup, UniqueSaturatedInto};
+use sp_runtime::{Perbill, SaturatedConversion};
 
 pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<AccountIdOf<T>>>::AssetId;
 pub type AssetBalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<AccountIdOf<T>>>::Balance;
@@ -16,6 +17,7 @@ pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type CommunityIdOf<T> = <T as Config>::CommunityId;
 pub type VoteOf<T> = Vote<AssetIdOf<T>, AssetBalanceOf<T>, NativeBalanceOf<T>>;
 pub type DecisionMethodFor<T> = DecisionMethod<AssetIdOf<T>, AssetBalanceOf<T>>;
+pub type CommunityMetadataFor<T> = CommunityMetadata<<T as frame_system::Config>::Hash>;
 pub type PollIndexOf<T> = <<T as Config>::Polls as Polling<Tally<T>>>::Index;
 pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 pub type PalletsOriginOf<T> =
@@ -23,6 +25,12 @@ pub type PalletsOriginOf<T> =
 pub type MembershipIdOf<T> = <T as Config>::MembershipId;
 pub type RuntimeCallFor<T> = <T as Config>::RuntimeCall;
 pub type RuntimeOriginFor<T> = <T as Config>::RuntimeOrigin;
+pub type RankHistoryOf<T> = BoundedVec<(BlockNumberFor<T>, GenericRank), ConstU32<16>>;
+pub type MembershipApplicationOf<T> = MembershipApplication<NativeBalanceOf<T>, BlockNumberFor<T>>;
+pub type SubsetApprovalsOf<T> = SubsetApprovals<BlockNumberFor<T>>;
+pub type SpendAssetOf<T> = SpendAsset<AssetIdOf<T>>;
+pub type SpendInfoOf<T> = SpendInfo<SpendAssetOf<T>, NativeBalanceOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+pub type SpendIndex = u32;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub type BenchmarkHelperOf<T> = <T as Config>::BenchmarkHelper;
@@ -39,6 +47,101 @@ pub struct CommunityInfo {
 	pub state: CommunityState,
 }
 
+pub type ConstSizedField<const S: u32> = BoundedVec<u8, ConstU32<S>>;
+
+/// The CommunityMetadata struct stores some descriptive information about
+/// the community, so it can be identified distinctively by clients.
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct CommunityMetadata<Hash> {
+	/// The name of the community
+	pub name: ConstSizedField<64>,
+	/// A short description of the community
+	pub description: ConstSizedField<256>,
+	/// The main URL that can lead to information about the community
+	pub main_url: ConstSizedField<256>,
+	/// The hash of the community logo, typically used to find it in a
+	/// content-addressed storage.
+	pub logo: Option<Hash>,
+}
+
+/// A request of an account to become a member of a community, which the
+/// community can approve or reject before it expires.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct MembershipApplication<Balance, BlockNumber> {
+	/// The deposit held from the applicant while the application is pending
+	pub deposit: Balance,
+	/// A message from the applicant to the community
+	pub message: ConstSizedField<256>,
+	/// The block after which the application can no longer be approved
+	pub expires_at: BlockNumber,
+}
+
+/// The approvals gathered to dispatch a call as a subset of a community,
+/// which are discarded if the call isn't dispatched before they expire.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct SubsetApprovals<BlockNumber> {
+	/// The number of memberships that approved the call
+	pub approvals: u32,
+	/// The approvals needed to dispatch the call, set by the first approval
+	pub threshold: u32,
+	/// The block after which the approvals are discarded
+	pub expires_at: BlockNumber,
+}
+
+/// The metadata of an asset issued by a community.
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct CommunityAssetMetadata {
+	pub name: ConstSizedField<64>,
+	pub symbol: ConstSizedField<16>,
+	pub decimals: u8,
+}
+
+/// The lowest and highest ranks the members of a community can be set to.
+/// Each bound is optional, leaving the rank unbounded on that side.
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct RankBounds {
+	pub min: Option<GenericRank>,
+	pub max: Option<GenericRank>,
+}
+
+impl RankBounds {
+	pub fn contains(&self, rank: &GenericRank) -> bool {
+		self.min.map_or(true, |min| rank >= &min) && self.max.map_or(true, |max| rank <= &max)
+	}
+}
+
+/// The number of members of a community, along with the sum of their
+/// ranks.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct MembersCount {
+	pub members: u32,
+	pub ranks: u32,
+}
+
+/// The asset a community spends from its treasury: either the native token
+/// or one of the assets held by the community account.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum SpendAsset<AssetId> {
+	Native,
+	Asset(AssetId),
+}
+
+/// A spend approved by a community, to be paid out of its treasury once it
+/// becomes valid and before it expires.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct SpendInfo<AssetKind, Balance, AccountId, BlockNumber> {
+	/// The asset to be paid
+	pub asset_kind: AssetKind,
+	/// The amount of the asset to be paid
+	pub amount: Balance,
+	/// The account the spend is paid to
+	pub beneficiary: AccountId,
+	/// The block from which the spend can be paid out
+	pub valid_from: BlockNumber,
+	/// The block from which the spend can no longer be paid out
+	pub expire_at: BlockNumber,
+}
+
 /// The current state of the community. It represents whether a community
 /// is awaiting to prove their contribution to the network, is active
 /// and can operate, blocked due to a violation of network norms, or
@@ -51,6 +154,9 @@ pub enum CommunityState {
 	/// The community is blocked, typically as a result of a restriction imposed
 	/// by violating the norms of the network.
 	Blocked,
+	/// The community is being destroyed. Its votes and memberships are
+	/// being cleaned up before it's completely removed.
+	Destroying,
 }
 
 /// The mechanism used by the community or one of its subsets to make decisions
@@ -61,43 +167,192 @@ pub enum DecisionMethod<AssetId, MinVote> {
 	NativeToken,
 	CommunityAsset(AssetId, MinVote),
 	Rank,
+	/// Like [`CommunityAsset`][DecisionMethod::CommunityAsset], but the
+	/// weight of a vote is the square root of the amount it locks.
+	Quadratic(AssetId, MinVote),
+	/// Like [`CommunityAsset`][DecisionMethod::CommunityAsset], but the
+	/// weight of a vote is given by the [`VoteCurve`] of the runtime.
+	CustomCurve(AssetId, MinVote),
+}
+
+impl<AssetId, MinVote> DecisionMethod<AssetId, MinVote> {
+	/// The asset voted with, if the decision method is token-based.
+	pub fn asset(&self) -> Option<&AssetId> {
+		match self {
+			DecisionMethod::CommunityAsset(asset, _)
+			| DecisionMethod::Quadratic(asset, _)
+			| DecisionMethod::CustomCurve(asset, _) => Some(asset),
+			_ => None,
+		}
+	}
+}
+
+/// Shapes the weight of a token-based vote from the amount of tokens it
+/// locks, e.g. to cap it or make it grow logarithmically.
+pub trait VoteCurve {
+	fn weigh(amount: VoteWeight) -> VoteWeight;
+}
+
+/// The weight of a vote is the amount it locks.
+impl VoteCurve for () {
+	fn weigh(amount: VoteWeight) -> VoteWeight {
+		amount
+	}
+}
+
+/// The weight of a vote is the square root of the amount it locks.
+pub struct QuadraticCurve;
+
+impl VoteCurve for QuadraticCurve {
+	fn weigh(amount: VoteWeight) -> VoteWeight {
+		amount.integer_sqrt()
+	}
 }
 
 // Governance
-pub type VoteWeight = u32;
+pub type VoteWeight = u128;
+
+/// A multiplier applied to the weight of a token-based vote, in exchange of
+/// keeping the funds frozen for a number of lock periods after the poll ends.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum Conviction {
+	/// 0.1x votes, unlocked as soon as the poll ends
+	#[default]
+	None,
+	/// 1x votes, locked for one period after the poll ends
+	Locked1x,
+	/// 2x votes, locked for 2x periods after the poll ends
+	Locked2x,
+	/// 3x votes, locked for 4x periods after the poll ends
+	Locked3x,
+	/// 4x votes, locked for 8x periods after the poll ends
+	Locked4x,
+	/// 5x votes, locked for 16x periods after the poll ends
+	Locked5x,
+	/// 6x votes, locked for 32x periods after the poll ends
+	Locked6x,
+	/// 1x votes, unlocked as soon as the poll ends. This is how token votes
+	/// were weighed before convictions existed, so it's kept for the votes
+	/// migrated from then, and can't be chosen when casting a new vote.
+	Unlocked1x,
+}
+
+impl Conviction {
+	/// The number of lock periods the funds remain frozen after the poll ends.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None | Conviction::Unlocked1x => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+
+	/// The weight of a vote of `capital` with this conviction.
+	pub fn votes(self, capital: VoteWeight) -> VoteWeight {
+		match self {
+			Conviction::None => capital / 10,
+			Conviction::Locked1x | Conviction::Unlocked1x => capital,
+			Conviction::Locked2x => capital.saturating_mul(2),
+			Conviction::Locked3x => capital.saturating_mul(3),
+			Conviction::Locked4x => capital.saturating_mul(4),
+			Conviction::Locked5x => capital.saturating_mul(5),
+			Conviction::Locked6x => capital.saturating_mul(6),
+		}
+	}
+}
+
+/// What backs a vote: the funds it locks, or the membership of the voter.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(AssetId, AssetBalance, NativeBalance))]
+pub enum VotePower<AssetId, AssetBalance, NativeBalance> {
+	Asset(AssetId, AssetBalance),
+	Native(NativeBalance),
+	Membership,
+}
+
+impl<A, B, N> VotePower<A, B, N>
+where
+	B: UniqueSaturatedInto<VoteWeight> + Clone,
+	N: UniqueSaturatedInto<VoteWeight> + Clone,
+{
+	pub fn weight(&self) -> VoteWeight {
+		match self {
+			VotePower::Asset(_, balance) => balance.clone().saturated_into(),
+			VotePower::Native(balance) => balance.clone().saturated_into(),
+			VotePower::Membership => 1,
+		}
+	}
+}
 
 #[derive(Clone, Debug, Decode, Encode, PartialEq, MaxEncodedLen, TypeInfo)]
 #[scale_info(skip_type_params(AssetId, AssetBalance, NativeBalance))]
 pub enum Vote<AssetId, AssetBalance, NativeBalance> {
-	AssetBalance(bool, AssetId, AssetBalance),
-	NativeBalance(bool, NativeBalance),
+	AssetBalance(bool, AssetId, AssetBalance, Conviction),
+	NativeBalance(bool, NativeBalance, Conviction),
 	Standard(bool),
+	/// Splits the voting power between ayes and nays, with the remainder
+	/// abstaining. Split votes are casted without conviction.
+	Split {
+		power: VotePower<AssetId, AssetBalance, NativeBalance>,
+		aye: Perbill,
+		nay: Perbill,
+	},
+	/// Counts toward the support of a poll without approving nor rejecting
+	/// it. Abstentions are casted without conviction.
+	Abstain(VotePower<AssetId, AssetBalance, NativeBalance>),
 }
 
 impl<A, B, N> Vote<A, B, N>
 where
+	A: Clone,
 	B: UniqueSaturatedInto<VoteWeight> + Clone,
 	N: UniqueSaturatedInto<VoteWeight> + Clone,
 {
-	pub fn say(&self) -> bool {
-		*match self {
-			Vote::AssetBalance(say, _, _) => say,
-			Vote::NativeBalance(say, _) => say,
-			Vote::Standard(say) => say,
+	/// Whether the vote approves or rejects the poll, if it takes a single
+	/// side.
+	pub fn say(&self) -> Option<bool> {
+		match self {
+			Vote::AssetBalance(say, ..) | Vote::NativeBalance(say, ..) | Vote::Standard(say) => Some(*say),
+			Vote::Split { .. } | Vote::Abstain(_) => None,
 		}
 	}
 
-	pub fn weight(&self) -> VoteWeight {
+	pub fn conviction(&self) -> Option<Conviction> {
+		match self {
+			Vote::AssetBalance(.., conviction) | Vote::NativeBalance(.., conviction) => Some(*conviction),
+			Vote::Standard(_) | Vote::Split { .. } | Vote::Abstain(_) => None,
+		}
+	}
+
+	pub fn power(&self) -> VotePower<A, B, N> {
 		match self {
-			Vote::AssetBalance(_, _, balance) => balance.clone().saturated_into(),
-			Vote::NativeBalance(_, balance) => balance.clone().saturated_into(),
-			Vote::Standard(_) => 1,
+			Vote::AssetBalance(_, asset_id, balance, _) => VotePower::Asset(asset_id.clone(), balance.clone()),
+			Vote::NativeBalance(_, balance, _) => VotePower::Native(balance.clone()),
+			Vote::Standard(_) => VotePower::Membership,
+			Vote::Split { power, .. } | Vote::Abstain(power) => power.clone(),
 		}
 	}
+
+	/// The weight of the vote, without accounting for any conviction. This
+	/// is what counts as support for a poll.
+	pub fn weight(&self) -> VoteWeight {
+		self.power().weight()
+	}
+
+	/// The weight of the vote, multiplied by its conviction.
+	pub fn conviction_weight(&self) -> VoteWeight {
+		let weight = self.weight();
+		self.conviction().map_or(weight, |c| c.votes(weight))
+	}
 }
 
 impl<A, B, N> From<&Vote<A, B, N>> for VoteWeight
 where
+	A: Clone,
 	B: UniqueSaturatedInto<VoteWeight> + Clone,
 	N: UniqueSaturatedInto<VoteWeight> + Clone,
 {
@@ -109,37 +364,76 @@ where
 #[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 #[codec(mel_bound(T: Config))]
-pub struct Tally<T> {
+pub struct Tally<T: Config> {
 	pub(crate) _phantom: PhantomData<T>,
 	pub(crate) ayes: VoteWeight,
 	pub(crate) nays: VoteWeight,
 	pub(crate) bare_ayes: VoteWeight,
+	/// The weight of the votes abstaining from the poll. It counts toward
+	/// its support, but not its approval.
+	pub(crate) abstentions: VoteWeight,
+	/// The maximum support of the poll, as of the moment its voting power
+	/// was snapshotted.
+	pub(crate) max_support: VoteWeight,
+	/// The block at which the voting power of the poll was snapshotted, which
+	/// happens when the poll is submitted.
+	pub(crate) snapshot_at: Option<BlockNumberFor<T>>,
 }
 
-impl<T> Default for Tally<T> {
+impl<T: Config> Default for Tally<T> {
 	fn default() -> Self {
 		Self {
 			_phantom: Default::default(),
 			ayes: Default::default(),
 			nays: Default::default(),
 			bare_ayes: Default::default(),
+			abstentions: Default::default(),
+			max_support: Default::default(),
+			snapshot_at: None,
 		}
 	}
 }
 
 impl<T: Config> Tally<T> {
-	pub(crate) fn max_support(community_id: CommunityIdOf<T>) -> VoteWeight {
-		match CommunityDecisionMethod::<T>::get(community_id) {
-			DecisionMethod::Membership => T::MemberMgmt::members_total(&community_id),
-			DecisionMethod::Rank => T::MemberMgmt::ranks_total(&community_id),
+	/// The maximum support a poll on `track_id` can get. Expired memberships
+	/// can't vote, so pruning them takes them out of it.
+	pub(crate) fn max_support(track_id: T::TrackId) -> VoteWeight {
+		let community_id = track_id.community_id();
+		match Pallet::<T>::decision_method(&track_id) {
+			DecisionMethod::Membership => VoteWeight::from(Pallet::<T>::members_count(&community_id).members),
+			DecisionMethod::Rank => VoteWeight::from(Pallet::<T>::members_count(&community_id).ranks),
 			DecisionMethod::NativeToken => T::Balances::total_issuance().saturated_into::<VoteWeight>(),
-			DecisionMethod::CommunityAsset(asset_id, _) => {
-				T::Assets::total_issuance(asset_id).saturated_into::<VoteWeight>()
-			}
+			DecisionMethod::CommunityAsset(asset_id, _)
+			| DecisionMethod::Quadratic(asset_id, _)
+			| DecisionMethod::CustomCurve(asset_id, _) => T::Assets::total_issuance(asset_id).saturated_into::<VoteWeight>(),
 		}
 	}
 }
 
+/// Resolves the community that owns a referenda track, and builds the
+/// identifiers of the tracks of a community.
+pub trait CommunityTrackId<CommunityId> {
+	/// The community that owns the track.
+	fn community_id(&self) -> CommunityId;
+
+	/// The identifier of the track at `index` of a community. The track at
+	/// index `0` is the default track of the community.
+	fn for_community(community_id: CommunityId, index: u16) -> Self;
+}
+
+/// The lower half of the track identifier is the community ID, while the
+/// upper half is the index of the track within the community. This way, the
+/// default track of a community has the same value as its ID.
+impl CommunityTrackId<u16> for u32 {
+	fn community_id(&self) -> u16 {
+		*self as u16
+	}
+
+	fn for_community(community_id: u16, index: u16) -> Self {
+		(index as u32) << 16 | community_id as u32
+	}
+}
+
 #[derive(PartialEq)]
 pub enum LockUpdateType {
 	Add,
@@ -178,6 +472,18 @@ pub trait BenchmarkHelper<T: Config> {
 		membership_id: MembershipIdOf<T>,
 	) -> Result<(), frame_benchmarking::BenchmarkError>;
 
+	/// Makes a membership of a community expire.
+	fn expire_membership(
+		community_id: CommunityIdOf<T>,
+		membership_id: MembershipIdOf<T>,
+	) -> Result<(), BenchmarkError>;
+
+	/// Returns an invitation key, along with the account it resolves to.
+	fn invitation_signer() -> (T::InvitationPublic, AccountIdOf<T>);
+
+	/// Signs a message with an invitation key.
+	fn sign_invitation(signer: &T::InvitationPublic, message: &[u8]) -> T::InvitationSignature;
+
 	/// This method prepares the referenda track to be used
 	/// to submit the poll, for benchmarking purposes.
 	fn prepare_track(track_origin: PalletsOriginOf<T>) -> Result<(), BenchmarkError>;
@@ -92,6 +92,7 @@ mod benchmarks {
 	#[benchmark]
 	fn pay(q: Linear<1, { T::MaxRemarkLength::get() }>) -> Result<(), BenchmarkError> {
 		let (sender, beneficiary, _, beneficiary_lookup) = create_accounts::<T>();
+		let arbiter_lookup = T::Lookup::unlookup(account("Charlie", 0, 12));
 
 		let asset_id: AssetIdOf<T> = <AssetIdOf<T>>::default();
 		create_and_mint_asset::<T>(&sender, &beneficiary, &asset_id)?;
@@ -110,6 +111,8 @@ mod benchmarks {
 			asset_id.clone(),
 			payment_amount,
 			order_remark.clone(),
+			None,
+			Some(arbiter_lookup),
 		);
 
 		assert_has_event!(
@@ -119,6 +122,37 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn pay_with_auto_release(q: Linear<1, { T::MaxRemarkLength::get() }>) -> Result<(), BenchmarkError> {
+		let (sender, beneficiary, _, beneficiary_lookup) = create_accounts::<T>();
+		let arbiter_lookup = T::Lookup::unlookup(account("Charlie", 0, 12));
+
+		let asset_id: AssetIdOf<T> = <AssetIdOf<T>>::default();
+		create_and_mint_asset::<T>(&sender, &beneficiary, &asset_id)?;
+		let payment_amount = <BalanceOf<T>>::from(100000_u32);
+
+		let order_remark: Option<BoundedDataOf<T>> = Some(BoundedVec::try_from(vec![1 as u8; q as usize]).unwrap());
+		let delay = T::CancelBufferBlockLength::get();
+
+		#[extrinsic_call]
+		pay(
+			RawOrigin::Signed(sender.clone()),
+			beneficiary_lookup,
+			asset_id.clone(),
+			payment_amount,
+			order_remark,
+			Some(delay),
+			Some(arbiter_lookup),
+		);
+
+		let current_block = frame_system::Pallet::<T>::block_number();
+		assert_has_event!(
+			Event::PaymentAutoReleaseScheduled { release_block, .. }
+			if release_block == (current_block + delay)
+		);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn release() -> Result<(), BenchmarkError> {
 		let amount = <BalanceOf<T>>::from(100000_u32);
@@ -134,6 +168,20 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn release_partial() -> Result<(), BenchmarkError> {
+		let amount = <BalanceOf<T>>::from(100000_u32);
+		let asset = <AssetIdOf<T>>::default();
+		let (payment_id, sender, _beneficiary, _sender_lookup, _beneficiary_lookup) =
+			create_payment::<T>(&amount, &asset, None)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender), payment_id, <BalanceOf<T>>::from(50000_u32));
+
+		assert_has_event!(Event::PaymentPartiallyReleased { .. });
+		Ok(())
+	}
+
 	#[benchmark]
 	fn cancel() -> Result<(), BenchmarkError> {
 		let amount = <BalanceOf<T>>::from(100000_u32);
@@ -185,6 +233,22 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn dispute() -> Result<(), BenchmarkError> {
+		let amount = <BalanceOf<T>>::from(100000_u32);
+		let asset = <AssetIdOf<T>>::default();
+		let (payment_id, _sender, beneficiary, _sender_lookup, _beneficiary_lookup) =
+			create_payment::<T>(&amount, &asset, None)?;
+		let evidence: BoundedDataOf<T> =
+			BoundedVec::try_from(vec![1 as u8; T::MaxRemarkLength::get() as usize]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(beneficiary.clone()), payment_id, evidence);
+
+		assert_has_event!(Event::PaymentDisputed { .. });
+		Ok(())
+	}
+
 	#[benchmark]
 	fn resolve_dispute() -> Result<(), BenchmarkError> {
 		let amount = <BalanceOf<T>>::from(100000_u32);
@@ -251,5 +315,47 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn prune_payment() -> Result<(), BenchmarkError> {
+		let amount = <BalanceOf<T>>::from(100000_u32);
+		let asset = <AssetIdOf<T>>::default();
+		let (payment_id, sender, _beneficiary, _sender_lookup, _beneficiary_lookup) =
+			create_payment::<T>(&amount, &asset, None)?;
+
+		assert_ok!(Payments::<T>::release(
+			RawOrigin::Signed(sender.clone()).into(),
+			payment_id
+		));
+		let current_block = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(current_block + T::PaymentRetentionPeriod::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender.clone()), payment_id);
+
+		assert_has_event!(Event::PaymentPruned { .. });
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_arbiter() -> Result<(), BenchmarkError> {
+		let amount = <BalanceOf<T>>::from(100000_u32);
+		let asset = <AssetIdOf<T>>::default();
+		let (payment_id, _sender, beneficiary, _sender_lookup, _beneficiary_lookup) =
+			create_payment::<T>(&amount, &asset, None)?;
+		PaymentArbiters::<T>::insert(
+			payment_id,
+			ArbiterDetail {
+				arbiter: account("Charlie", 0, 12),
+				accepted: false,
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(beneficiary.clone()), payment_id);
+
+		assert_has_event!(Event::PaymentArbiterAccepted { .. });
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Payments, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -39,8 +39,8 @@ use sp_std::vec::Vec;
 
 pub mod weights;
 use sp_runtime::{
-	traits::{CheckedAdd, Dispatchable, StaticLookup},
-	DispatchError, DispatchResult, Percent, Saturating,
+	traits::{CheckedAdd, Dispatchable, Hash, StaticLookup, Zero},
+	DispatchError, DispatchResult, Perbill, Percent, Saturating,
 };
 pub use weights::*;
 
@@ -111,6 +111,10 @@ pub mod pallet {
 
 		type DisputeResolver: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+		/// Origin of the arbiters chosen to resolve the disputes of a payment,
+		/// e.g. a community or a designated account
+		type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
 		type PaymentId: PaymentId<Self> + Member + Parameter + MaxEncodedLen;
 
 		type Scheduler: ScheduleNamed<BlockNumberFor<Self>, CallOf<Self>, Self::PalletsOrigin, Hasher = Self::Hashing>;
@@ -144,6 +148,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type CancelBufferBlockLength: Get<BlockNumberFor<Self>>;
 
+		/// Number of blocks a finished payment is kept in storage before it
+		/// can be pruned
+		#[pallet::constant]
+		type PaymentRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks the arbiter of a payment has to resolve a dispute
+		/// before the `DisputeResolver` can resolve it instead
+		#[pallet::constant]
+		type ArbiterTimeout: Get<BlockNumberFor<Self>>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<AccountIdOf<Self>, AssetIdOf<Self>, BalanceOf<Self>>;
 	}
@@ -158,7 +172,8 @@ pub mod pallet {
 	/// payment will only be referenced by the creator in any transaction of
 	/// interest. The storage map keys are the creator and the recipient, this
 	/// also ensures that for any (sender,recipient) combo, only a single
-	/// payment is active. The history of payment is not stored.
+	/// payment is active. Finished payments are kept until the retention
+	/// period is over, after that only their receipt is stored.
 	pub type Payment<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
@@ -180,6 +195,20 @@ pub mod pallet {
 		ResultQuery<Error<T>::NonExistentStorageValue>,
 	>;
 
+	#[pallet::storage]
+	/// Receipts of the payments that reached a terminal state. Unlike
+	/// payments, receipts are never pruned.
+	pub type PaymentReceipts<T: Config> = StorageMap<_, Twox64Concat, T::PaymentId, PaymentReceiptOf<T>>;
+
+	#[pallet::storage]
+	/// Arbiters chosen at creation to resolve the disputes of a payment. They
+	/// only do so once the beneficiary accepts them.
+	pub type PaymentArbiters<T: Config> = StorageMap<_, Twox64Concat, T::PaymentId, ArbiterDetailOf<T>>;
+
+	#[pallet::storage]
+	/// Details of the disputes of payments that need review.
+	pub type PaymentDisputes<T: Config> = StorageMap<_, Twox64Concat, T::PaymentId, DisputeDetailOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -190,8 +219,30 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 			remark: Option<BoundedDataOf<T>>,
 		},
+		/// The payment will be released to the recipient at `release_block`
+		/// unless the creator requests a refund first
+		PaymentAutoReleaseScheduled {
+			payment_id: T::PaymentId,
+			release_block: BlockNumberFor<T>,
+		},
+		/// The sender chose an arbiter to resolve the disputes of the payment
+		PaymentArbiterProposed {
+			payment_id: T::PaymentId,
+			arbiter: T::AccountId,
+		},
+		/// The beneficiary accepted the arbiter of the payment
+		PaymentArbiterAccepted {
+			payment_id: T::PaymentId,
+			arbiter: T::AccountId,
+		},
 		/// Payment amount released to the recipient
 		PaymentReleased { payment_id: T::PaymentId },
+		/// Part of the payment amount released to the recipient
+		PaymentPartiallyReleased {
+			payment_id: T::PaymentId,
+			amount: BalanceOf<T>,
+			remaining: BalanceOf<T>,
+		},
 		/// Payment has been cancelled by the creator
 		PaymentCancelled { payment_id: T::PaymentId },
 		/// A payment that NeedsReview has been resolved by Judge
@@ -208,12 +259,16 @@ pub mod pallet {
 		PaymentRefunded { payment_id: T::PaymentId },
 		/// the refund request from creator was disputed by recipient
 		PaymentRefundDisputed { payment_id: T::PaymentId },
+		/// A created payment was disputed by one of its parties
+		PaymentDisputed { payment_id: T::PaymentId, opened_by: Role },
 		/// Payment request was created by recipient
 		PaymentRequestCreated { payment_id: T::PaymentId },
 		/// Payment request was completed by sender
 		PaymentRequestCompleted { payment_id: T::PaymentId },
 		/// Payment disputed resolved
 		PaymentDisputeResolved { payment_id: T::PaymentId },
+		/// A finished payment was removed from storage, its receipt is kept
+		PaymentPruned { payment_id: T::PaymentId },
 	}
 
 	#[pallet::error]
@@ -246,6 +301,15 @@ pub mod pallet {
 		NoPaymentIdAvailable,
 		/// Call from wrong beneficiary
 		InvalidBeneficiary,
+		/// Retention period of the finished payment has not passed
+		RetentionPeriodNotPassed,
+		/// The amount to release must be greater than zero and less than the
+		/// payment amount
+		InvalidPartialAmount,
+		/// The arbiter can't be a party of the payment
+		InvalidArbiter,
+		/// The arbiter of the payment can still resolve the dispute
+		ArbiterTimeoutNotPassed,
 	}
 
 	#[pallet::composite_enum]
@@ -261,18 +325,38 @@ pub mod pallet {
 		/// payment in storage and reserve the specified amount. User also has
 		/// the option to add a remark, this remark can then be used to run
 		/// custom logic and trigger alternate payment flows. the specified
-		/// amount.
+		/// amount. If `auto_release_after` is given, the payment is released
+		/// to the recipient after that many blocks, unless the creator
+		/// releases it or requests a refund before. If an `arbiter` is given
+		/// and the beneficiary accepts them, only they can resolve the disputes
+		/// of the payment until the `ArbiterTimeout` is over, after that the
+		/// `DisputeResolver` can as well.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::pay(remark.as_ref().map(|x| x.len() as u32).unwrap_or(0)))]
+		#[pallet::weight({
+			let q = remark.as_ref().map(|x| x.len() as u32).unwrap_or(0);
+			match auto_release_after {
+				Some(_) => <T as Config>::WeightInfo::pay_with_auto_release(q),
+				None => <T as Config>::WeightInfo::pay(q),
+			}
+		})]
 		pub fn pay(
 			origin: OriginFor<T>,
 			beneficiary: AccountIdLookupOf<T>,
 			asset: AssetIdOf<T>,
 			#[pallet::compact] amount: BalanceOf<T>,
 			remark: Option<BoundedDataOf<T>>,
+			auto_release_after: Option<BlockNumberFor<T>>,
+			arbiter: Option<AccountIdLookupOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = T::SenderOrigin::ensure_origin(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let arbiter = arbiter.map(T::Lookup::lookup).transpose()?;
+			if let Some(arbiter) = &arbiter {
+				ensure!(
+					arbiter != &sender && arbiter != &beneficiary,
+					Error::<T>::InvalidArbiter
+				);
+			}
 
 			// create PaymentDetail and add to storage
 			let (payment_id, payment_detail) = Self::create_payment(
@@ -294,6 +378,38 @@ pub mod pallet {
 				amount,
 				remark,
 			});
+
+			if let Some(arbiter) = arbiter {
+				PaymentArbiters::<T>::insert(
+					payment_id,
+					ArbiterDetail {
+						arbiter: arbiter.clone(),
+						accepted: false,
+					},
+				);
+				Self::deposit_event(Event::PaymentArbiterProposed { payment_id, arbiter });
+			}
+
+			if let Some(delay) = auto_release_after {
+				let release_block = frame_system::Pallet::<T>::block_number()
+					.checked_add(&delay)
+					.ok_or(Error::<T>::MathError)?;
+				let release_call = <T as Config>::RuntimeCall::from(pallet::Call::<T>::release { payment_id });
+
+				T::Scheduler::schedule_named(
+					Self::auto_release_name(&payment_id),
+					DispatchTime::At(release_block),
+					None,
+					63,
+					frame_system::RawOrigin::Signed(sender).into(),
+					T::Preimages::bound(release_call)?,
+				)?;
+
+				Self::deposit_event(Event::PaymentAutoReleaseScheduled {
+					payment_id,
+					release_block,
+				});
+			}
 			Ok(().into())
 		}
 
@@ -308,6 +424,7 @@ pub mod pallet {
 			let payment = Payment::<T>::get(&sender, &payment_id).map_err(|_| Error::<T>::InvalidPayment)?;
 			ensure!(payment.state == PaymentState::Created, Error::<T>::InvalidAction);
 			Self::settle_payment(&sender, &payment.beneficiary, &payment_id, None)?;
+			Self::cancel_auto_release(&payment_id);
 
 			Self::deposit_event(Event::PaymentReleased { payment_id });
 			Ok(().into())
@@ -344,6 +461,7 @@ pub mod pallet {
 				)?;
 
 				payment.state = PaymentState::RefundRequested { cancel_block };
+				Self::cancel_auto_release(&payment_id);
 
 				Ok(cancel_block)
 			})?;
@@ -381,6 +499,7 @@ pub mod pallet {
 				Self::try_transfer_fees(&beneficiary, payment, fee_beneficiary_recipients, IS_DISPUTE)?;
 
 				payment.state = PaymentState::Finished;
+				Self::record_receipt(&sender, &payment_id, payment);
 				Ok(())
 			})?;
 
@@ -388,6 +507,50 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Release part of a created payment to the recipient, keeping the rest
+		/// on hold. Fees and the incentive are pro-rated to the released
+		/// amount, and the remaining payment can still be released, refunded
+		/// or disputed. Use `release` to release the whole remaining amount.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_partial())]
+		pub fn release_partial(
+			origin: OriginFor<T>,
+			payment_id: T::PaymentId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::SenderOrigin::ensure_origin(origin)?;
+
+			let remaining =
+				Payment::<T>::try_mutate(&sender, &payment_id, |maybe_payment| -> Result<_, DispatchError> {
+					let payment = maybe_payment.as_mut().map_err(|_| Error::<T>::InvalidPayment)?;
+					ensure!(payment.state == PaymentState::Created, Error::<T>::InvalidAction);
+					ensure!(
+						!amount.is_zero() && amount < payment.amount,
+						Error::<T>::InvalidPartialAmount
+					);
+
+					let portion = Perbill::from_rational(amount, payment.amount);
+					let released = PaymentDetail::<T> {
+						amount,
+						incentive_amount: portion.mul_floor(payment.incentive_amount),
+						fees: payment.fees.split_off(portion),
+						..payment.clone()
+					};
+					payment.amount = payment.amount.saturating_sub(released.amount);
+					payment.incentive_amount = payment.incentive_amount.saturating_sub(released.incentive_amount);
+
+					Self::release_held_amounts(&sender, &payment.beneficiary, &released, false, false)?;
+					Ok(payment.amount)
+				})?;
+
+			Self::deposit_event(Event::PaymentPartiallyReleased {
+				payment_id,
+				amount,
+				remaining,
+			});
+			Ok(().into())
+		}
+
 		/// Cancel a payment in created state, this will release the reserved
 		/// back to creator of the payment. This extrinsic can only be called by
 		/// the recipient of the payment
@@ -400,9 +563,12 @@ pub mod pallet {
 
 			let payment = Payment::<T>::get(&sender, &payment_id).map_err(|_| Error::<T>::InvalidPayment)?;
 
+			Self::record_receipt(&sender, &payment_id, &payment);
+
 			match payment.state {
 				PaymentState::Created => {
 					Self::cancel_payment(&sender, payment)?;
+					Self::cancel_auto_release(&payment_id);
 					Self::deposit_event(Event::PaymentCancelled { payment_id });
 				}
 				PaymentState::RefundRequested { cancel_block: _ } => {
@@ -412,8 +578,8 @@ pub mod pallet {
 				_ => fail!(Error::<T>::InvalidAction),
 			}
 
-			Payment::<T>::remove(&sender, &payment_id);
-			PaymentParties::<T>::remove(payment_id);
+			Self::remove_payment(&sender, &payment_id);
+			PaymentArbiters::<T>::remove(payment_id);
 
 			Ok(().into())
 		}
@@ -452,10 +618,69 @@ pub mod pallet {
 				T::Scheduler::cancel_named(("payment", payment_id).using_encoded(blake2_256))
 			})?;
 
+			PaymentDisputes::<T>::insert(
+				payment_id,
+				DisputeDetail {
+					opened_by: Role::Beneficiary,
+					evidence: None,
+					opened_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
 			Self::deposit_event(Event::PaymentRefundDisputed { payment_id });
 			Ok(().into())
 		}
 
+		/// Allow either party of a created payment to dispute it. The incentive
+		/// of the beneficiary is held when they open the dispute, the one of
+		/// the sender is held since the payment was created. The payment is
+		/// sent to a NeedsReview state, recording who opened the dispute and
+		/// the hash of the `evidence` remark.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::dispute())]
+		pub fn dispute(
+			origin: OriginFor<T>,
+			payment_id: T::PaymentId,
+			evidence: BoundedDataOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = T::SenderOrigin::try_origin(origin).or_else(|o| T::BeneficiaryOrigin::ensure_origin(o))?;
+			let (sender, beneficiary) = PaymentParties::<T>::get(&payment_id)?;
+
+			let opened_by = if who == sender {
+				Role::Sender
+			} else if who == beneficiary {
+				Role::Beneficiary
+			} else {
+				fail!(Error::<T>::InvalidAction)
+			};
+
+			Payment::<T>::try_mutate(&sender, &payment_id, |maybe_payment| -> DispatchResult {
+				let payment = maybe_payment.as_mut().map_err(|_| Error::<T>::InvalidPayment)?;
+				ensure!(payment.state == PaymentState::Created, Error::<T>::InvalidAction);
+
+				if opened_by == Role::Beneficiary {
+					let reason = &HoldReason::TransferPayment.into();
+					T::Assets::hold(payment.asset.clone(), reason, &beneficiary, payment.incentive_amount)?;
+				}
+
+				payment.state = PaymentState::NeedsReview;
+				Ok(())
+			})?;
+			Self::cancel_auto_release(&payment_id);
+
+			PaymentDisputes::<T>::insert(
+				payment_id,
+				DisputeDetail {
+					opened_by: opened_by.clone(),
+					evidence: Some(<T::Hashing as Hash>::hash(&evidence)),
+					opened_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::PaymentDisputed { payment_id, opened_by });
+			Ok(().into())
+		}
+
 		// Creates a new payment with the given details. This can be called by the
 		// recipient of the payment to create a payment and then completed by the sender
 		// using the `accept_and_pay` extrinsic.  The payment will be in
@@ -487,6 +712,10 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Resolve the dispute of a payment that needs review. Payments with an
+		/// accepted arbiter are resolved by the arbiter, or by the
+		/// `DisputeResolver` once the `ArbiterTimeout` since the dispute was
+		/// opened is over.
 		#[pallet::call_index(20)]
 		#[pallet::weight(<T as Config>::WeightInfo::resolve_dispute())]
 		pub fn resolve_dispute(
@@ -494,7 +723,25 @@ pub mod pallet {
 			payment_id: T::PaymentId,
 			dispute_result: DisputeResult,
 		) -> DispatchResultWithPostInfo {
-			let dispute_resolver = T::DisputeResolver::ensure_origin(origin)?;
+			let dispute_resolver = match PaymentArbiters::<T>::get(&payment_id) {
+				Some(ArbiterDetail {
+					arbiter,
+					accepted: true,
+				}) if T::ArbiterOrigin::ensure_origin(origin.clone()).is_ok_and(|who| who == arbiter) => arbiter,
+				Some(ArbiterDetail { accepted: true, .. }) => {
+					let dispute_resolver = T::DisputeResolver::ensure_origin(origin)?;
+					let opened_at = PaymentDisputes::<T>::get(&payment_id)
+						.map(|dispute| dispute.opened_at)
+						.unwrap_or_default();
+					ensure!(
+						frame_system::Pallet::<T>::block_number() >= opened_at.saturating_add(T::ArbiterTimeout::get()),
+						Error::<T>::ArbiterTimeoutNotPassed
+					);
+					dispute_resolver
+				}
+				// Arbiters the beneficiary never accepted have no say in the dispute
+				_ => T::DisputeResolver::ensure_origin(origin)?,
+			};
 			let (sender, beneficiary) = PaymentParties::<T>::get(&payment_id)?;
 
 			let payment = Payment::<T>::get(&sender, &payment_id).map_err(|_| Error::<T>::InvalidPayment)?;
@@ -506,6 +753,60 @@ pub mod pallet {
 			Self::deposit_event(Event::PaymentDisputeResolved { payment_id });
 			Ok(().into())
 		}
+
+		/// Remove a finished payment from storage once the retention period
+		/// is over. The receipt of the payment is kept. This extrinsic can be
+		/// called by anyone.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::prune_payment())]
+		pub fn prune_payment(origin: OriginFor<T>, payment_id: T::PaymentId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let (sender, _) = PaymentParties::<T>::get(&payment_id)?;
+
+			let payment = Payment::<T>::get(&sender, &payment_id).map_err(|_| Error::<T>::InvalidPayment)?;
+			ensure!(payment.state == PaymentState::Finished, Error::<T>::InvalidAction);
+
+			// Payments finished before receipts were recorded get one now and can be
+			// pruned right away
+			if let Some(receipt) = PaymentReceipts::<T>::get(&payment_id) {
+				let prunable_at = receipt.settled_at.saturating_add(T::PaymentRetentionPeriod::get());
+				ensure!(
+					frame_system::Pallet::<T>::block_number() >= prunable_at,
+					Error::<T>::RetentionPeriodNotPassed
+				);
+			} else {
+				Self::record_receipt(&sender, &payment_id, &payment);
+			}
+
+			Self::remove_payment(&sender, &payment_id);
+
+			Self::deposit_event(Event::PaymentPruned { payment_id });
+			Ok(().into())
+		}
+
+		/// Allow the beneficiary of a created payment to accept the arbiter
+		/// chosen by the sender. Until then, the disputes of the payment are
+		/// resolved by the `DisputeResolver`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_arbiter())]
+		pub fn accept_arbiter(origin: OriginFor<T>, payment_id: T::PaymentId) -> DispatchResultWithPostInfo {
+			let beneficiary = T::BeneficiaryOrigin::ensure_origin(origin)?;
+			let (sender, b) = PaymentParties::<T>::get(&payment_id)?;
+			ensure!(beneficiary == b, Error::<T>::InvalidBeneficiary);
+
+			let payment = Payment::<T>::get(&sender, &payment_id).map_err(|_| Error::<T>::InvalidPayment)?;
+			ensure!(payment.state == PaymentState::Created, Error::<T>::InvalidAction);
+
+			let arbiter = PaymentArbiters::<T>::try_mutate(&payment_id, |maybe_arbiter| -> Result<_, DispatchError> {
+				let detail = maybe_arbiter.as_mut().ok_or(Error::<T>::InvalidAction)?;
+				ensure!(!detail.accepted, Error::<T>::InvalidAction);
+				detail.accepted = true;
+				Ok(detail.arbiter.clone())
+			})?;
+
+			Self::deposit_event(Event::PaymentArbiterAccepted { payment_id, arbiter });
+			Ok(().into())
+		}
 	}
 }
 
@@ -607,44 +908,18 @@ impl<T: Config> Pallet<T> {
 		Payment::<T>::try_mutate(sender, payment_id, |maybe_payment| -> DispatchResult {
 			let payment = maybe_payment.as_mut().map_err(|_| Error::<T>::InvalidPayment)?;
 
-			let reason = &HoldReason::TransferPayment.into();
-			let is_dispute = maybe_dispute.is_some();
-
-			// Release sender fees recipients
-			let (fee_sender_recipients, total_sender_fee_amount_mandatory, total_sender_fee_amount_optional) =
-				payment.fees.summary_for(Role::Sender, is_dispute)?;
-
-			let total_sender_release = total_sender_fee_amount_mandatory
-				.saturating_add(payment.incentive_amount)
-				.saturating_add(total_sender_fee_amount_optional);
-
-			T::Assets::release(payment.asset.clone(), reason, sender, total_sender_release, Exact)
-				.map_err(|_| Error::<T>::ReleaseFailed)?;
-
-			let (
-				fee_beneficiary_recipients,
-				_total_beneficiary_fee_amount_mandatory,
-				_total_beneficiary_fee_amount_optional,
-			) = payment.fees.summary_for(Role::Beneficiary, is_dispute)?;
+			// Disputes opened by the sender hold no incentive from the beneficiary
+			let beneficiary_incentive_held = maybe_dispute.is_some()
+				&& PaymentDisputes::<T>::take(payment_id)
+					.map_or(true, |dispute| dispute.opened_by == Role::Beneficiary);
 
-			let mut beneficiary_release_amount = payment.amount;
-
-			if is_dispute {
-				beneficiary_release_amount = beneficiary_release_amount.saturating_add(payment.incentive_amount);
-			}
-
-			T::Assets::release(
-				payment.asset.clone(),
-				reason,
+			Self::release_held_amounts(
+				sender,
 				beneficiary,
-				beneficiary_release_amount,
-				Exact,
-			)
-			.map_err(|_| Error::<T>::ReleaseFailed)?;
-
-			Self::try_transfer_fees(sender, payment, fee_sender_recipients, is_dispute)?;
-
-			Self::try_transfer_fees(beneficiary, payment, fee_beneficiary_recipients, is_dispute)?;
+				payment,
+				maybe_dispute.is_some(),
+				beneficiary_incentive_held,
+			)?;
 
 			if let Some((dispute_result, resolver)) = maybe_dispute {
 				match dispute_result.in_favor_of {
@@ -652,15 +927,17 @@ impl<T: Config> Pallet<T> {
 						let amount_to_sender = dispute_result.percent_beneficiary.mul_floor(payment.amount);
 
 						// Beneficiary looses the dispute and has to transfer the incentive_amount to
-						// the dispute_resolver.
-						T::Assets::transfer(
-							payment.asset.clone(),
-							beneficiary,
-							&resolver,
-							payment.incentive_amount,
-							Expendable,
-						)
-						.map_err(|_| Error::<T>::TransferFailed)?;
+						// the dispute_resolver, as long as it staked it when opening the dispute.
+						if beneficiary_incentive_held {
+							T::Assets::transfer(
+								payment.asset.clone(),
+								beneficiary,
+								&resolver,
+								payment.incentive_amount,
+								Expendable,
+							)
+							.map_err(|_| Error::<T>::TransferFailed)?;
+						}
 
 						T::Assets::transfer(payment.asset.clone(), beneficiary, sender, amount_to_sender, Expendable)
 							.map_err(|_| Error::<T>::TransferFailed)?;
@@ -685,10 +962,89 @@ impl<T: Config> Pallet<T> {
 			}
 
 			payment.state = PaymentState::Finished;
+			PaymentArbiters::<T>::remove(payment_id);
+			Self::record_receipt(sender, payment_id, payment);
 			Ok(())
 		})
 	}
 
+	/// Release the amounts held for `payment` from both parties and transfer
+	/// the fees each of them pays. The incentive held from the beneficiary
+	/// when disputing is released as well.
+	fn release_held_amounts(
+		sender: &T::AccountId,
+		beneficiary: &T::AccountId,
+		payment: &PaymentDetail<T>,
+		is_dispute: bool,
+		beneficiary_incentive_held: bool,
+	) -> DispatchResult {
+		let reason = &HoldReason::TransferPayment.into();
+
+		// Release sender fees recipients
+		let (fee_sender_recipients, total_sender_fee_amount_mandatory, total_sender_fee_amount_optional) =
+			payment.fees.summary_for(Role::Sender, is_dispute)?;
+
+		let total_sender_release = total_sender_fee_amount_mandatory
+			.saturating_add(payment.incentive_amount)
+			.saturating_add(total_sender_fee_amount_optional);
+
+		T::Assets::release(payment.asset.clone(), reason, sender, total_sender_release, Exact)
+			.map_err(|_| Error::<T>::ReleaseFailed)?;
+
+		let (
+			fee_beneficiary_recipients,
+			_total_beneficiary_fee_amount_mandatory,
+			_total_beneficiary_fee_amount_optional,
+		) = payment.fees.summary_for(Role::Beneficiary, is_dispute)?;
+
+		let mut beneficiary_release_amount = payment.amount;
+
+		if beneficiary_incentive_held {
+			beneficiary_release_amount = beneficiary_release_amount.saturating_add(payment.incentive_amount);
+		}
+
+		T::Assets::release(
+			payment.asset.clone(),
+			reason,
+			beneficiary,
+			beneficiary_release_amount,
+			Exact,
+		)
+		.map_err(|_| Error::<T>::ReleaseFailed)?;
+
+		Self::try_transfer_fees(sender, payment, fee_sender_recipients, is_dispute)?;
+
+		Self::try_transfer_fees(beneficiary, payment, fee_beneficiary_recipients, is_dispute)
+	}
+
+	fn auto_release_name(payment_id: &T::PaymentId) -> [u8; 32] {
+		("payment_release", payment_id).using_encoded(blake2_256)
+	}
+
+	/// Cancel the automatic release of a payment, if one is pending.
+	fn cancel_auto_release(payment_id: &T::PaymentId) {
+		// Payments created without `auto_release_after` have nothing to cancel
+		let _ = T::Scheduler::cancel_named(Self::auto_release_name(payment_id));
+	}
+
+	/// Commit to the final details of a payment that reached a terminal
+	/// state.
+	fn record_receipt(sender: &T::AccountId, payment_id: &T::PaymentId, payment: &PaymentDetail<T>) {
+		PaymentReceipts::<T>::insert(
+			payment_id,
+			PaymentReceipt {
+				hash: T::Hashing::hash_of(&(sender, payment_id, payment)),
+				settled_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+	}
+
+	/// Remove a payment from storage together with its parties.
+	fn remove_payment(sender: &T::AccountId, payment_id: &T::PaymentId) {
+		Payment::<T>::remove(sender, payment_id);
+		PaymentParties::<T>::remove(payment_id);
+	}
+
 	fn try_transfer_fees(
 		account: &T::AccountId,
 		payment: &PaymentDetail<T>,
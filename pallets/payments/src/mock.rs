@@ -214,6 +214,7 @@ impl Config for Test {
 	type SenderOrigin = EnsureSigned<AccountId>;
 	type BeneficiaryOrigin = EnsureSigned<AccountId>;
 	type DisputeResolver = frame_system::EnsureRootWithSuccess<u64, ConstU64<ROOT_ACCOUNT>>;
+	type ArbiterOrigin = EnsureSigned<AccountId>;
 	type PalletId = PaymentPalletId;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxDiscounts = ConstU32<50>;
@@ -222,6 +223,8 @@ impl Config for Test {
 	type Scheduler = Scheduler;
 	type Preimages = ();
 	type CancelBufferBlockLength = ConstU64<10>;
+	type PaymentRetentionPeriod = ConstU64<20>;
+	type ArbiterTimeout = ConstU64<10>;
 	type PalletsOrigin = OriginCaller;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -2,12 +2,12 @@ use super::*;
 use crate::{
 	mock::*,
 	types::{PaymentDetail, PaymentState},
-	Payment as PaymentStore, PaymentId,
+	Payment as PaymentStore, PaymentDisputes, PaymentId, PaymentParties, PaymentReceipts,
 };
 use frame_support::{assert_err, assert_ok, traits::fungibles, weights::constants::WEIGHT_REF_TIME_PER_NANOS};
 use weights::SubstrateWeight;
 
-use sp_runtime::{BoundedVec, Perbill};
+use sp_runtime::{traits::Hash, BoundedVec, Perbill};
 
 const ASSERT_PAYMENT_CREATION: bool = true;
 
@@ -21,6 +21,8 @@ fn build_payment(assert_payment_creation: bool) -> Fees<Test> {
 		ASSET_ID,
 		PAYMENT_AMOUNT,
 		Some(remark.clone()),
+		None,
+		None,
 	));
 
 	let fees_details: Fees<Test> = <Test as pallet_payments::Config>::FeeHandler::apply_fees(
@@ -154,6 +156,195 @@ fn test_pay_and_release_works() {
 	});
 }
 
+#[test]
+fn release_partial_pro_rates_fees_and_incentive() {
+	new_test_ext().execute_with(|| {
+		let fees = build_payment(ASSERT_PAYMENT_CREATION);
+
+		assert_err!(
+			Payments::release_partial(RuntimeOrigin::signed(SENDER_ACCOUNT), PAYMENT_ID, PAYMENT_AMOUNT),
+			Error::<Test>::InvalidPartialAmount
+		);
+		assert_ok!(Payments::release_partial(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_ID,
+			PAYMENT_AMOUNT / 2
+		));
+
+		System::assert_has_event(RuntimeEvent::Payments(
+			pallet_payments::Event::PaymentPartiallyReleased {
+				payment_id: PAYMENT_ID,
+				amount: PAYMENT_AMOUNT / 2,
+				remaining: PAYMENT_AMOUNT / 2,
+			},
+		));
+
+		let payment = PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap();
+		assert_eq!(payment.amount, PAYMENT_AMOUNT / 2);
+		assert_eq!(payment.incentive_amount, INCENTIVE_AMOUNT / 2);
+		assert_eq!(payment.state, PaymentState::Created);
+
+		// Half of each fee is charged, rounded down
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_SENDER_ACCOUNT),
+			1
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_BENEFICIARY_ACCOUNT),
+			1
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_SYSTEM_ACCOUNT),
+			2
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &PAYMENT_BENEFICIARY),
+			PAYMENT_AMOUNT / 2 - 2
+		);
+
+		// Releasing the rest charges the remaining fees
+		assert_ok!(Payments::release(RuntimeOrigin::signed(SENDER_ACCOUNT), PAYMENT_ID));
+
+		assert_eq!(
+			PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap().fees,
+			Fees {
+				sender_pays: BoundedVec::truncate_from(
+					fees.sender_pays
+						.iter()
+						.map(|(a, fee, m)| (*a, fee - fee / 2, *m))
+						.collect()
+				),
+				beneficiary_pays: BoundedVec::truncate_from(
+					fees.beneficiary_pays
+						.iter()
+						.map(|(a, fee, m)| (*a, fee - fee / 2, *m))
+						.collect()
+				),
+			}
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_SYSTEM_ACCOUNT),
+			EXPECTED_SYSTEM_TOTAL_FEE
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_SENDER_ACCOUNT),
+			FEE_SENDER_AMOUNT
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_BENEFICIARY_ACCOUNT),
+			FEE_BENEFICIARY_AMOUNT
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &PAYMENT_BENEFICIARY),
+			PAYMENT_AMOUNT - FEE_BENEFICIARY_AMOUNT - SYSTEM_FEE
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &SENDER_ACCOUNT),
+			INITIAL_BALANCE - PAYMENT_AMOUNT - FEE_SENDER_AMOUNT - SYSTEM_FEE
+		);
+	});
+}
+
+#[test]
+fn remaining_amount_of_partial_release_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		build_payment(ASSERT_PAYMENT_CREATION);
+
+		assert_ok!(Payments::release_partial(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_ID,
+			PAYMENT_AMOUNT / 2
+		));
+		assert_ok!(Payments::cancel(RuntimeOrigin::signed(PAYMENT_BENEFICIARY), PAYMENT_ID));
+
+		System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentCancelled {
+			payment_id: PAYMENT_ID,
+		}));
+
+		// The sender only paid for the released half and its fees
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &SENDER_ACCOUNT),
+			INITIAL_BALANCE - PAYMENT_AMOUNT / 2 - 2
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &PAYMENT_BENEFICIARY),
+			PAYMENT_AMOUNT / 2 - 2
+		);
+	});
+}
+
+#[test]
+fn payment_is_released_automatically() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Payments::pay(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_BENEFICIARY,
+			ASSET_ID,
+			PAYMENT_AMOUNT,
+			None,
+			Some(10),
+			None,
+		));
+
+		System::assert_has_event(RuntimeEvent::Payments(
+			pallet_payments::Event::PaymentAutoReleaseScheduled {
+				payment_id: PAYMENT_ID,
+				release_block: 11,
+			},
+		));
+
+		run_to_block(10);
+		assert_eq!(
+			PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap().state,
+			PaymentState::Created
+		);
+
+		run_to_block(11);
+		System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentReleased {
+			payment_id: PAYMENT_ID,
+		}));
+		assert_eq!(
+			PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap().state,
+			PaymentState::Finished
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &PAYMENT_BENEFICIARY),
+			PAYMENT_AMOUNT - FEE_BENEFICIARY_AMOUNT - SYSTEM_FEE
+		);
+	});
+}
+
+#[test]
+fn refund_request_stops_automatic_release() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Payments::pay(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_BENEFICIARY,
+			ASSET_ID,
+			PAYMENT_AMOUNT,
+			None,
+			Some(5),
+			None,
+		));
+		assert_ok!(Payments::request_refund(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_ID
+		));
+
+		run_to_block(6);
+		assert_eq!(
+			PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap().state,
+			PaymentState::RefundRequested { cancel_block: 11 }
+		);
+
+		// The refund goes through once the cancel buffer is over
+		run_to_block(11);
+		System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentRefunded {
+			payment_id: PAYMENT_ID,
+		}));
+	});
+}
+
 #[test]
 fn test_pay_and_cancel_works() {
 	new_test_ext().execute_with(|| {
@@ -470,6 +661,349 @@ fn payment_disputed_sender_wins() {
 	})
 }
 
+#[test]
+fn sender_disputes_created_payment() {
+	new_test_ext().execute_with(|| {
+		const EXPECTED_BALANCE_SENDER: u64 = 75;
+		const EXPECTED_BALANCE_BENEFICIARY: u64 = 17;
+		const EXPECTED_RESOLVER_BALANCE: u64 = 2;
+
+		build_payment(ASSERT_PAYMENT_CREATION);
+		let evidence: BoundedVec<u8, MaxRemarkLength> = BoundedVec::truncate_from(b"defective".to_vec());
+
+		assert_err!(
+			Payments::dispute(RuntimeOrigin::signed(999), PAYMENT_ID, evidence.clone()),
+			Error::<Test>::InvalidAction
+		);
+		assert_ok!(Payments::dispute(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_ID,
+			evidence.clone()
+		));
+
+		System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentDisputed {
+			payment_id: PAYMENT_ID,
+			opened_by: Role::Sender,
+		}));
+		assert_eq!(
+			PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap().state,
+			PaymentState::NeedsReview
+		);
+		assert_eq!(
+			PaymentDisputes::<Test>::get(PAYMENT_ID),
+			Some(DisputeDetail {
+				opened_by: Role::Sender,
+				evidence: Some(<Test as frame_system::Config>::Hashing::hash(&evidence)),
+			})
+		);
+
+		// A disputed payment can't be disputed again
+		assert_err!(
+			Payments::dispute(RuntimeOrigin::signed(PAYMENT_BENEFICIARY), PAYMENT_ID, evidence),
+			Error::<Test>::InvalidAction
+		);
+
+		assert_ok!(Payments::resolve_dispute(
+			RuntimeOrigin::root(),
+			PAYMENT_ID,
+			DisputeResult {
+				percent_beneficiary: Percent::from_percent(100),
+				in_favor_of: Role::Beneficiary
+			}
+		));
+
+		assert_eq!(PaymentDisputes::<Test>::get(PAYMENT_ID), None);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &SENDER_ACCOUNT),
+			EXPECTED_BALANCE_SENDER
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &PAYMENT_BENEFICIARY),
+			EXPECTED_BALANCE_BENEFICIARY
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &ROOT_ACCOUNT),
+			EXPECTED_RESOLVER_BALANCE
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &FEE_SYSTEM_ACCOUNT),
+			EXPECTED_SYSTEM_TOTAL_FEE
+		);
+	})
+}
+
+/// A dispute opened by the sender holds no incentive from the beneficiary, so
+/// it has nothing to lose to the dispute resolver when the sender wins.
+#[test]
+fn sender_disputes_created_payment_and_wins() {
+	new_test_ext().execute_with(|| {
+		const EXPECTED_BALANCE_SENDER: u64 = 87;
+		const EXPECTED_BALANCE_BENEFICIARY: u64 = 7;
+
+		build_payment(ASSERT_PAYMENT_CREATION);
+		let evidence: BoundedVec<u8, MaxRemarkLength> = BoundedVec::truncate_from(b"defective".to_vec());
+
+		assert_ok!(Payments::dispute(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_ID,
+			evidence
+		));
+
+		assert_ok!(Payments::resolve_dispute(
+			RuntimeOrigin::root(),
+			PAYMENT_ID,
+			DisputeResult {
+				percent_beneficiary: Percent::from_percent(50),
+				in_favor_of: Role::Sender
+			}
+		));
+
+		assert_eq!(
+			PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap().state,
+			PaymentState::Finished
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &SENDER_ACCOUNT),
+			EXPECTED_BALANCE_SENDER
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &PAYMENT_BENEFICIARY),
+			EXPECTED_BALANCE_BENEFICIARY
+		);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &ROOT_ACCOUNT), 0);
+	})
+}
+
+#[test]
+fn beneficiary_disputes_created_payment() {
+	new_test_ext().execute_with(|| {
+		let reason: &<Test as Config>::RuntimeHoldReason = &HoldReason::TransferPayment.into();
+		let _ = Assets::mint(
+			RuntimeOrigin::signed(ASSET_ADMIN_ACCOUNT),
+			ASSET_ID,
+			PAYMENT_BENEFICIARY,
+			10,
+		);
+
+		build_payment(ASSERT_PAYMENT_CREATION);
+		let evidence: BoundedVec<u8, MaxRemarkLength> = BoundedVec::truncate_from(b"not paid".to_vec());
+
+		assert_ok!(Payments::dispute(
+			RuntimeOrigin::signed(PAYMENT_BENEFICIARY),
+			PAYMENT_ID,
+			evidence.clone()
+		));
+
+		assert_eq!(
+			PaymentDisputes::<Test>::get(PAYMENT_ID),
+			Some(DisputeDetail {
+				opened_by: Role::Beneficiary,
+				evidence: Some(<Test as frame_system::Config>::Hashing::hash(&evidence)),
+			})
+		);
+		assert_eq!(
+			<Assets as fungibles::InspectHold<_>>::balance_on_hold(ASSET_ID, reason, &PAYMENT_BENEFICIARY),
+			PAYMENT_AMOUNT + INCENTIVE_AMOUNT
+		);
+	})
+}
+
+const ARBITER_ACCOUNT: AccountId = 40;
+
+fn build_arbitrated_dispute() {
+	assert_ok!(Payments::pay(
+		RuntimeOrigin::signed(SENDER_ACCOUNT),
+		PAYMENT_BENEFICIARY,
+		ASSET_ID,
+		PAYMENT_AMOUNT,
+		None,
+		None,
+		Some(ARBITER_ACCOUNT),
+	));
+	System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentArbiterProposed {
+		payment_id: PAYMENT_ID,
+		arbiter: ARBITER_ACCOUNT,
+	}));
+
+	assert_ok!(Payments::accept_arbiter(
+		RuntimeOrigin::signed(PAYMENT_BENEFICIARY),
+		PAYMENT_ID
+	));
+	System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentArbiterAccepted {
+		payment_id: PAYMENT_ID,
+		arbiter: ARBITER_ACCOUNT,
+	}));
+
+	assert_ok!(Payments::dispute(
+		RuntimeOrigin::signed(SENDER_ACCOUNT),
+		PAYMENT_ID,
+		BoundedVec::truncate_from(b"defective".to_vec())
+	));
+}
+
+#[test]
+fn arbiter_cannot_be_a_party_of_the_payment() {
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			Payments::pay(
+				RuntimeOrigin::signed(SENDER_ACCOUNT),
+				PAYMENT_BENEFICIARY,
+				ASSET_ID,
+				PAYMENT_AMOUNT,
+				None,
+				None,
+				Some(PAYMENT_BENEFICIARY),
+			),
+			Error::<Test>::InvalidArbiter
+		);
+	})
+}
+
+#[test]
+fn only_the_beneficiary_accepts_the_arbiter() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Payments::pay(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_BENEFICIARY,
+			ASSET_ID,
+			PAYMENT_AMOUNT,
+			None,
+			None,
+			Some(ARBITER_ACCOUNT),
+		));
+
+		assert_err!(
+			Payments::accept_arbiter(RuntimeOrigin::signed(SENDER_ACCOUNT), PAYMENT_ID),
+			Error::<Test>::InvalidBeneficiary
+		);
+		assert_ok!(Payments::accept_arbiter(
+			RuntimeOrigin::signed(PAYMENT_BENEFICIARY),
+			PAYMENT_ID
+		));
+		assert_err!(
+			Payments::accept_arbiter(RuntimeOrigin::signed(PAYMENT_BENEFICIARY), PAYMENT_ID),
+			Error::<Test>::InvalidAction
+		);
+	})
+}
+
+#[test]
+fn arbiter_not_accepted_cannot_resolve_dispute() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Payments::pay(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_BENEFICIARY,
+			ASSET_ID,
+			PAYMENT_AMOUNT,
+			None,
+			None,
+			Some(ARBITER_ACCOUNT),
+		));
+		assert_ok!(Payments::dispute(
+			RuntimeOrigin::signed(SENDER_ACCOUNT),
+			PAYMENT_ID,
+			BoundedVec::truncate_from(b"defective".to_vec())
+		));
+
+		// The beneficiary can't accept the arbiter once the payment is disputed
+		assert_err!(
+			Payments::accept_arbiter(RuntimeOrigin::signed(PAYMENT_BENEFICIARY), PAYMENT_ID),
+			Error::<Test>::InvalidAction
+		);
+		assert_err!(
+			Payments::resolve_dispute(
+				RuntimeOrigin::signed(ARBITER_ACCOUNT),
+				PAYMENT_ID,
+				DisputeResult {
+					percent_beneficiary: Percent::from_percent(100),
+					in_favor_of: Role::Beneficiary
+				}
+			),
+			DispatchError::BadOrigin
+		);
+		// The dispute resolver doesn't wait for the arbiter timeout
+		assert_ok!(Payments::resolve_dispute(
+			RuntimeOrigin::root(),
+			PAYMENT_ID,
+			DisputeResult {
+				percent_beneficiary: Percent::from_percent(100),
+				in_favor_of: Role::Beneficiary
+			}
+		));
+	})
+}
+
+#[test]
+fn arbiter_resolves_dispute() {
+	new_test_ext().execute_with(|| {
+		build_arbitrated_dispute();
+
+		assert_err!(
+			Payments::resolve_dispute(
+				RuntimeOrigin::signed(999),
+				PAYMENT_ID,
+				DisputeResult {
+					percent_beneficiary: Percent::from_percent(100),
+					in_favor_of: Role::Beneficiary
+				}
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Payments::resolve_dispute(
+			RuntimeOrigin::signed(ARBITER_ACCOUNT),
+			PAYMENT_ID,
+			DisputeResult {
+				percent_beneficiary: Percent::from_percent(100),
+				in_favor_of: Role::Beneficiary
+			}
+		));
+
+		System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentDisputeResolved {
+			payment_id: PAYMENT_ID,
+		}));
+		// The incentive of the losing party goes to the arbiter
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &ARBITER_ACCOUNT),
+			INCENTIVE_AMOUNT
+		);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &ROOT_ACCOUNT), 0);
+	})
+}
+
+#[test]
+fn dispute_resolver_takes_over_after_arbiter_timeout() {
+	new_test_ext().execute_with(|| {
+		build_arbitrated_dispute();
+
+		assert_err!(
+			Payments::resolve_dispute(
+				RuntimeOrigin::root(),
+				PAYMENT_ID,
+				DisputeResult {
+					percent_beneficiary: Percent::from_percent(100),
+					in_favor_of: Role::Beneficiary
+				}
+			),
+			Error::<Test>::ArbiterTimeoutNotPassed
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Payments::resolve_dispute(
+			RuntimeOrigin::root(),
+			PAYMENT_ID,
+			DisputeResult {
+				percent_beneficiary: Percent::from_percent(100),
+				in_favor_of: Role::Beneficiary
+			}
+		));
+
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::balance(ASSET_ID, &ROOT_ACCOUNT),
+			INCENTIVE_AMOUNT
+		);
+	})
+}
+
 #[test]
 fn request_payment() {
 	new_test_ext().execute_with(|| {
@@ -534,6 +1068,69 @@ fn request_payment() {
 	})
 }
 
+#[test]
+fn finished_payments_are_pruned_after_retention() {
+	new_test_ext().execute_with(|| {
+		build_payment(ASSERT_PAYMENT_CREATION);
+		assert_ok!(Payments::release(RuntimeOrigin::signed(SENDER_ACCOUNT), PAYMENT_ID));
+
+		let payment = PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap();
+		let receipt = PaymentReceipt {
+			hash: <Test as frame_system::Config>::Hashing::hash_of(&(SENDER_ACCOUNT, PAYMENT_ID, payment)),
+			settled_at: 1,
+		};
+		assert_eq!(PaymentReceipts::<Test>::get(PAYMENT_ID), Some(receipt.clone()));
+
+		assert_err!(
+			Payments::prune_payment(RuntimeOrigin::signed(999), PAYMENT_ID),
+			Error::<Test>::RetentionPeriodNotPassed
+		);
+
+		System::set_block_number(21);
+		assert_ok!(Payments::prune_payment(RuntimeOrigin::signed(999), PAYMENT_ID));
+
+		System::assert_has_event(RuntimeEvent::Payments(pallet_payments::Event::PaymentPruned {
+			payment_id: PAYMENT_ID,
+		}));
+		assert!(PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).is_err());
+		assert!(PaymentParties::<Test>::get(PAYMENT_ID).is_err());
+		// The receipt outlives the payment
+		assert_eq!(PaymentReceipts::<Test>::get(PAYMENT_ID), Some(receipt));
+	});
+}
+
+#[test]
+fn only_finished_payments_can_be_pruned() {
+	new_test_ext().execute_with(|| {
+		build_payment(ASSERT_PAYMENT_CREATION);
+
+		System::set_block_number(21);
+		assert_err!(
+			Payments::prune_payment(RuntimeOrigin::signed(999), PAYMENT_ID),
+			Error::<Test>::InvalidAction
+		);
+	});
+}
+
+#[test]
+fn cancelled_payments_are_removed_with_a_receipt() {
+	new_test_ext().execute_with(|| {
+		build_payment(ASSERT_PAYMENT_CREATION);
+		let payment = PaymentStore::<Test>::get(SENDER_ACCOUNT, PAYMENT_ID).unwrap();
+
+		assert_ok!(Payments::cancel(RuntimeOrigin::signed(PAYMENT_BENEFICIARY), PAYMENT_ID));
+
+		assert!(PaymentParties::<Test>::get(PAYMENT_ID).is_err());
+		assert_eq!(
+			PaymentReceipts::<Test>::get(PAYMENT_ID),
+			Some(PaymentReceipt {
+				hash: <Test as frame_system::Config>::Hashing::hash_of(&(SENDER_ACCOUNT, PAYMENT_ID, payment)),
+				settled_at: 1,
+			})
+		);
+	});
+}
+
 #[test]
 fn next_id_works() {
 	new_test_ext().execute_with(|| {
@@ -577,13 +1174,20 @@ fn weights() {
 		// Examples: call available weight functions with various parameters (as applicable) to gauge weight usage in
 		// comparison to limits
 		("pay (20)", SubstrateWeight::<Test>::pay(20_u32)),
+		(
+			"pay_with_auto_release (20)",
+			SubstrateWeight::<Test>::pay_with_auto_release(20_u32),
+		),
 		("release", SubstrateWeight::<Test>::release()),
+		("release_partial", SubstrateWeight::<Test>::release_partial()),
 		("cancel", SubstrateWeight::<Test>::cancel()),
 		("request_refund", SubstrateWeight::<Test>::request_refund()),
 		("dispute_refund", SubstrateWeight::<Test>::dispute_refund()),
+		("dispute", SubstrateWeight::<Test>::dispute()),
 		("resolve_dispute", SubstrateWeight::<Test>::resolve_dispute()),
 		("request_payment", SubstrateWeight::<Test>::request_payment()),
 		("accept_and_pay", SubstrateWeight::<Test>::accept_and_pay()),
+		("prune_payment", SubstrateWeight::<Test>::prune_payment()),
 	] {
 		println!("{function}: {weight:?}",);
 		println!(
@@ -4,7 +4,7 @@ use crate::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Zero, BoundedVec, Percent, Saturating};
+use sp_runtime::{traits::Zero, BoundedVec, Perbill, Percent, Saturating};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 // This pallet's asset id and balance type.
@@ -21,6 +21,9 @@ pub type FeeDetails<T> = BoundedVec<Fee<T>, MaxFeesOf<T>>;
 pub type CallOf<T> = <T as Config>::RuntimeCall;
 pub type PreimagesOf<T> = <T as Config>::Preimages;
 pub type BoundedCallOf<T> = Bounded<CallOf<T>, <T as frame_system::Config>::Hashing>;
+pub type PaymentReceiptOf<T> = PaymentReceipt<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+pub type DisputeDetailOf<T> = DisputeDetail<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+pub type ArbiterDetailOf<T> = ArbiterDetail<<T as frame_system::Config>::AccountId>;
 
 /// The PaymentDetail struct stores information about the payment
 /// A "payment" is similar to an escrow, it is used to guarantee proof of
@@ -48,8 +51,9 @@ pub struct PaymentDetail<T: pallet::Config> {
 }
 
 /// The `PaymentState` enum tracks the possible states that a payment can be in.
-/// When a payment is 'completed' or 'cancelled' it is removed from storage and
-/// hence not tracked by a state.
+/// When a payment is 'cancelled' it is removed from storage and hence not
+/// tracked by a state. A 'completed' payment stays `Finished` until it is
+/// pruned, once the retention period is over.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo, Debug)]
 pub enum PaymentState<BlockNumber> {
 	/// Amounts have been reserved and waiting for release/cancel
@@ -57,14 +61,24 @@ pub enum PaymentState<BlockNumber> {
 	/// A judge needs to review and release manually
 	NeedsReview,
 	/// The user has requested refund and will be processed by `BlockNumber`
-	RefundRequested {
-		cancel_block: BlockNumber,
-	},
+	RefundRequested { cancel_block: BlockNumber },
 	/// The recipient of this transaction has created a request
 	PaymentRequested,
+	/// The payment has been settled and is kept until it is pruned
 	Finished,
 }
 
+/// A commitment to the final details of a payment, recorded when the payment
+/// reaches a terminal state. Receipts outlive the payments they refer to, so
+/// the parties can prove how a payment was settled after it has been pruned.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo, Debug)]
+pub struct PaymentReceipt<Hash, BlockNumber> {
+	/// hash of the sender, payment id and the final `PaymentDetail`
+	pub hash: Hash,
+	/// block at which the payment reached its terminal state
+	pub settled_at: BlockNumber,
+}
+
 /// Fee Handler trait that defines how to handle marketplace fees to every
 /// payment/swap
 pub trait FeeHandler<T: pallet::Config> {
@@ -116,6 +130,24 @@ impl<T: pallet::Config> Fees<T> {
 		Self::get_summary(fees, is_dispute)
 	}
 
+	/// Split off the share of the fees that corresponds to `portion` of the
+	/// payment, leaving the rest in place.
+	pub fn split_off(&mut self, portion: Perbill) -> Self {
+		Self {
+			sender_pays: Self::split_details(&mut self.sender_pays, portion),
+			beneficiary_pays: Self::split_details(&mut self.beneficiary_pays, portion),
+		}
+	}
+
+	fn split_details(fees: &mut FeeDetails<T>, portion: Perbill) -> FeeDetails<T> {
+		let mut split_fees = fees.clone();
+		for ((_, fee, _), (_, split_fee, _)) in fees.iter_mut().zip(split_fees.iter_mut()) {
+			*split_fee = portion.mul_floor(*fee);
+			*fee = fee.saturating_sub(*split_fee);
+		}
+		split_fees
+	}
+
 	fn get_summary(fees: &FeeDetails<T>, is_dispute: bool) -> Result<FeesSummaryForRole<T>, DispatchError> {
 		let mut fees_per_account: BTreeMap<AccountIdOf<T>, Fee<T>> = BTreeMap::new();
 		let mut total_to_discount: BalanceOf<T> = Zero::zero();
@@ -163,6 +195,28 @@ pub enum Role {
 	Beneficiary,
 }
 
+/// Who opened the dispute of a payment that needs review, and the evidence
+/// they provided.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DisputeDetail<Hash, BlockNumber> {
+	/// party that opened the dispute
+	pub opened_by: Role,
+	/// hash of the evidence remark, if any was given
+	pub evidence: Option<Hash>,
+	/// block at which the dispute was opened
+	pub opened_at: BlockNumber,
+}
+
+/// Arbiter the sender chose to resolve the disputes of a payment, and
+/// whether the beneficiary accepted them.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ArbiterDetail<AccountId> {
+	/// account chosen to resolve the disputes
+	pub arbiter: AccountId,
+	/// whether the beneficiary accepted the arbiter
+	pub accepted: bool,
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
 pub struct DisputeResult {
 	pub percent_beneficiary: Percent,
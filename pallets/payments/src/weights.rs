@@ -35,6 +35,7 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_payments.
 pub trait WeightInfo {
 	fn pay(q: u32, ) -> Weight;
+	fn pay_with_auto_release(q: u32, ) -> Weight;
 	fn release() -> Weight;
 	fn cancel() -> Weight;
 	fn request_refund() -> Weight;
@@ -42,6 +43,10 @@ pub trait WeightInfo {
 	fn resolve_dispute() -> Weight;
 	fn request_payment() -> Weight;
 	fn accept_and_pay() -> Weight;
+	fn prune_payment() -> Weight;
+	fn release_partial() -> Weight;
+	fn dispute() -> Weight;
+	fn accept_arbiter() -> Weight;
 }
 
 /// Weights for pallet_payments using the Substrate node and recommended hardware.
@@ -57,6 +62,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	/// Storage: `Payments::PaymentParties` (r:0 w:1)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `q` is `[1, 50]`.
 	fn pay(q: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -68,7 +75,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 78_914
 			.saturating_add(Weight::from_parts(900_944, 0).saturating_mul(q.into()))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Holds` (r:2 w:2)
+	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentParties` (r:0 w:1)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `q` is `[1, 50]`.
+	fn pay_with_auto_release(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `473`
+		//  Estimated: `159279`
+		// Minimum execution time: 219_587_000 picoseconds.
+		Weight::from_parts(279_726_681, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 78_914
+			.saturating_add(Weight::from_parts(900_944, 0).saturating_mul(q.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Payments::Payment` (r:1 w:1)
 	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
@@ -80,15 +116,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn release() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1082`
-		//  Estimated: `8856`
+		//  Estimated: `159279`
 		// Minimum execution time: 398_666_000 picoseconds.
 		Weight::from_parts(404_550_000, 0)
-			.saturating_add(Weight::from_parts(0, 8856))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
 	/// Storage: `Payments::PaymentParties` (r:1 w:1)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -100,15 +144,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Holds` (r:2 w:2)
 	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn cancel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1049`
-		//  Estimated: `8517`
+		//  Estimated: `159279`
 		// Minimum execution time: 300_337_000 picoseconds.
 		Weight::from_parts(308_347_000, 0)
-			.saturating_add(Weight::from_parts(0, 8517))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	/// Storage: `Payments::Payment` (r:1 w:1)
 	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
@@ -123,8 +175,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 80_215_000 picoseconds.
 		Weight::from_parts(82_549_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Payments::PaymentParties` (r:1 w:0)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -140,6 +192,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentDisputes` (r:0 w:1)
+	/// Proof: `Payments::PaymentDisputes` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
 	fn dispute_refund() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1150`
@@ -148,7 +202,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(216_604_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
 			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Payments::PaymentParties` (r:1 w:0)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -162,6 +216,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentDisputes` (r:1 w:1)
+	/// Proof: `Payments::PaymentDisputes` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:1 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn resolve_dispute() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1152`
@@ -169,8 +229,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 588_034_000 picoseconds.
 		Weight::from_parts(602_119_000, 0)
 			.saturating_add(Weight::from_parts(0, 8856))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	/// Storage: `Payments::Payment` (r:1 w:1)
 	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
@@ -198,6 +258,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn accept_and_pay() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `943`
@@ -206,7 +268,85 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(369_330_000, 0)
 			.saturating_add(Weight::from_parts(0, 8856))
 			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Payments::PaymentParties` (r:1 w:1)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:1 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `8517`
+		// Minimum execution time: 41_210_000 picoseconds.
+		Weight::from_parts(42_385_000, 0)
+			.saturating_add(Weight::from_parts(0, 8517))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:3 w:3)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Holds` (r:2 w:2)
+	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1082`
+		//  Estimated: `8856`
+		// Minimum execution time: 412_305_000 picoseconds.
+		Weight::from_parts(419_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 8856))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Payments::PaymentParties` (r:1 w:0)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Holds` (r:1 w:1)
+	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentDisputes` (r:0 w:1)
+	/// Proof: `Payments::PaymentDisputes` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	fn dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1150`
+		//  Estimated: `159279`
+		// Minimum execution time: 221_840_000 picoseconds.
+		Weight::from_parts(225_317_000, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Payments::PaymentParties` (r:1 w:0)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::Payment` (r:1 w:0)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:1 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn accept_arbiter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1087`
+		//  Estimated: `8517`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_615_000, 0)
+			.saturating_add(Weight::from_parts(0, 8517))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
 
@@ -222,6 +362,8 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	/// Storage: `Payments::PaymentParties` (r:0 w:1)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `q` is `[1, 50]`.
 	fn pay(q: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -233,7 +375,36 @@ impl WeightInfo for () {
 			// Standard Error: 78_914
 			.saturating_add(Weight::from_parts(900_944, 0).saturating_mul(q.into()))
 			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Holds` (r:2 w:2)
+	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentParties` (r:0 w:1)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `q` is `[1, 50]`.
+	fn pay_with_auto_release(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `473`
+		//  Estimated: `159279`
+		// Minimum execution time: 219_587_000 picoseconds.
+		Weight::from_parts(279_726_681, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 78_914
+			.saturating_add(Weight::from_parts(900_944, 0).saturating_mul(q.into()))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(9))
 	}
 	/// Storage: `Payments::Payment` (r:1 w:1)
 	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
@@ -245,15 +416,23 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn release() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1082`
-		//  Estimated: `8856`
+		//  Estimated: `159279`
 		// Minimum execution time: 398_666_000 picoseconds.
 		Weight::from_parts(404_550_000, 0)
-			.saturating_add(Weight::from_parts(0, 8856))
-			.saturating_add(RocksDbWeight::get().reads(8))
-			.saturating_add(RocksDbWeight::get().writes(8))
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(12))
 	}
 	/// Storage: `Payments::PaymentParties` (r:1 w:1)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -265,15 +444,23 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Holds` (r:2 w:2)
 	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:0 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn cancel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1049`
-		//  Estimated: `8517`
+		//  Estimated: `159279`
 		// Minimum execution time: 300_337_000 picoseconds.
 		Weight::from_parts(308_347_000, 0)
-			.saturating_add(Weight::from_parts(0, 8517))
-			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(11))
 	}
 	/// Storage: `Payments::Payment` (r:1 w:1)
 	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
@@ -288,8 +475,8 @@ impl WeightInfo for () {
 		// Minimum execution time: 80_215_000 picoseconds.
 		Weight::from_parts(82_549_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(RocksDbWeight::get().reads(3))
-			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 	/// Storage: `Payments::PaymentParties` (r:1 w:0)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -305,6 +492,8 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentDisputes` (r:0 w:1)
+	/// Proof: `Payments::PaymentDisputes` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
 	fn dispute_refund() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1150`
@@ -313,7 +502,7 @@ impl WeightInfo for () {
 		Weight::from_parts(216_604_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
 			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
 	}
 	/// Storage: `Payments::PaymentParties` (r:1 w:0)
 	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -327,6 +516,12 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentDisputes` (r:1 w:1)
+	/// Proof: `Payments::PaymentDisputes` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:1 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn resolve_dispute() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1152`
@@ -334,8 +529,8 @@ impl WeightInfo for () {
 		// Minimum execution time: 588_034_000 picoseconds.
 		Weight::from_parts(602_119_000, 0)
 			.saturating_add(Weight::from_parts(0, 8856))
-			.saturating_add(RocksDbWeight::get().reads(9))
-			.saturating_add(RocksDbWeight::get().writes(8))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(11))
 	}
 	/// Storage: `Payments::Payment` (r:1 w:1)
 	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
@@ -363,6 +558,8 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:0 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn accept_and_pay() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `943`
@@ -371,6 +568,84 @@ impl WeightInfo for () {
 		Weight::from_parts(369_330_000, 0)
 			.saturating_add(Weight::from_parts(0, 8856))
 			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `Payments::PaymentParties` (r:1 w:1)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentReceipts` (r:1 w:1)
+	/// Proof: `Payments::PaymentReceipts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `8517`
+		// Minimum execution time: 41_210_000 picoseconds.
+		Weight::from_parts(42_385_000, 0)
+			.saturating_add(Weight::from_parts(0, 8517))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:3 w:3)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Holds` (r:2 w:2)
+	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1082`
+		//  Estimated: `8856`
+		// Minimum execution time: 412_305_000 picoseconds.
+		Weight::from_parts(419_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 8856))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	/// Storage: `Payments::PaymentParties` (r:1 w:0)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::Payment` (r:1 w:1)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Holds` (r:1 w:1)
+	/// Proof: `Assets::Holds` (`max_values`: None, `max_size`: Some(982), added: 3457, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentDisputes` (r:0 w:1)
+	/// Proof: `Payments::PaymentDisputes` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	fn dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1150`
+		//  Estimated: `159279`
+		// Minimum execution time: 221_840_000 picoseconds.
+		Weight::from_parts(225_317_000, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `Payments::PaymentParties` (r:1 w:0)
+	/// Proof: `Payments::PaymentParties` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::Payment` (r:1 w:0)
+	/// Proof: `Payments::Payment` (`max_values`: None, `max_size`: Some(5052), added: 7527, mode: `MaxEncodedLen`)
+	/// Storage: `Payments::PaymentArbiters` (r:1 w:1)
+	/// Proof: `Payments::PaymentArbiters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn accept_arbiter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1087`
+		//  Estimated: `8517`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_615_000, 0)
+			.saturating_add(Weight::from_parts(0, 8517))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
 	}
 }
@@ -1,7 +1,8 @@
 use super::*;
 
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRootWithSuccess};
-use pallet_communities::RuntimeOriginFor;
+use pallet_communities::{CommunityTrackId, RuntimeOriginFor};
+use sp_runtime::traits::TryConvert;
 use sp_std::marker::PhantomData;
 
 use pallet_referenda::{BalanceOf, PalletsOriginOf, TrackIdOf, TracksInfo};
@@ -9,6 +10,11 @@ use pallet_referenda::{BalanceOf, PalletsOriginOf, TrackIdOf, TracksInfo};
 pub type CommunityTracksInstance = pallet_referenda_tracks::Instance2;
 pub type CommunityReferendaInstance = pallet_referenda::Instance2;
 
+/// Identifies a community track: the lower 16 bits hold the owning
+/// [`CommunityId`], the upper ones the index of the track within the
+/// community.
+pub type CommunityTrackIdOf = u32;
+
 parameter_types! {
 	pub const AlarmInterval: BlockNumber = 1;
 	pub const SubmissionDeposit: Balance = 0;
@@ -32,7 +38,15 @@ impl EnsureOriginWithArg<RuntimeOrigin, TrackIdOf<Runtime, CommunityTracksInstan
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin(id: &TrackIdOf<Runtime, CommunityTracksInstance>) -> Result<RuntimeOrigin, ()> {
-		Ok(pallet_communities::Origin::<Runtime>::new(id.clone()).into())
+		Ok(pallet_communities::Origin::<Runtime>::new(id.community_id()).into())
+	}
+}
+
+/// Resolves the community track a community origin belongs to.
+pub struct TrackForOrigin;
+impl TryConvert<OriginCaller, CommunityTrackIdOf> for TrackForOrigin {
+	fn try_convert(origin: OriginCaller) -> Result<CommunityTrackIdOf, OriginCaller> {
+		CommunityTracks::track_for(&origin).map_err(|_| origin)
 	}
 }
 
@@ -40,7 +54,7 @@ impl pallet_referenda_tracks::Config<CommunityTracksInstance> for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureOriginToTrack;
 	type RuntimeEvent = RuntimeEvent;
-	type TrackId = CommunityId;
+	type TrackId = CommunityTrackIdOf;
 	type MaxTracks = ConstU32<65536>;
 	type WeightInfo = weights::pallet_referenda_tracks::WeightInfo<Self>;
 
@@ -57,7 +71,7 @@ where
 		BalanceOf<T, I>,
 		BlockNumberFor<T>,
 		RuntimeOrigin = PalletsOriginOf<T>,
-		Id = <T as pallet_communities::Config>::CommunityId,
+		Id = <T as pallet_communities::Config>::TrackId,
 	>,
 {
 	type Success = T::AccountId;
@@ -68,7 +82,9 @@ where
 	) -> Result<Self::Success, RuntimeOriginFor<T>> {
 		use fc_traits_memberships::Inspect;
 		use frame_system::RawOrigin::Signed;
-		let community_id = T::Tracks::track_for(track_origin).map_err(|_| o.clone())?;
+		let community_id = T::Tracks::track_for(track_origin)
+			.map_err(|_| o.clone())?
+			.community_id();
 
 		match o.clone().into() {
 			Ok(Signed(who)) => {
@@ -0,0 +1,254 @@
+//! Migrations of the storage of community polls.
+
+use super::*;
+
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{UncheckedOnRuntimeUpgrade, VoteTally},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use governance::{CommunityReferendaInstance, CommunityTrackIdOf, CommunityTracksInstance};
+use pallet_communities::migrations::v2::{translate_tally, OldTally};
+use pallet_referenda::{
+	BalanceOf, BoundedCallOf, DecidingCount, PalletsOriginOf, ReferendumIndex, ReferendumInfo, ReferendumInfoFor,
+	ReferendumStatus, ScheduleAddressOf, TrackInfoOf, TrackQueue,
+};
+use sp_runtime::Saturating;
+
+/// Community tracks were identified by a `u16` before a community could have
+/// more than one track.
+type OldTrackId = u16;
+
+type MaxTracks = <Runtime as pallet_referenda_tracks::Config<CommunityTracksInstance>>::MaxTracks;
+
+mod old {
+	use super::*;
+	use frame_support::{storage_alias, Blake2_128Concat, Twox64Concat};
+
+	#[storage_alias(pallet_name)]
+	pub type Tracks =
+		StorageMap<CommunityTracks, Blake2_128Concat, OldTrackId, TrackInfoOf<Runtime, CommunityReferendaInstance>>;
+
+	#[storage_alias(pallet_name)]
+	pub type OriginToTrackId = StorageMap<CommunityTracks, Blake2_128Concat, PalletsOriginOf<Runtime>, OldTrackId>;
+
+	#[storage_alias(pallet_name)]
+	pub type TracksIds = StorageValue<CommunityTracks, BoundedVec<OldTrackId, MaxTracks>, ValueQuery>;
+
+	#[storage_alias(pallet_name)]
+	pub type TrackQueue = StorageMap<CommunityReferenda, Twox64Concat, OldTrackId, OldTrackQueueOf, ValueQuery>;
+
+	#[storage_alias(pallet_name)]
+	pub type DecidingCount = StorageMap<CommunityReferenda, Twox64Concat, OldTrackId, u32, ValueQuery>;
+}
+
+mod new {
+	use super::*;
+	use frame_support::{storage_alias, Blake2_128Concat, Twox64Concat};
+
+	#[storage_alias(pallet_name)]
+	pub type Tracks = StorageMap<
+		CommunityTracks,
+		Blake2_128Concat,
+		CommunityTrackIdOf,
+		TrackInfoOf<Runtime, CommunityReferendaInstance>,
+	>;
+
+	#[storage_alias(pallet_name)]
+	pub type OriginToTrackId =
+		StorageMap<CommunityTracks, Blake2_128Concat, PalletsOriginOf<Runtime>, CommunityTrackIdOf>;
+
+	#[storage_alias(pallet_name)]
+	pub type TracksIds = StorageValue<CommunityTracks, BoundedVec<CommunityTrackIdOf, MaxTracks>, ValueQuery>;
+
+	/// The votes of queued referenda are widened later on, along with their
+	/// tallies.
+	#[storage_alias(pallet_name)]
+	pub type TrackQueue = StorageMap<CommunityReferenda, Twox64Concat, CommunityTrackIdOf, OldTrackQueueOf, ValueQuery>;
+}
+
+type OldReferendumInfoOf = ReferendumInfo<
+	OldTrackId,
+	PalletsOriginOf<Runtime>,
+	BlockNumberFor<Runtime>,
+	BoundedCallOf<Runtime, CommunityReferendaInstance>,
+	BalanceOf<Runtime, CommunityReferendaInstance>,
+	OldTally<Runtime>,
+	AccountId,
+	ScheduleAddressOf<Runtime, CommunityReferendaInstance>,
+>;
+
+type OldTrackQueueOf =
+	BoundedVec<(ReferendumIndex, u32), <Runtime as pallet_referenda::Config<CommunityReferendaInstance>>::MaxQueued>;
+
+/// Widens the ids of community tracks to a `u32`, and the tallies of in-flight
+/// community polls, as well as the votes of the referenda waiting in the queue
+/// of a track, now that [`VoteWeight`][pallet_communities::types::VoteWeight]
+/// is a `u128`.
+pub type MigrateTalliesToV2 = VersionedMigration<
+	1,
+	2,
+	(UncheckedMigrateTrackIdsToU32, UncheckedMigrateTalliesToV2),
+	pallet_communities::Pallet<Runtime>,
+	<Runtime as frame_system::Config>::DbWeight,
+>;
+
+/// Moves the community tracks, and the queues and deciding counts of their
+/// referenda, to the `u32` encoding of their ids. The id of the track a
+/// referendum is on is widened along with its tally by
+/// [`UncheckedMigrateTalliesToV2`].
+pub struct UncheckedMigrateTrackIdsToU32;
+
+impl UncheckedOnRuntimeUpgrade for UncheckedMigrateTrackIdsToU32 {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+
+		for (track_id, info) in old::Tracks::drain().collect::<Vec<_>>() {
+			reads.saturating_inc();
+			writes.saturating_accrue(2);
+			new::Tracks::insert(CommunityTrackIdOf::from(track_id), info);
+		}
+
+		new::OriginToTrackId::translate_values::<OldTrackId, _>(|track_id| {
+			reads.saturating_inc();
+			writes.saturating_inc();
+			Some(track_id.into())
+		});
+
+		reads.saturating_inc();
+		writes.saturating_inc();
+		let tracks_ids = old::TracksIds::take();
+		new::TracksIds::put(BoundedVec::truncate_from(
+			tracks_ids.into_iter().map(CommunityTrackIdOf::from).collect(),
+		));
+
+		for (track_id, queue) in old::TrackQueue::drain().collect::<Vec<_>>() {
+			reads.saturating_inc();
+			writes.saturating_accrue(2);
+			new::TrackQueue::insert(CommunityTrackIdOf::from(track_id), queue);
+		}
+
+		for (track_id, count) in old::DecidingCount::drain().collect::<Vec<_>>() {
+			reads.saturating_inc();
+			writes.saturating_accrue(2);
+			DecidingCount::<Runtime, CommunityReferendaInstance>::insert(CommunityTrackIdOf::from(track_id), count);
+		}
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+pub struct UncheckedMigrateTalliesToV2;
+
+impl UncheckedOnRuntimeUpgrade for UncheckedMigrateTalliesToV2 {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+
+		ReferendumInfoFor::<Runtime, CommunityReferendaInstance>::translate::<OldReferendumInfoOf, _>(|index, info| {
+			reads.saturating_inc();
+			writes.saturating_inc();
+			Some(match info {
+				ReferendumInfo::Ongoing(status) => {
+					let track = status.track.into();
+					let (tally, counted) = translate_tally::<Runtime>(index, &track, status.tally);
+					reads.saturating_accrue(counted.saturating_add(2));
+					writes.saturating_accrue(counted);
+					ReferendumInfo::Ongoing(ReferendumStatus {
+						track,
+						origin: status.origin,
+						proposal: status.proposal,
+						enactment: status.enactment,
+						submitted: status.submitted,
+						submission_deposit: status.submission_deposit,
+						decision_deposit: status.decision_deposit,
+						deciding: status.deciding,
+						tally,
+						in_queue: status.in_queue,
+						alarm: status.alarm,
+					})
+				}
+				ReferendumInfo::Approved(at, submission, decision) => {
+					ReferendumInfo::Approved(at, submission, decision)
+				}
+				ReferendumInfo::Rejected(at, submission, decision) => {
+					ReferendumInfo::Rejected(at, submission, decision)
+				}
+				ReferendumInfo::Cancelled(at, submission, decision) => {
+					ReferendumInfo::Cancelled(at, submission, decision)
+				}
+				ReferendumInfo::TimedOut(at, submission, decision) => {
+					ReferendumInfo::TimedOut(at, submission, decision)
+				}
+				ReferendumInfo::Killed(at) => ReferendumInfo::Killed(at),
+			})
+		});
+
+		// Queued referenda are sorted by their ayes, which might have changed
+		// after counting their votes again
+		TrackQueue::<Runtime, CommunityReferendaInstance>::translate::<OldTrackQueueOf, _>(|_, queue| {
+			reads.saturating_accrue(queue.len() as u64 + 1);
+			writes.saturating_inc();
+			let mut queue: Vec<_> = queue
+				.into_iter()
+				.map(|(index, ayes)| {
+					let ayes = match ReferendumInfoFor::<Runtime, CommunityReferendaInstance>::get(index) {
+						Some(ReferendumInfo::Ongoing(status)) => status.tally.ayes(status.track),
+						_ => ayes.into(),
+					};
+					(index, ayes)
+				})
+				.collect();
+			queue.sort_by_key(|(_, ayes)| *ayes);
+			Some(BoundedVec::truncate_from(queue))
+		});
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_communities::CommunityTrackId;
+	use pallet_referenda::TracksInfo;
+	use parity_scale_codec::Encode;
+
+	#[test]
+	fn track_ids_are_widened() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let info = <collective::tracks::TracksInfo as TracksInfo<Balance, BlockNumber>>::tracks()
+				.next()
+				.expect("collective has tracks; qed")
+				.info
+				.clone();
+			let origin: OriginCaller = pallet_communities::Origin::<Runtime>::new(1).into();
+			let track_id = CommunityTrackIdOf::for_community(1, 0);
+
+			// Tracks were identified by a `u16`
+			let old_track_id = track_id as OldTrackId;
+			assert_eq!(old_track_id.encode().len(), 2);
+			old::Tracks::insert(old_track_id, info.clone());
+			old::OriginToTrackId::insert(origin.clone(), old_track_id);
+			old::TracksIds::put(BoundedVec::truncate_from(vec![old_track_id]));
+			old::TrackQueue::insert(old_track_id, OldTrackQueueOf::truncate_from(vec![(0, 10)]));
+			old::DecidingCount::insert(old_track_id, 1);
+
+			UncheckedMigrateTrackIdsToU32::on_runtime_upgrade();
+
+			assert!(!old::Tracks::contains_key(old_track_id));
+			assert!(!old::TrackQueue::contains_key(old_track_id));
+			assert!(!old::DecidingCount::contains_key(old_track_id));
+
+			assert_eq!(
+				new::Tracks::get(track_id).map(|info| info.encode()),
+				Some(info.encode())
+			);
+			assert_eq!(CommunityTracks::track_for(&origin), Ok(track_id));
+			assert_eq!(new::TracksIds::get().into_inner(), vec![track_id]);
+			assert_eq!(new::TrackQueue::get(track_id).into_inner(), vec![(0, 10)]);
+			assert_eq!(DecidingCount::<Runtime, CommunityReferendaInstance>::get(track_id), 1);
+		});
+	}
+}
@@ -1,16 +1,20 @@
 use super::*;
 
-use frame_support::traits::TryMapSuccess;
+use frame_support::traits::{ConstU16, Contains, TryMapSuccess};
 #[cfg(not(feature = "runtime-benchmarks"))]
 use frame_system::EnsureNever;
 use frame_system::{EnsureRootWithSuccess, EnsureSigned};
-use pallet_communities::origin::{EnsureCommunity, EnsureSignedPays};
-use sp_runtime::{morph_types, traits::AccountIdConversion};
+use pallet_communities::origin::{EnsureCommunity, EnsureCommunityTrack, EnsureSignedPays};
+use sp_runtime::{
+	morph_types,
+	traits::{AccountIdConversion, Verify},
+};
 use virto_common::{CommunityId, MembershipId};
 
 use fc_traits_memberships::{NonFungiblesMemberships, WithHooks};
 pub mod governance;
 pub mod memberships;
+pub mod migrations;
 
 #[cfg(feature = "runtime-benchmarks")]
 use self::{
@@ -43,6 +47,8 @@ parameter_types! {
 	pub const MembershipNftAttr: &'static [u8; 10] = b"membership";
 	pub const CommunityDepositAmount: Balance = UNITS / 2;
 	pub const NoPay: CreationPayment = None;
+	// One item storing up to 343 bytes in `MembershipApplications`
+	pub const MembershipApplicationDeposit: Balance = deposit(1, 343);
 }
 
 morph_types! {
@@ -57,12 +63,14 @@ type AnyoneElsePays = EnsureSignedPays<Runtime, CommunityDepositAmount, Treasury
 
 impl pallet_communities::Config for Runtime {
 	type CommunityId = CommunityId;
+	type TrackId = governance::CommunityTrackIdOf;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type CreateOrigin = EnsureNever<CreationPayment>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type CreateOrigin = RootCreatesCommunitiesForFree;
 	type AdminOrigin = EitherOf<EnsureCommunity<Self>, EnsureCommunityAccount>;
 	type MemberMgmtOrigin = EitherOf<EnsureCommunity<Self>, EnsureCommunityAccount>;
+	type BlockOrigin = EnsureRoot<AccountId>;
 	type MemberMgmt =
 		WithHooks<NonFungiblesMemberships<CommunityMemberships>, memberships::CopySystemAttributesOnAssign>;
 	type MembershipId = MembershipId;
@@ -79,14 +87,44 @@ impl pallet_communities::Config for Runtime {
 	type WeightInfo = crate::weights::pallet_communities::WeightInfo<Runtime>;
 
 	type PalletId = CommunityPalletId;
+	type RemoveItemsLimit = ConstU32<100>;
+	type MaxDelegators = ConstU32<64>;
+	type VoteLockingPeriod = ConstU32<{ 7 * DAYS }>;
+	type VoteCurve = ();
+	type ExpiredMemberships = MembershipHasExpired;
+	type Memberships = CommunityMemberships;
+	type ApplicationDeposit = MembershipApplicationDeposit;
+	type ApplicationPeriod = ConstU32<{ 14 * DAYS }>;
+	type MaxMembersPerBatch = ConstU32<100>;
+	type InvitationSignature = Signature;
+	type InvitationPublic = <Signature as Verify>::Signer;
+	type TrackForOrigin = governance::TrackForOrigin;
+	type SpendOrigin = EnsureCommunityTrack<Self>;
+	type PayoutPeriod = ConstU32<{ 30 * DAYS }>;
+	type SubsetApprovalPeriod = ConstU32<{ 7 * DAYS }>;
 
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = CommunityBenchmarkHelper;
 }
 
+/// A membership expires once the block set in its `membership_expiration`
+/// attribute has passed.
+pub struct MembershipHasExpired;
+
+impl Contains<(CommunityId, MembershipId)> for MembershipHasExpired {
+	fn contains((community_id, membership_id): &(CommunityId, MembershipId)) -> bool {
+		use frame_support::traits::nonfungibles_v2::Inspect;
+		CommunityMemberships::typed_system_attribute(community_id, Some(membership_id), &b"membership_expiration")
+			.map(|expiration: BlockNumber| System::block_number() > expiration)
+			// Otherwise, the membership will not expire
+			.unwrap_or(false)
+	}
+}
+
 impl pallet_communities_manager::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CreateCollection = CommunityMemberships;
@@ -100,6 +138,7 @@ impl pallet_communities_manager::Config for Runtime {
 	type MembershipsManagerCollectionId = MembershipsCollectionId;
 	type CreateMemberships = CommunityMemberships;
 	type MakeTank = MembershipsGasTank;
+	type MaxTracksPerCommunity = ConstU16<8>;
 
 	type WeightInfo = crate::weights::pallet_communities_manager::WeightInfo<Self>;
 }
@@ -165,6 +204,31 @@ impl BenchmarkHelper<Runtime> for CommunityBenchmarkHelper {
 		Ok(())
 	}
 
+	fn expire_membership(
+		community_id: CommunityIdOf<Runtime>,
+		membership_id: MembershipIdOf<Runtime>,
+	) -> Result<(), BenchmarkError> {
+		use frame_support::traits::nonfungibles_v2::Mutate;
+
+		CommunityMemberships::set_typed_attribute(
+			&community_id,
+			&membership_id,
+			&b"membership_expiration",
+			&BlockNumber::default(),
+		)?;
+		System::set_block_number(System::block_number().max(1));
+
+		Ok(())
+	}
+
+	fn invitation_signer() -> (sp_runtime::MultiSigner, AccountId) {
+		<memberships::NftsBenchmarksHelper as pallet_nfts::BenchmarkHelper<_, _, _, _, _>>::signer()
+	}
+
+	fn sign_invitation(signer: &sp_runtime::MultiSigner, message: &[u8]) -> Signature {
+		<memberships::NftsBenchmarksHelper as pallet_nfts::BenchmarkHelper<_, _, _, _, _>>::sign(signer, message)
+	}
+
 	fn prepare_track(pallet_origin: PalletsOriginOf<Runtime>) -> Result<(), BenchmarkError> {
 		let id = Self::community_id();
 		let info = TrackInfo {
@@ -187,7 +251,7 @@ impl BenchmarkHelper<Runtime> for CommunityBenchmarkHelper {
 			},
 		};
 
-		Tracks::<Runtime, CommunityTracksInstance>::insert(RuntimeOrigin::root(), id, info, pallet_origin)?;
+		Tracks::<Runtime, CommunityTracksInstance>::insert(RuntimeOrigin::root(), id.into(), info, pallet_origin)?;
 
 		Ok(())
 	}
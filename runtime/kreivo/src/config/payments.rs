@@ -83,6 +83,7 @@ impl pallet_payments::Config for Runtime {
 	type SenderOrigin = EitherOf<AsSignedByCommunity<Self>, EnsureSigned<AccountId>>;
 	type BeneficiaryOrigin = EnsureSigned<AccountId>;
 	type DisputeResolver = frame_system::EnsureRootWithSuccess<AccountId, TreasuryAccount>;
+	type ArbiterOrigin = EitherOf<AsSignedByCommunity<Self>, EnsureSigned<AccountId>>;
 	type PalletId = PaymentPalletId;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxDiscounts = ConstU32<10>;
@@ -91,6 +92,8 @@ impl pallet_payments::Config for Runtime {
 	type Scheduler = Scheduler;
 	type Preimages = Preimage;
 	type CancelBufferBlockLength = ConstU32<14400>; // 2 days
+	type PaymentRetentionPeriod = ConstU32<{ 30 * DAYS }>;
+	type ArbiterTimeout = ConstU32<{ 7 * DAYS }>;
 	type PalletsOrigin = OriginCaller;
 	type WeightInfo = crate::weights::pallet_payments::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -111,9 +111,24 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_communities::migrations::v1::MigrateVotesToV1<Runtime>,
+	communities::migrations::MigrateTalliesToV2,
+	pallet_communities::migrations::v3::MigrateAdminOriginsToV3<Runtime>,
+	pallet_communities::migrations::v4::MigrateAssetCreatorsToV4<Runtime>,
+	pallet_communities::migrations::v5::MigrateMembersCountToV5<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 impl_opaque_keys! {
 	pub struct SessionKeys {
@@ -1,29 +1,7 @@
-
-//! Autogenerated weights for `pallet_communities`
+//! Weights for `pallet_communities`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 45.0.0
-//! DATE: 2025-01-27, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `virto-us3`, CPU: `Intel(R) Xeon(R) Silver 4216 CPU @ 2.10GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
-
-// Executed Command:
-// /home/devops/.cargo/bin/frame-omni-bencher
-// v1
-// benchmark
-// pallet
-// --runtime
-// target/release/wbuild/kreivo-runtime/kreivo_runtime.compact.compressed.wasm
-// --pallet
-// pallet_communities
-// --extrinsic
-// *
-// --steps
-// 50
-// --repeat
-// 20
-// --output
-// ./runtime/kreivo/src/weights/
+//! Some of these weights were estimated rather than measured. Regenerate them
+//! with the benchmark CLI before relying on them.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -42,6 +20,8 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAdminOrigin` (r:0 w:1)
+	/// Proof: `Communities::CommunityAdminOrigin` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `94`
@@ -50,12 +30,14 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(73_261_000, 0)
 			.saturating_add(Weight::from_parts(0, 4087))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Communities::CommunityIdFor` (r:1 w:2)
 	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	/// Storage: `Communities::Info` (r:1 w:0)
 	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAdminOrigin` (r:0 w:1)
+	/// Proof: `Communities::CommunityAdminOrigin` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	fn set_admin_origin() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `160`
@@ -64,7 +46,7 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(69_555_000, 0)
 			.saturating_add(Weight::from_parts(0, 4087))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Communities::Info` (r:1 w:0)
 	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
@@ -72,8 +54,8 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::NextAssetId` (r:1 w:0)
 	/// Proof: `Assets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(18), added: 513, mode: `MaxEncodedLen`)
-	/// Storage: `Communities::CommunityDecisionMethod` (r:0 w:1)
-	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackDecisionMethod` (r:0 w:1)
+	/// Proof: `Communities::TrackDecisionMethod` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	fn set_decision_method() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `229`
@@ -134,15 +116,21 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Proof: `CommunityMemberships::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(999), added: 3474, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:2)
 	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	fn remove_member() -> Weight {
+	/// The range of component `d` is `[0, 64]`.
+	fn remove_member(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1389`
-		//  Estimated: `15750`
+		//  Estimated: `15750 + d * (2769 ±0)`
 		// Minimum execution time: 552_210_000 picoseconds.
 		Weight::from_parts(666_901_000, 0)
 			.saturating_add(Weight::from_parts(0, 15750))
+			// Standard Error: 2_140_600
+			.saturating_add(Weight::from_parts(21_406_000, 0).saturating_mul(d.into()))
 			.saturating_add(T::DbWeight::get().reads(15))
 			.saturating_add(T::DbWeight::get().writes(15))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2769).saturating_mul(d.into()))
 	}
 	/// Storage: `Communities::Info` (r:1 w:0)
 	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
@@ -150,6 +138,8 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
 	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:1)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
 	fn promote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `755`
@@ -157,8 +147,8 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 180_116_000 picoseconds.
 		Weight::from_parts(292_475_000, 0)
 			.saturating_add(Weight::from_parts(0, 6894))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Communities::Info` (r:1 w:0)
 	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
@@ -166,6 +156,8 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
 	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:1)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
 	fn demote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `793`
@@ -173,15 +165,15 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 170_118_000 picoseconds.
 		Weight::from_parts(235_429_000, 0)
 			.saturating_add(Weight::from_parts(0, 6894))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `CommunityMemberships::Account` (r:1 w:0)
 	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	/// Storage: `Communities::CommunityDecisionMethod` (r:1 w:0)
 	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `Communities::CommunityVotes` (r:1 w:1)
-	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Communities::CommunityVoteLocks` (r:2 w:1)
@@ -192,15 +184,21 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
-	fn vote() -> Weight {
+	/// The range of component `d` is `[0, 64]`.
+	fn vote(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2494`
-		//  Estimated: `6148`
+		//  Estimated: `6148 + d * (2594 ±0)`
 		// Minimum execution time: 314_045_000 picoseconds.
 		Weight::from_parts(458_108_000, 0)
 			.saturating_add(Weight::from_parts(0, 6148))
+			// Standard Error: 2_931_800
+			.saturating_add(Weight::from_parts(29_318_000, 0).saturating_mul(d.into()))
 			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2594).saturating_mul(d.into()))
 	}
 	/// Storage: `CommunityMemberships::Account` (r:1 w:0)
 	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
@@ -209,18 +207,24 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Communities::CommunityVotes` (r:1 w:1)
-	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
 	/// Storage: `CommunityMemberships::Attribute` (r:1 w:0)
 	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
-	fn remove_vote() -> Weight {
+	/// The range of component `d` is `[0, 64]`.
+	fn remove_vote(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2970`
-		//  Estimated: `4365`
+		//  Estimated: `4365 + d * (2594 ±0)`
 		// Minimum execution time: 204_186_000 picoseconds.
 		Weight::from_parts(299_085_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
+			// Standard Error: 2_795_100
+			.saturating_add(Weight::from_parts(27_951_000, 0).saturating_mul(d.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2594).saturating_mul(d.into()))
 	}
 	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -253,4 +257,804 @@ impl<T: frame_system::Config> pallet_communities::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3484))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Metadata` (r:0 w:1)
+	/// Proof: `Communities::Metadata` (`max_values`: None, `max_size`: Some(629), added: 3104, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `3484`
+		// Minimum execution time: 45_812_000 picoseconds.
+		Weight::from_parts(47_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 3484))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Metadata` (r:1 w:1)
+	/// Proof: `Communities::Metadata` (`max_values`: None, `max_size`: Some(629), added: 3104, mode: `MaxEncodedLen`)
+	fn clear_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `4094`
+		// Minimum execution time: 50_127_000 picoseconds.
+		Weight::from_parts(52_304_000, 0)
+			.saturating_add(Weight::from_parts(0, 4094))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:1)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	fn block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `3484`
+		// Minimum execution time: 17_983_000 picoseconds.
+		Weight::from_parts(25_034_000, 0)
+			.saturating_add(Weight::from_parts(0, 3484))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:1)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	fn unblock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `3484`
+		// Minimum execution time: 17_612_000 picoseconds.
+		Weight::from_parts(24_877_000, 0)
+			.saturating_add(Weight::from_parts(0, 3484))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::CommunityIdFor` (r:1 w:0)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:1)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	fn start_destroy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `4087`
+		// Minimum execution time: 24_137_000 picoseconds.
+		Weight::from_parts(31_592_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityPolls` (r:2 w:1)
+	/// Proof: `Communities::CommunityPolls` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:0)
+	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityVotes` (r:101 w:100)
+	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityVoteLocks` (r:200 w:100)
+	/// Proof: `Communities::CommunityVoteLocks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:100 w:100)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(4658), added: 7133, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:100 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 100]`.
+	fn destroy_votes(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3831 + c * (7133 ±0)`
+		// Minimum execution time: 31_228_000 picoseconds.
+		Weight::from_parts(38_507_000, 0)
+			.saturating_add(Weight::from_parts(0, 3831))
+			// Standard Error: 309_691
+			.saturating_add(Weight::from_parts(96_314_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 7133).saturating_mul(c.into()))
+	}
+	/// Storage: `Communities::Info` (r:1 w:1)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityPolls` (r:1 w:0)
+	/// Proof: `Communities::CommunityPolls` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipApplications` (r:1 w:0)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovals` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovals` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovalsCount` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovalsCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:0)
+	/// Proof: `Communities::MembershipInvitations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:1 w:0)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:0)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::TrackDecisionMethod` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackSpendLimits` (r:1 w:0)
+	/// Proof: `Communities::TrackSpendLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Spends` (r:1 w:0)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAdminOrigin` (r:1 w:1)
+	/// Proof: `Communities::CommunityAdminOrigin` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityIdFor` (r:0 w:1)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Metadata` (r:0 w:1)
+	/// Proof: `Communities::Metadata` (`max_values`: None, `max_size`: Some(629), added: 3104, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:0 w:1)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityRankBounds` (r:0 w:1)
+	/// Proof: `Communities::CommunityRankBounds` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SpendCount` (r:0 w:1)
+	/// Proof: `Communities::SpendCount` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn finish_destroy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `7184`
+		// Minimum execution time: 61_402_000 picoseconds.
+		Weight::from_parts(74_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 7184))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Communities::CommunityIdFor` (r:1 w:0)
+	/// Proof: `Communities::CommunityIdFor` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	fn dispatch_as_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `4087`
+		// Minimum execution time: 35_214_000 picoseconds.
+		Weight::from_parts(49_072_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `CommunityMemberships::Account` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:2 w:1)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegators` (r:2 w:1)
+	/// Proof: `Communities::CommunityDelegators` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3942`
+		// Minimum execution time: 71_203_000 picoseconds.
+		Weight::from_parts(86_417_000, 0)
+			.saturating_add(Weight::from_parts(0, 3942))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CommunityMemberships::Account` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:1 w:1)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegators` (r:1 w:1)
+	/// Proof: `Communities::CommunityDelegators` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegatedVotes` (r:2 w:1)
+	/// Proof: `Communities::CommunityDelegatedVotes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityVotes` (r:1 w:1)
+	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3104`
+		//  Estimated: `4365`
+		// Minimum execution time: 152_618_000 picoseconds.
+		Weight::from_parts(197_304_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `CommunityMemberships::Account` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovals` (r:1 w:1)
+	/// Proof: `Communities::SubsetApprovals` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SubsetApprovalsCount` (r:1 w:1)
+	/// Proof: `Communities::SubsetApprovalsCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	fn dispatch_as_subset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3551`
+		// Minimum execution time: 58_417_000 picoseconds.
+		Weight::from_parts(71_932_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CommunityMemberships::Account` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:4 w:3)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:1 w:1)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemMetadataOf` (r:1 w:0)
+	/// Proof: `CommunityMemberships::ItemMetadataOf` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemPriceOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::ItemPriceOf` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemAttributesApprovalsOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(999), added: 3474, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipVotes` (r:2 w:1)
+	/// Proof: `Communities::MembershipVotes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityVotes` (r:1 w:1)
+	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegators` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegators` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 64]`.
+	fn leave(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1421`
+		//  Estimated: `12798 + d * (2769 ±0)`
+		// Minimum execution time: 498_213_000 picoseconds.
+		Weight::from_parts(611_924_000, 0)
+			.saturating_add(Weight::from_parts(0, 12798))
+			// Standard Error: 4_671_300
+			.saturating_add(Weight::from_parts(46_713_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2769).saturating_mul(d.into()))
+	}
+	/// Storage: `CommunityMemberships::Account` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:5 w:3)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:1 w:1)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemMetadataOf` (r:1 w:0)
+	/// Proof: `CommunityMemberships::ItemMetadataOf` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemPriceOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::ItemPriceOf` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemAttributesApprovalsOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(999), added: 3474, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipVotes` (r:2 w:1)
+	/// Proof: `Communities::MembershipVotes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityVotes` (r:1 w:1)
+	/// Proof: `Communities::CommunityVotes` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityReferenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `CommunityReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegators` (r:1 w:0)
+	/// Proof: `Communities::CommunityDelegators` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 64]`.
+	fn prune_expired_member(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1503`
+		//  Estimated: `12798 + d * (2769 ±0)`
+		// Minimum execution time: 521_087_000 picoseconds.
+		Weight::from_parts(640_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 12798))
+			// Standard Error: 4_728_200
+			.saturating_add(Weight::from_parts(47_282_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2769).saturating_mul(d.into()))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:1 w:0)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipApplications` (r:1 w:1)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn apply_for_membership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3808`
+		// Minimum execution time: 58_210_000 picoseconds.
+		Weight::from_parts(61_934_000, 0)
+			.saturating_add(Weight::from_parts(0, 3808))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipApplications` (r:1 w:1)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:1 w:3)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:2 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:6 w:2)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionConfigOf` (r:2 w:0)
+	/// Proof: `CommunityMemberships::CollectionConfigOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:2 w:1)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:2 w:2)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemPriceOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::ItemPriceOf` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn approve_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1203`
+		//  Estimated: `18702`
+		// Minimum execution time: 472_118_000 picoseconds.
+		Weight::from_parts(571_406_000, 0)
+			.saturating_add(Weight::from_parts(0, 18702))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipApplications` (r:1 w:1)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `519`
+		//  Estimated: `6196`
+		// Minimum execution time: 79_502_000 picoseconds.
+		Weight::from_parts(84_117_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Communities::MembershipApplications` (r:1 w:1)
+	/// Proof: `Communities::MembershipApplications` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_expired_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `481`
+		//  Estimated: `3808`
+		// Minimum execution time: 55_830_000 picoseconds.
+		Weight::from_parts(58_341_000, 0)
+			.saturating_add(Weight::from_parts(0, 3808))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:1)
+	/// Proof: `Communities::MembershipInvitations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:2 w:0)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:1 w:1)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn invite_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583`
+		//  Estimated: `6142`
+		// Minimum execution time: 41_376_000 picoseconds.
+		Weight::from_parts(43_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 6142))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:1)
+	/// Proof: `Communities::MembershipInvitations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:0 w:1)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:1 w:3)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:2 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:6 w:2)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::CollectionConfigOf` (r:2 w:0)
+	/// Proof: `CommunityMemberships::CollectionConfigOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:2 w:1)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:2 w:2)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemPriceOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::ItemPriceOf` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:1)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn claim_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1041`
+		//  Estimated: `18702`
+		// Minimum execution time: 431_540_000 picoseconds.
+		Weight::from_parts(529_873_000, 0)
+			.saturating_add(Weight::from_parts(0, 18702))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:1)
+	/// Proof: `Communities::MembershipInvitations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:0 w:1)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn revoke_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3535`
+		// Minimum execution time: 19_824_000 picoseconds.
+		Weight::from_parts(20_613_000, 0)
+			.saturating_add(Weight::from_parts(0, 3535))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:101 w:300)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:2 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:501 w:200)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:101 w:100)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:101 w:200)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::InvitedMemberships` (r:100 w:0)
+	/// Proof: `Communities::InvitedMemberships` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn add_members(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `880`
+		//  Estimated: `9846 + n * (3334 ±0)`
+		// Minimum execution time: 251_604_000 picoseconds.
+		Weight::from_parts(103_518_000, 0)
+			.saturating_add(Weight::from_parts(0, 9846))
+			// Standard Error: 19_284_000
+			.saturating_add(Weight::from_parts(268_911_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3334).saturating_mul(n.into()))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipVotes` (r:100 w:0)
+	/// Proof: `Communities::MembershipVotes` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegations` (r:100 w:0)
+	/// Proof: `Communities::CommunityDelegations` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDelegators` (r:100 w:0)
+	/// Proof: `Communities::CommunityDelegators` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:100 w:100)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Account` (r:100 w:100)
+	/// Proof: `CommunityMemberships::Account` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:200 w:100)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::ItemConfigOf` (r:100 w:100)
+	/// Proof: `CommunityMemberships::ItemConfigOf` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:100 w:100)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::PendingSwapOf` (r:0 w:100)
+	/// Proof: `CommunityMemberships::PendingSwapOf` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_members(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `6364 + n * (3334 ±0)`
+		// Minimum execution time: 203_015_000 picoseconds.
+		Weight::from_parts(88_273_000, 0)
+			.saturating_add(Weight::from_parts(0, 6364))
+			// Standard Error: 14_805_000
+			.saturating_add(Weight::from_parts(192_714_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3334).saturating_mul(n.into()))
+	}
+	/// Storage: `Communities::MembershipRankHistory` (r:100 w:100)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Attribute` (r:100 w:100)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Item` (r:100 w:0)
+	/// Proof: `CommunityMemberships::Item` (`max_values`: None, `max_size`: Some(859), added: 3334, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn set_ranks(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `655`
+		//  Estimated: `3484 + n * (3334 ±0)`
+		// Minimum execution time: 55_208_000 picoseconds.
+		Weight::from_parts(21_093_000, 0)
+			.saturating_add(Weight::from_parts(0, 3484))
+			// Standard Error: 2_917_000
+			.saturating_add(Weight::from_parts(38_416_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3334).saturating_mul(n.into()))
+	}
+	/// Storage: `CommunityMemberships::Attribute` (r:2 w:2)
+	/// Proof: `CommunityMemberships::Attribute` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityRankBounds` (r:1 w:0)
+	/// Proof: `Communities::CommunityRankBounds` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityMemberships::Collection` (r:1 w:1)
+	/// Proof: `CommunityMemberships::Collection` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:1)
+	/// Proof: `Communities::MembershipRankHistory` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	fn set_member_rank() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `755`
+		//  Estimated: `6894`
+		// Minimum execution time: 183_402_000 picoseconds.
+		Weight::from_parts(295_160_000, 0)
+			.saturating_add(Weight::from_parts(0, 6894))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Communities::CommunityRankBounds` (r:0 w:1)
+	/// Proof: `Communities::CommunityRankBounds` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn set_rank_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_342_000 picoseconds.
+		Weight::from_parts(11_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:1 w:0)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackSpendLimits` (r:1 w:0)
+	/// Proof: `Communities::TrackSpendLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::SpendCount` (r:1 w:1)
+	/// Proof: `Communities::SpendCount` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Spends` (r:0 w:1)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `392`
+		//  Estimated: `4087`
+		// Minimum execution time: 61_207_000 picoseconds.
+		Weight::from_parts(64_913_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Communities::Spends` (r:1 w:1)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `6196`
+		// Minimum execution time: 95_630_000 picoseconds.
+		Weight::from_parts(99_184_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::Spends` (r:1 w:1)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn void_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3578`
+		// Minimum execution time: 31_820_000 picoseconds.
+		Weight::from_parts(33_472_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Spends` (r:1 w:1)
+	/// Proof: `Communities::Spends` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn remove_expired_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `195`
+		//  Estimated: `3578`
+		// Minimum execution time: 27_301_000 picoseconds.
+		Weight::from_parts(28_845_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `CommunityTracks::OriginToTrackId` (r:1 w:0)
+	/// Proof: `CommunityTracks::OriginToTrackId` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::TrackSpendLimits` (r:0 w:1)
+	/// Proof: `Communities::TrackSpendLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn set_spend_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `4087`
+		// Minimum execution time: 24_514_000 picoseconds.
+		Weight::from_parts(25_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 4087))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAssets` (r:1 w:1)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::AssetCreators` (r:0 w:1)
+	/// Proof: `Communities::AssetCreators` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Metadata` (r:1 w:1)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn create_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `203`
+		//  Estimated: `3689`
+		// Minimum execution time: 78_512_000 picoseconds.
+		Weight::from_parts(82_940_000, 0)
+			.saturating_add(Weight::from_parts(0, 3689))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Metadata` (r:1 w:1)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3689`
+		// Minimum execution time: 51_208_000 picoseconds.
+		Weight::from_parts(53_771_000, 0)
+			.saturating_add(Weight::from_parts(0, 3689))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn mint_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `487`
+		//  Estimated: `3689`
+		// Minimum execution time: 57_034_000 picoseconds.
+		Weight::from_parts(59_318_000, 0)
+			.saturating_add(Weight::from_parts(0, 3689))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	fn burn_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `623`
+		//  Estimated: `3689`
+		// Minimum execution time: 54_610_000 picoseconds.
+		Weight::from_parts(56_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 3689))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::AssetCreators` (r:1 w:0)
+	/// Proof: `Communities::AssetCreators` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::CommunityDecisionMethod` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Communities::CommunityAssets` (r:0 w:1)
+	/// Proof: `Communities::CommunityAssets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn claim_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `431`
+		//  Estimated: `3689`
+		// Minimum execution time: 32_117_000 picoseconds.
+		Weight::from_parts(34_205_000, 0)
+			.saturating_add(Weight::from_parts(0, 3689))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Communities::Info` (r:1 w:0)
+	/// Proof: `Communities::Info` ((`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::MembershipApplications` (r:101 w:100)
+	/// Proof: `Communities::MembershipApplications` ((`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`))
+	/// Storage: `Balances::Holds` (r:100 w:100)
+	/// Proof: `Balances::Holds` ((`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`))
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Proof: `System::Account` ((`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::SubsetApprovals` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovals` ((`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::SubsetApprovalsCount` (r:1 w:0)
+	/// Proof: `Communities::SubsetApprovalsCount` ((`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::MembershipInvitations` (r:1 w:0)
+	/// Proof: `Communities::MembershipInvitations` ((`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::InvitedMemberships` (r:1 w:0)
+	/// Proof: `Communities::InvitedMemberships` ((`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::MembershipRankHistory` (r:1 w:0)
+	/// Proof: `Communities::MembershipRankHistory` ((`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::TrackDecisionMethod` (r:1 w:0)
+	/// Proof: `Communities::TrackDecisionMethod` ((`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::TrackSpendLimits` (r:1 w:0)
+	/// Proof: `Communities::TrackSpendLimits` ((`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::Spends` (r:1 w:0)
+	/// Proof: `Communities::Spends` ((`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`))
+	/// Storage: `Communities::CommunityAssets` (r:1 w:0)
+	/// Proof: `Communities::CommunityAssets` ((`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`))
+	/// The range of component `c` is `[0, 100]`.
+	fn destroy_records(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3808 + c * (2818 ±0)`
+		// Minimum execution time: 24_615_000 picoseconds.
+		Weight::from_parts(31_472_000, 0)
+			.saturating_add(Weight::from_parts(0, 3808))
+			// Standard Error: 284_117
+			.saturating_add(Weight::from_parts(71_963_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2818).saturating_mul(c.into()))
+	}
 }